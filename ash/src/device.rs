@@ -6,6 +6,7 @@ use std::mem;
 use std::os::raw::c_void;
 use std::ptr;
 
+#[cfg(feature = "V1_2")]
 #[allow(non_camel_case_types)]
 pub trait DeviceV1_2: DeviceV1_1 {
     fn fp_v1_2(&self) -> &vk::DeviceFnV1_2;
@@ -21,7 +22,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         max_draw_count: u32,
         stride: u32,
     ) {
-        self.fp_v1_2().cmd_draw_indirect_count(
+        (self.fp_v1_2().cmd_draw_indirect_count)(
             command_buffer,
             buffer,
             offset,
@@ -43,7 +44,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         max_draw_count: u32,
         stride: u32,
     ) {
-        self.fp_v1_2().cmd_draw_indexed_indirect_count(
+        (self.fp_v1_2().cmd_draw_indexed_indirect_count)(
             command_buffer,
             buffer,
             offset,
@@ -61,7 +62,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::RenderPass> {
         let mut renderpass = mem::zeroed();
-        let err_code = self.fp_v1_2().create_render_pass2(
+        let err_code = (self.fp_v1_2().create_render_pass2)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -80,7 +81,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         render_pass_begin_info: &vk::RenderPassBeginInfo,
         subpass_begin_info: &vk::SubpassBeginInfo,
     ) {
-        self.fp_v1_2().cmd_begin_render_pass2(
+        (self.fp_v1_2().cmd_begin_render_pass2)(
             command_buffer,
             render_pass_begin_info,
             subpass_begin_info,
@@ -94,8 +95,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         subpass_begin_info: &vk::SubpassBeginInfo,
         subpass_end_info: &vk::SubpassEndInfo,
     ) {
-        self.fp_v1_2()
-            .cmd_next_subpass2(command_buffer, subpass_begin_info, subpass_end_info);
+        (self.fp_v1_2().cmd_next_subpass2)(command_buffer, subpass_begin_info, subpass_end_info);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass2.html>"]
@@ -104,8 +104,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         command_buffer: vk::CommandBuffer,
         subpass_end_info: &vk::SubpassEndInfo,
     ) {
-        self.fp_v1_2()
-            .cmd_end_render_pass2(command_buffer, subpass_end_info);
+        (self.fp_v1_2().cmd_end_render_pass2)(command_buffer, subpass_end_info);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkResetQueryPool.html>"]
@@ -115,16 +114,14 @@ pub trait DeviceV1_2: DeviceV1_1 {
         first_query: u32,
         query_count: u32,
     ) {
-        self.fp_v1_2()
-            .reset_query_pool(self.handle(), query_pool, first_query, query_count);
+        (self.fp_v1_2().reset_query_pool)(self.handle(), query_pool, first_query, query_count);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetSemaphoreCounterValue.html>"]
     unsafe fn get_semaphore_counter_value(&self, semaphore: vk::Semaphore) -> VkResult<u64> {
         let mut value = 0;
         let err_code =
-            self.fp_v1_2()
-                .get_semaphore_counter_value(self.handle(), semaphore, &mut value);
+            (self.fp_v1_2().get_semaphore_counter_value)(self.handle(), semaphore, &mut value);
         match err_code {
             vk::Result::SUCCESS => Ok(value),
             _ => Err(err_code),
@@ -137,9 +134,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
         wait_info: &vk::SemaphoreWaitInfo,
         timeout: u64,
     ) -> VkResult<()> {
-        let err_code = self
-            .fp_v1_2()
-            .wait_semaphores(self.handle(), wait_info, timeout);
+        let err_code = (self.fp_v1_2().wait_semaphores)(self.handle(), wait_info, timeout);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -148,7 +143,7 @@ pub trait DeviceV1_2: DeviceV1_1 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkSignalSemaphore.html>"]
     unsafe fn signal_semaphore(&self, signal_info: &vk::SemaphoreSignalInfo) -> VkResult<()> {
-        let err_code = self.fp_v1_2().signal_semaphore(self.handle(), signal_info);
+        let err_code = (self.fp_v1_2().signal_semaphore)(self.handle(), signal_info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -160,14 +155,12 @@ pub trait DeviceV1_2: DeviceV1_1 {
         &self,
         info: &vk::BufferDeviceAddressInfo,
     ) -> vk::DeviceAddress {
-        self.fp_v1_2()
-            .get_buffer_device_address(self.handle(), info)
+        (self.fp_v1_2().get_buffer_device_address)(self.handle(), info)
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetBufferOpaqueCaptureAddress.html>"]
     unsafe fn get_buffer_opaque_capture_address(&self, info: &vk::BufferDeviceAddressInfo) -> u64 {
-        self.fp_v1_2()
-            .get_buffer_opaque_capture_address(self.handle(), info)
+        (self.fp_v1_2().get_buffer_opaque_capture_address)(self.handle(), info)
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetDeviceMemoryOpaqueCaptureAddress.html>"]
@@ -175,18 +168,18 @@ pub trait DeviceV1_2: DeviceV1_1 {
         &self,
         info: &vk::DeviceMemoryOpaqueCaptureAddressInfo,
     ) -> u64 {
-        self.fp_v1_2()
-            .get_device_memory_opaque_capture_address(self.handle(), info)
+        (self.fp_v1_2().get_device_memory_opaque_capture_address)(self.handle(), info)
     }
 }
 
+#[cfg(feature = "V1_1")]
 #[allow(non_camel_case_types)]
 pub trait DeviceV1_1: DeviceV1_0 {
     fn fp_v1_1(&self) -> &vk::DeviceFnV1_1;
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkBindBufferMemory2.html>"]
     unsafe fn bind_buffer_memory2(&self, bind_infos: &[vk::BindBufferMemoryInfo]) -> VkResult<()> {
-        let err_code = self.fp_v1_1().bind_buffer_memory2(
+        let err_code = (self.fp_v1_1().bind_buffer_memory2)(
             self.handle(),
             bind_infos.len() as _,
             bind_infos.as_ptr(),
@@ -199,7 +192,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkBindImageMemory2.html>"]
     unsafe fn bind_image_memory2(&self, bind_infos: &[vk::BindImageMemoryInfo]) -> VkResult<()> {
-        let err_code = self.fp_v1_1().bind_image_memory2(
+        let err_code = (self.fp_v1_1().bind_image_memory2)(
             self.handle(),
             bind_infos.len() as _,
             bind_infos.as_ptr(),
@@ -218,7 +211,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         remote_device_index: u32,
     ) -> vk::PeerMemoryFeatureFlags {
         let mut peer_memory_features = mem::zeroed();
-        self.fp_v1_1().get_device_group_peer_memory_features(
+        (self.fp_v1_1().get_device_group_peer_memory_features)(
             self.handle(),
             heap_index,
             local_device_index,
@@ -230,8 +223,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDeviceMask.html>"]
     unsafe fn cmd_set_device_mask(&self, command_buffer: vk::CommandBuffer, device_mask: u32) {
-        self.fp_v1_1()
-            .cmd_set_device_mask(command_buffer, device_mask);
+        (self.fp_v1_1().cmd_set_device_mask)(command_buffer, device_mask);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatchBase.html>"]
@@ -245,7 +237,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
-        self.fp_v1_1().cmd_dispatch_base(
+        (self.fp_v1_1().cmd_dispatch_base)(
             command_buffer,
             base_group_x,
             base_group_y,
@@ -262,8 +254,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::ImageMemoryRequirementsInfo2,
         out: &mut vk::MemoryRequirements2,
     ) {
-        self.fp_v1_1()
-            .get_image_memory_requirements2(self.handle(), info, out);
+        (self.fp_v1_1().get_image_memory_requirements2)(self.handle(), info, out);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetBufferMemoryRequirements2.html>"]
@@ -272,8 +263,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::BufferMemoryRequirementsInfo2,
         out: &mut vk::MemoryRequirements2,
     ) {
-        self.fp_v1_1()
-            .get_buffer_memory_requirements2(self.handle(), info, out);
+        (self.fp_v1_1().get_buffer_memory_requirements2)(self.handle(), info, out);
     }
 
     unsafe fn get_image_sparse_memory_requirements2_len(
@@ -281,7 +271,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::ImageSparseMemoryRequirementsInfo2,
     ) -> usize {
         let mut count = mem::zeroed();
-        self.fp_v1_1().get_image_sparse_memory_requirements2(
+        (self.fp_v1_1().get_image_sparse_memory_requirements2)(
             self.handle(),
             info,
             &mut count,
@@ -297,7 +287,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         out: &mut [vk::SparseImageMemoryRequirements2],
     ) {
         let mut count = out.len() as u32;
-        self.fp_v1_1().get_image_sparse_memory_requirements2(
+        (self.fp_v1_1().get_image_sparse_memory_requirements2)(
             self.handle(),
             info,
             &mut count,
@@ -311,8 +301,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         command_pool: vk::CommandPool,
         flags: vk::CommandPoolTrimFlags,
     ) {
-        self.fp_v1_1()
-            .trim_command_pool(self.handle(), command_pool, flags);
+        (self.fp_v1_1().trim_command_pool)(self.handle(), command_pool, flags);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateSamplerYcbcrConversion.html>"]
@@ -322,7 +311,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SamplerYcbcrConversion> {
         let mut ycbcr_conversion = mem::zeroed();
-        let err_code = self.fp_v1_1().create_sampler_ycbcr_conversion(
+        let err_code = (self.fp_v1_1().create_sampler_ycbcr_conversion)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -340,7 +329,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         ycbcr_conversion: vk::SamplerYcbcrConversion,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_1().destroy_sampler_ycbcr_conversion(
+        (self.fp_v1_1().destroy_sampler_ycbcr_conversion)(
             self.handle(),
             ycbcr_conversion,
             allocation_callbacks.as_raw_ptr(),
@@ -354,7 +343,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorUpdateTemplate> {
         let mut descriptor_update_template = mem::zeroed();
-        let err_code = self.fp_v1_1().create_descriptor_update_template(
+        let err_code = (self.fp_v1_1().create_descriptor_update_template)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -372,7 +361,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         descriptor_update_template: vk::DescriptorUpdateTemplate,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_1().destroy_descriptor_update_template(
+        (self.fp_v1_1().destroy_descriptor_update_template)(
             self.handle(),
             descriptor_update_template,
             allocation_callbacks.as_raw_ptr(),
@@ -386,7 +375,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         descriptor_update_template: vk::DescriptorUpdateTemplate,
         data: *const c_void,
     ) {
-        self.fp_v1_1().update_descriptor_set_with_template(
+        (self.fp_v1_1().update_descriptor_set_with_template)(
             self.handle(),
             descriptor_set,
             descriptor_update_template,
@@ -400,8 +389,7 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &vk::DescriptorSetLayoutCreateInfo,
         out: &mut vk::DescriptorSetLayoutSupport,
     ) {
-        self.fp_v1_1()
-            .get_descriptor_set_layout_support(self.handle(), create_info, out);
+        (self.fp_v1_1().get_descriptor_set_layout_support)(self.handle(), create_info, out);
     }
 }
 
@@ -411,8 +399,7 @@ pub trait DeviceV1_0 {
     fn fp_v1_0(&self) -> &vk::DeviceFnV1_0;
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyDevice.html>"]
     unsafe fn destroy_device(&self, allocation_callbacks: Option<&vk::AllocationCallbacks>) {
-        self.fp_v1_0()
-            .destroy_device(self.handle(), allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_device)(self.handle(), allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroySampler.html>"]
@@ -421,8 +408,7 @@ pub trait DeviceV1_0 {
         sampler: vk::Sampler,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_sampler(self.handle(), sampler, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_sampler)(self.handle(), sampler, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkFreeMemory.html>"]
@@ -431,8 +417,7 @@ pub trait DeviceV1_0 {
         memory: vk::DeviceMemory,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .free_memory(self.handle(), memory, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().free_memory)(self.handle(), memory, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkFreeCommandBuffers.html>"]
@@ -441,7 +426,7 @@ pub trait DeviceV1_0 {
         command_pool: vk::CommandPool,
         command_buffers: &[vk::CommandBuffer],
     ) {
-        self.fp_v1_0().free_command_buffers(
+        (self.fp_v1_0().free_command_buffers)(
             self.handle(),
             command_pool,
             command_buffers.len() as u32,
@@ -456,7 +441,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Event> {
         let mut event = mem::zeroed();
-        let err_code = self.fp_v1_0().create_event(
+        let err_code = (self.fp_v1_0().create_event)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -472,7 +457,7 @@ pub trait DeviceV1_0 {
     /// return the error code.
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetEventStatus.html>"]
     unsafe fn get_event_status(&self, event: vk::Event) -> VkResult<bool> {
-        let err_code = self.fp_v1_0().get_event_status(self.handle(), event);
+        let err_code = (self.fp_v1_0().get_event_status)(self.handle(), event);
         match err_code {
             vk::Result::EVENT_SET => Ok(true),
             vk::Result::EVENT_RESET => Ok(false),
@@ -482,7 +467,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkSetEvent.html>"]
     unsafe fn set_event(&self, event: vk::Event) -> VkResult<()> {
-        let err_code = self.fp_v1_0().set_event(self.handle(), event);
+        let err_code = (self.fp_v1_0().set_event)(self.handle(), event);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -491,7 +476,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkResetEvent.html>"]
     unsafe fn reset_event(&self, event: vk::Event) -> VkResult<()> {
-        let err_code = self.fp_v1_0().reset_event(self.handle(), event);
+        let err_code = (self.fp_v1_0().reset_event)(self.handle(), event);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -504,8 +489,7 @@ pub trait DeviceV1_0 {
         event: vk::Event,
         stage_mask: vk::PipelineStageFlags,
     ) {
-        self.fp_v1_0()
-            .cmd_set_event(command_buffer, event, stage_mask);
+        (self.fp_v1_0().cmd_set_event)(command_buffer, event, stage_mask);
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResetEvent.html>"]
     unsafe fn cmd_reset_event(
@@ -514,8 +498,7 @@ pub trait DeviceV1_0 {
         event: vk::Event,
         stage_mask: vk::PipelineStageFlags,
     ) {
-        self.fp_v1_0()
-            .cmd_reset_event(command_buffer, event, stage_mask);
+        (self.fp_v1_0().cmd_reset_event)(command_buffer, event, stage_mask);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWaitEvents.html>"]
@@ -529,7 +512,7 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         image_memory_barriers: &[vk::ImageMemoryBarrier],
     ) {
-        self.fp_v1_0().cmd_wait_events(
+        (self.fp_v1_0().cmd_wait_events)(
             command_buffer,
             events.len() as _,
             events.as_ptr(),
@@ -550,8 +533,7 @@ pub trait DeviceV1_0 {
         fence: vk::Fence,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_fence(self.handle(), fence, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_fence)(self.handle(), fence, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyEvent.html>"]
@@ -560,8 +542,7 @@ pub trait DeviceV1_0 {
         event: vk::Event,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_event(self.handle(), event, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_event)(self.handle(), event, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyImage.html>"]
@@ -570,8 +551,7 @@ pub trait DeviceV1_0 {
         image: vk::Image,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_image(self.handle(), image, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_image)(self.handle(), image, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyCommandPool.html>"]
@@ -580,8 +560,11 @@ pub trait DeviceV1_0 {
         pool: vk::CommandPool,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_command_pool(self.handle(), pool, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_command_pool)(
+            self.handle(),
+            pool,
+            allocation_callbacks.as_raw_ptr(),
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyImageView.html>"]
@@ -590,7 +573,7 @@ pub trait DeviceV1_0 {
         image_view: vk::ImageView,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_image_view(
+        (self.fp_v1_0().destroy_image_view)(
             self.handle(),
             image_view,
             allocation_callbacks.as_raw_ptr(),
@@ -603,7 +586,7 @@ pub trait DeviceV1_0 {
         renderpass: vk::RenderPass,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_render_pass(
+        (self.fp_v1_0().destroy_render_pass)(
             self.handle(),
             renderpass,
             allocation_callbacks.as_raw_ptr(),
@@ -616,7 +599,7 @@ pub trait DeviceV1_0 {
         framebuffer: vk::Framebuffer,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_framebuffer(
+        (self.fp_v1_0().destroy_framebuffer)(
             self.handle(),
             framebuffer,
             allocation_callbacks.as_raw_ptr(),
@@ -629,7 +612,7 @@ pub trait DeviceV1_0 {
         pipeline_layout: vk::PipelineLayout,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_pipeline_layout(
+        (self.fp_v1_0().destroy_pipeline_layout)(
             self.handle(),
             pipeline_layout,
             allocation_callbacks.as_raw_ptr(),
@@ -642,7 +625,7 @@ pub trait DeviceV1_0 {
         pipeline_cache: vk::PipelineCache,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_pipeline_cache(
+        (self.fp_v1_0().destroy_pipeline_cache)(
             self.handle(),
             pipeline_cache,
             allocation_callbacks.as_raw_ptr(),
@@ -655,8 +638,7 @@ pub trait DeviceV1_0 {
         buffer: vk::Buffer,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_buffer(self.handle(), buffer, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_buffer)(self.handle(), buffer, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyShaderModule.html>"]
@@ -665,7 +647,7 @@ pub trait DeviceV1_0 {
         shader: vk::ShaderModule,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_shader_module(
+        (self.fp_v1_0().destroy_shader_module)(
             self.handle(),
             shader,
             allocation_callbacks.as_raw_ptr(),
@@ -678,8 +660,11 @@ pub trait DeviceV1_0 {
         pipeline: vk::Pipeline,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_pipeline(self.handle(), pipeline, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_pipeline)(
+            self.handle(),
+            pipeline,
+            allocation_callbacks.as_raw_ptr(),
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroySemaphore.html>"]
@@ -688,7 +673,7 @@ pub trait DeviceV1_0 {
         semaphore: vk::Semaphore,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_semaphore(
+        (self.fp_v1_0().destroy_semaphore)(
             self.handle(),
             semaphore,
             allocation_callbacks.as_raw_ptr(),
@@ -701,7 +686,7 @@ pub trait DeviceV1_0 {
         pool: vk::DescriptorPool,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_descriptor_pool(
+        (self.fp_v1_0().destroy_descriptor_pool)(
             self.handle(),
             pool,
             allocation_callbacks.as_raw_ptr(),
@@ -714,8 +699,7 @@ pub trait DeviceV1_0 {
         pool: vk::QueryPool,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0()
-            .destroy_query_pool(self.handle(), pool, allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_query_pool)(self.handle(), pool, allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyDescriptorSetLayout.html>"]
@@ -724,7 +708,7 @@ pub trait DeviceV1_0 {
         layout: vk::DescriptorSetLayout,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_descriptor_set_layout(
+        (self.fp_v1_0().destroy_descriptor_set_layout)(
             self.handle(),
             layout,
             allocation_callbacks.as_raw_ptr(),
@@ -737,7 +721,7 @@ pub trait DeviceV1_0 {
         pool: vk::DescriptorPool,
         descriptor_sets: &[vk::DescriptorSet],
     ) {
-        self.fp_v1_0().free_descriptor_sets(
+        (self.fp_v1_0().free_descriptor_sets)(
             self.handle(),
             pool,
             descriptor_sets.len() as u32,
@@ -751,7 +735,7 @@ pub trait DeviceV1_0 {
         descriptor_writes: &[vk::WriteDescriptorSet],
         descriptor_copies: &[vk::CopyDescriptorSet],
     ) {
-        self.fp_v1_0().update_descriptor_sets(
+        (self.fp_v1_0().update_descriptor_sets)(
             self.handle(),
             descriptor_writes.len() as u32,
             descriptor_writes.as_ptr(),
@@ -767,7 +751,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Sampler> {
         let mut sampler = mem::zeroed();
-        let err_code = self.fp_v1_0().create_sampler(
+        let err_code = (self.fp_v1_0().create_sampler)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -790,7 +774,7 @@ pub trait DeviceV1_0 {
         regions: &[vk::ImageBlit],
         filter: vk::Filter,
     ) {
-        self.fp_v1_0().cmd_blit_image(
+        (self.fp_v1_0().cmd_blit_image)(
             command_buffer,
             src_image,
             src_image_layout,
@@ -812,7 +796,7 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::ImageResolve],
     ) {
-        self.fp_v1_0().cmd_resolve_image(
+        (self.fp_v1_0().cmd_resolve_image)(
             command_buffer,
             src_image,
             src_image_layout,
@@ -832,8 +816,7 @@ pub trait DeviceV1_0 {
         size: vk::DeviceSize,
         data: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_fill_buffer(command_buffer, buffer, offset, size, data);
+        (self.fp_v1_0().cmd_fill_buffer)(command_buffer, buffer, offset, size, data);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdUpdateBuffer.html>"]
@@ -844,7 +827,7 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
         data: &[u8],
     ) {
-        self.fp_v1_0().cmd_update_buffer(
+        (self.fp_v1_0().cmd_update_buffer)(
             command_buffer,
             buffer,
             offset,
@@ -861,7 +844,7 @@ pub trait DeviceV1_0 {
         dst_buffer: vk::Buffer,
         regions: &[vk::BufferCopy],
     ) {
-        self.fp_v1_0().cmd_copy_buffer(
+        (self.fp_v1_0().cmd_copy_buffer)(
             command_buffer,
             src_buffer,
             dst_buffer,
@@ -879,7 +862,7 @@ pub trait DeviceV1_0 {
         dst_buffer: vk::Buffer,
         regions: &[vk::BufferImageCopy],
     ) {
-        self.fp_v1_0().cmd_copy_image_to_buffer(
+        (self.fp_v1_0().cmd_copy_image_to_buffer)(
             command_buffer,
             src_image,
             src_image_layout,
@@ -898,7 +881,7 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::BufferImageCopy],
     ) {
-        self.fp_v1_0().cmd_copy_buffer_to_image(
+        (self.fp_v1_0().cmd_copy_buffer_to_image)(
             command_buffer,
             src_buffer,
             dst_image,
@@ -918,7 +901,7 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::ImageCopy],
     ) {
-        self.fp_v1_0().cmd_copy_image(
+        (self.fp_v1_0().cmd_copy_image)(
             command_buffer,
             src_image,
             src_image_layout,
@@ -935,7 +918,7 @@ pub trait DeviceV1_0 {
         create_info: &vk::DescriptorSetAllocateInfo,
     ) -> VkResult<Vec<vk::DescriptorSet>> {
         let mut desc_set = Vec::with_capacity(create_info.descriptor_set_count as usize);
-        let err_code = self.fp_v1_0().allocate_descriptor_sets(
+        let err_code = (self.fp_v1_0().allocate_descriptor_sets)(
             self.handle(),
             create_info,
             desc_set.as_mut_ptr(),
@@ -955,7 +938,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorSetLayout> {
         let mut layout = mem::zeroed();
-        let err_code = self.fp_v1_0().create_descriptor_set_layout(
+        let err_code = (self.fp_v1_0().create_descriptor_set_layout)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -969,7 +952,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDeviceWaitIdle.html>"]
     unsafe fn device_wait_idle(&self) -> VkResult<()> {
-        let err_code = self.fp_v1_0().device_wait_idle(self.handle());
+        let err_code = (self.fp_v1_0().device_wait_idle)(self.handle());
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -983,7 +966,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorPool> {
         let mut pool = mem::zeroed();
-        let err_code = self.fp_v1_0().create_descriptor_pool(
+        let err_code = (self.fp_v1_0().create_descriptor_pool)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1001,9 +984,7 @@ pub trait DeviceV1_0 {
         pool: vk::DescriptorPool,
         flags: vk::DescriptorPoolResetFlags,
     ) -> VkResult<()> {
-        let err_code = self
-            .fp_v1_0()
-            .reset_descriptor_pool(self.handle(), pool, flags);
+        let err_code = (self.fp_v1_0().reset_descriptor_pool)(self.handle(), pool, flags);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1016,9 +997,7 @@ pub trait DeviceV1_0 {
         command_pool: vk::CommandPool,
         flags: vk::CommandPoolResetFlags,
     ) -> VkResult<()> {
-        let err_code = self
-            .fp_v1_0()
-            .reset_command_pool(self.handle(), command_pool, flags);
+        let err_code = (self.fp_v1_0().reset_command_pool)(self.handle(), command_pool, flags);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1031,7 +1010,7 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         flags: vk::CommandBufferResetFlags,
     ) -> VkResult<()> {
-        let err_code = self.fp_v1_0().reset_command_buffer(command_buffer, flags);
+        let err_code = (self.fp_v1_0().reset_command_buffer)(command_buffer, flags);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1041,8 +1020,7 @@ pub trait DeviceV1_0 {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkResetFences.html>"]
     unsafe fn reset_fences(&self, fences: &[vk::Fence]) -> VkResult<()> {
         let err_code =
-            self.fp_v1_0()
-                .reset_fences(self.handle(), fences.len() as u32, fences.as_ptr());
+            (self.fp_v1_0().reset_fences)(self.handle(), fences.len() as u32, fences.as_ptr());
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1057,8 +1035,7 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
         index_type: vk::IndexType,
     ) {
-        self.fp_v1_0()
-            .cmd_bind_index_buffer(command_buffer, buffer, offset, index_type);
+        (self.fp_v1_0().cmd_bind_index_buffer)(command_buffer, buffer, offset, index_type);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdClearColorImage.html>"]
@@ -1070,7 +1047,7 @@ pub trait DeviceV1_0 {
         clear_color_value: &vk::ClearColorValue,
         ranges: &[vk::ImageSubresourceRange],
     ) {
-        self.fp_v1_0().cmd_clear_color_image(
+        (self.fp_v1_0().cmd_clear_color_image)(
             command_buffer,
             image,
             image_layout,
@@ -1089,7 +1066,7 @@ pub trait DeviceV1_0 {
         clear_depth_stencil_value: &vk::ClearDepthStencilValue,
         ranges: &[vk::ImageSubresourceRange],
     ) {
-        self.fp_v1_0().cmd_clear_depth_stencil_image(
+        (self.fp_v1_0().cmd_clear_depth_stencil_image)(
             command_buffer,
             image,
             image_layout,
@@ -1106,7 +1083,7 @@ pub trait DeviceV1_0 {
         attachments: &[vk::ClearAttachment],
         rects: &[vk::ClearRect],
     ) {
-        self.fp_v1_0().cmd_clear_attachments(
+        (self.fp_v1_0().cmd_clear_attachments)(
             command_buffer,
             attachments.len() as u32,
             attachments.as_ptr(),
@@ -1125,7 +1102,7 @@ pub trait DeviceV1_0 {
         vertex_offset: i32,
         first_instance: u32,
     ) {
-        self.fp_v1_0().cmd_draw_indexed(
+        (self.fp_v1_0().cmd_draw_indexed)(
             command_buffer,
             index_count,
             instance_count,
@@ -1144,7 +1121,7 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
-        self.fp_v1_0().cmd_draw_indexed_indirect(
+        (self.fp_v1_0().cmd_draw_indexed_indirect)(
             command_buffer,
             buffer,
             offset,
@@ -1159,7 +1136,7 @@ pub trait DeviceV1_0 {
         primary_command_buffer: vk::CommandBuffer,
         secondary_command_buffers: &[vk::CommandBuffer],
     ) {
-        self.fp_v1_0().cmd_execute_commands(
+        (self.fp_v1_0().cmd_execute_commands)(
             primary_command_buffer,
             secondary_command_buffers.len() as u32,
             secondary_command_buffers.as_ptr(),
@@ -1176,7 +1153,7 @@ pub trait DeviceV1_0 {
         descriptor_sets: &[vk::DescriptorSet],
         dynamic_offsets: &[u32],
     ) {
-        self.fp_v1_0().cmd_bind_descriptor_sets(
+        (self.fp_v1_0().cmd_bind_descriptor_sets)(
             command_buffer,
             pipeline_bind_point,
             layout,
@@ -1200,7 +1177,7 @@ pub trait DeviceV1_0 {
         stride: vk::DeviceSize,
         flags: vk::QueryResultFlags,
     ) {
-        self.fp_v1_0().cmd_copy_query_pool_results(
+        (self.fp_v1_0().cmd_copy_query_pool_results)(
             command_buffer,
             query_pool,
             first_query,
@@ -1221,7 +1198,7 @@ pub trait DeviceV1_0 {
         offset: u32,
         constants: &[u8],
     ) {
-        self.fp_v1_0().cmd_push_constants(
+        (self.fp_v1_0().cmd_push_constants)(
             command_buffer,
             layout,
             stage_flags,
@@ -1238,8 +1215,7 @@ pub trait DeviceV1_0 {
         create_info: &vk::RenderPassBeginInfo,
         contents: vk::SubpassContents,
     ) {
-        self.fp_v1_0()
-            .cmd_begin_render_pass(command_buffer, create_info, contents);
+        (self.fp_v1_0().cmd_begin_render_pass)(command_buffer, create_info, contents);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdNextSubpass.html>"]
@@ -1248,7 +1224,7 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         contents: vk::SubpassContents,
     ) {
-        self.fp_v1_0().cmd_next_subpass(command_buffer, contents);
+        (self.fp_v1_0().cmd_next_subpass)(command_buffer, contents);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindPipeline.html>"]
@@ -1258,8 +1234,7 @@ pub trait DeviceV1_0 {
         pipeline_bind_point: vk::PipelineBindPoint,
         pipeline: vk::Pipeline,
     ) {
-        self.fp_v1_0()
-            .cmd_bind_pipeline(command_buffer, pipeline_bind_point, pipeline);
+        (self.fp_v1_0().cmd_bind_pipeline)(command_buffer, pipeline_bind_point, pipeline);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetScissor.html>"]
@@ -1269,7 +1244,7 @@ pub trait DeviceV1_0 {
         first_scissor: u32,
         scissors: &[vk::Rect2D],
     ) {
-        self.fp_v1_0().cmd_set_scissor(
+        (self.fp_v1_0().cmd_set_scissor)(
             command_buffer,
             first_scissor,
             scissors.len() as u32,
@@ -1279,8 +1254,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetLineWidth.html>"]
     unsafe fn cmd_set_line_width(&self, command_buffer: vk::CommandBuffer, line_width: f32) {
-        self.fp_v1_0()
-            .cmd_set_line_width(command_buffer, line_width);
+        (self.fp_v1_0().cmd_set_line_width)(command_buffer, line_width);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindVertexBuffers.html>"]
@@ -1292,7 +1266,7 @@ pub trait DeviceV1_0 {
         offsets: &[vk::DeviceSize],
     ) {
         debug_assert_eq!(buffers.len(), offsets.len());
-        self.fp_v1_0().cmd_bind_vertex_buffers(
+        (self.fp_v1_0().cmd_bind_vertex_buffers)(
             command_buffer,
             first_binding,
             buffers.len() as u32,
@@ -1303,7 +1277,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass.html>"]
     unsafe fn cmd_end_render_pass(&self, command_buffer: vk::CommandBuffer) {
-        self.fp_v1_0().cmd_end_render_pass(command_buffer);
+        (self.fp_v1_0().cmd_end_render_pass)(command_buffer);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDraw.html>"]
@@ -1315,7 +1289,7 @@ pub trait DeviceV1_0 {
         first_vertex: u32,
         first_instance: u32,
     ) {
-        self.fp_v1_0().cmd_draw(
+        (self.fp_v1_0().cmd_draw)(
             command_buffer,
             vertex_count,
             instance_count,
@@ -1333,8 +1307,7 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_draw_indirect(command_buffer, buffer, offset, draw_count, stride);
+        (self.fp_v1_0().cmd_draw_indirect)(command_buffer, buffer, offset, draw_count, stride);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatch.html>"]
@@ -1345,8 +1318,7 @@ pub trait DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_dispatch(command_buffer, group_count_x, group_count_y, group_count_z);
+        (self.fp_v1_0().cmd_dispatch)(command_buffer, group_count_x, group_count_y, group_count_z);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatchIndirect.html>"]
@@ -1356,8 +1328,7 @@ pub trait DeviceV1_0 {
         buffer: vk::Buffer,
         offset: vk::DeviceSize,
     ) {
-        self.fp_v1_0()
-            .cmd_dispatch_indirect(command_buffer, buffer, offset);
+        (self.fp_v1_0().cmd_dispatch_indirect)(command_buffer, buffer, offset);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetViewport.html>"]
@@ -1367,7 +1338,7 @@ pub trait DeviceV1_0 {
         first_viewport: u32,
         viewports: &[vk::Viewport],
     ) {
-        self.fp_v1_0().cmd_set_viewport(
+        (self.fp_v1_0().cmd_set_viewport)(
             command_buffer,
             first_viewport,
             viewports.len() as u32,
@@ -1383,8 +1354,7 @@ pub trait DeviceV1_0 {
         clamp: f32,
         slope_factor: f32,
     ) {
-        self.fp_v1_0()
-            .cmd_set_depth_bias(command_buffer, constant_factor, clamp, slope_factor);
+        (self.fp_v1_0().cmd_set_depth_bias)(command_buffer, constant_factor, clamp, slope_factor);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetBlendConstants.html>"]
@@ -1393,8 +1363,7 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         blend_constants: &[f32; 4],
     ) {
-        self.fp_v1_0()
-            .cmd_set_blend_constants(command_buffer, blend_constants);
+        (self.fp_v1_0().cmd_set_blend_constants)(command_buffer, blend_constants);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthBounds.html>"]
@@ -1404,8 +1373,7 @@ pub trait DeviceV1_0 {
         min_depth_bounds: f32,
         max_depth_bounds: f32,
     ) {
-        self.fp_v1_0()
-            .cmd_set_depth_bounds(command_buffer, min_depth_bounds, max_depth_bounds);
+        (self.fp_v1_0().cmd_set_depth_bounds)(command_buffer, min_depth_bounds, max_depth_bounds);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilCompareMask.html>"]
@@ -1415,8 +1383,7 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         compare_mask: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_set_stencil_compare_mask(command_buffer, face_mask, compare_mask);
+        (self.fp_v1_0().cmd_set_stencil_compare_mask)(command_buffer, face_mask, compare_mask);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilWriteMask.html>"]
@@ -1426,8 +1393,7 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         write_mask: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_set_stencil_write_mask(command_buffer, face_mask, write_mask);
+        (self.fp_v1_0().cmd_set_stencil_write_mask)(command_buffer, face_mask, write_mask);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilReference.html>"]
@@ -1437,8 +1403,7 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         reference: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_set_stencil_reference(command_buffer, face_mask, reference);
+        (self.fp_v1_0().cmd_set_stencil_reference)(command_buffer, face_mask, reference);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetQueryPoolResults.html>"]
@@ -1460,7 +1425,7 @@ pub trait DeviceV1_0 {
             "query_count was higher than the length of the slice"
         );
         let data_size = mem::size_of::<T>() * data_length;
-        let err_code = self.fp_v1_0().get_query_pool_results(
+        let err_code = (self.fp_v1_0().get_query_pool_results)(
             self.handle(),
             query_pool,
             first_query,
@@ -1485,8 +1450,7 @@ pub trait DeviceV1_0 {
         query: u32,
         flags: vk::QueryControlFlags,
     ) {
-        self.fp_v1_0()
-            .cmd_begin_query(command_buffer, query_pool, query, flags);
+        (self.fp_v1_0().cmd_begin_query)(command_buffer, query_pool, query, flags);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndQuery.html>"]
@@ -1496,8 +1460,7 @@ pub trait DeviceV1_0 {
         query_pool: vk::QueryPool,
         query: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_end_query(command_buffer, query_pool, query);
+        (self.fp_v1_0().cmd_end_query)(command_buffer, query_pool, query);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResetQueryPool.html>"]
@@ -1508,8 +1471,7 @@ pub trait DeviceV1_0 {
         first_query: u32,
         query_count: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_reset_query_pool(command_buffer, pool, first_query, query_count);
+        (self.fp_v1_0().cmd_reset_query_pool)(command_buffer, pool, first_query, query_count);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteTimestamp.html>"]
@@ -1520,8 +1482,7 @@ pub trait DeviceV1_0 {
         query_pool: vk::QueryPool,
         query: u32,
     ) {
-        self.fp_v1_0()
-            .cmd_write_timestamp(command_buffer, pipeline_stage, query_pool, query);
+        (self.fp_v1_0().cmd_write_timestamp)(command_buffer, pipeline_stage, query_pool, query);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateSemaphore.html>"]
@@ -1531,7 +1492,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Semaphore> {
         let mut semaphore = mem::zeroed();
-        let err_code = self.fp_v1_0().create_semaphore(
+        let err_code = (self.fp_v1_0().create_semaphore)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1551,7 +1512,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
         let mut pipelines = Vec::with_capacity(create_infos.len());
-        let err_code = self.fp_v1_0().create_graphics_pipelines(
+        let err_code = (self.fp_v1_0().create_graphics_pipelines)(
             self.handle(),
             pipeline_cache,
             create_infos.len() as u32,
@@ -1574,7 +1535,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
         let mut pipelines = Vec::with_capacity(create_infos.len());
-        let err_code = self.fp_v1_0().create_compute_pipelines(
+        let err_code = (self.fp_v1_0().create_compute_pipelines)(
             self.handle(),
             pipeline_cache,
             create_infos.len() as u32,
@@ -1596,7 +1557,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Buffer> {
         let mut buffer = mem::zeroed();
-        let err_code = self.fp_v1_0().create_buffer(
+        let err_code = (self.fp_v1_0().create_buffer)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1615,7 +1576,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::PipelineLayout> {
         let mut pipeline_layout = mem::zeroed();
-        let err_code = self.fp_v1_0().create_pipeline_layout(
+        let err_code = (self.fp_v1_0().create_pipeline_layout)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1634,7 +1595,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::PipelineCache> {
         let mut pipeline_cache = mem::zeroed();
-        let err_code = self.fp_v1_0().create_pipeline_cache(
+        let err_code = (self.fp_v1_0().create_pipeline_cache)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1653,7 +1614,7 @@ pub trait DeviceV1_0 {
         pipeline_cache: vk::PipelineCache,
     ) -> VkResult<Vec<u8>> {
         let mut data_size: usize = 0;
-        let err_code = self.fp_v1_0().get_pipeline_cache_data(
+        let err_code = (self.fp_v1_0().get_pipeline_cache_data)(
             self.handle(),
             pipeline_cache,
             &mut data_size,
@@ -1663,7 +1624,7 @@ pub trait DeviceV1_0 {
             return Err(err_code);
         };
         let mut data: Vec<u8> = Vec::with_capacity(data_size);
-        let err_code = self.fp_v1_0().get_pipeline_cache_data(
+        let err_code = (self.fp_v1_0().get_pipeline_cache_data)(
             self.handle(),
             pipeline_cache,
             &mut data_size,
@@ -1686,8 +1647,7 @@ pub trait DeviceV1_0 {
     ) -> VkResult<*mut c_void> {
         let mut data: *mut c_void = ptr::null_mut();
         let err_code =
-            self.fp_v1_0()
-                .map_memory(self.handle(), memory, offset, size, flags, &mut data);
+            (self.fp_v1_0().map_memory)(self.handle(), memory, offset, size, flags, &mut data);
         match err_code {
             vk::Result::SUCCESS => Ok(data),
             _ => Err(err_code),
@@ -1696,7 +1656,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkUnmapMemory.html>"]
     unsafe fn unmap_memory(&self, memory: vk::DeviceMemory) {
-        self.fp_v1_0().unmap_memory(self.handle(), memory);
+        (self.fp_v1_0().unmap_memory)(self.handle(), memory);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkInvalidateMappedMemoryRanges.html>"]
@@ -1704,7 +1664,7 @@ pub trait DeviceV1_0 {
         &self,
         ranges: &[vk::MappedMemoryRange],
     ) -> VkResult<()> {
-        let err_code = self.fp_v1_0().invalidate_mapped_memory_ranges(
+        let err_code = (self.fp_v1_0().invalidate_mapped_memory_ranges)(
             self.handle(),
             ranges.len() as u32,
             ranges.as_ptr(),
@@ -1717,7 +1677,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkFlushMappedMemoryRanges.html>"]
     unsafe fn flush_mapped_memory_ranges(&self, ranges: &[vk::MappedMemoryRange]) -> VkResult<()> {
-        let err_code = self.fp_v1_0().flush_mapped_memory_ranges(
+        let err_code = (self.fp_v1_0().flush_mapped_memory_ranges)(
             self.handle(),
             ranges.len() as u32,
             ranges.as_ptr(),
@@ -1735,7 +1695,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Framebuffer> {
         let mut framebuffer = mem::zeroed();
-        let err_code = self.fp_v1_0().create_framebuffer(
+        let err_code = (self.fp_v1_0().create_framebuffer)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1750,8 +1710,12 @@ pub trait DeviceV1_0 {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetDeviceQueue.html>"]
     unsafe fn get_device_queue(&self, queue_family_index: u32, queue_index: u32) -> vk::Queue {
         let mut queue = mem::zeroed();
-        self.fp_v1_0()
-            .get_device_queue(self.handle(), queue_family_index, queue_index, &mut queue);
+        (self.fp_v1_0().get_device_queue)(
+            self.handle(),
+            queue_family_index,
+            queue_index,
+            &mut queue,
+        );
         queue
     }
 
@@ -1766,7 +1730,7 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         image_memory_barriers: &[vk::ImageMemoryBarrier],
     ) {
-        self.fp_v1_0().cmd_pipeline_barrier(
+        (self.fp_v1_0().cmd_pipeline_barrier)(
             command_buffer,
             src_stage_mask,
             dst_stage_mask,
@@ -1787,7 +1751,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::RenderPass> {
         let mut renderpass = mem::zeroed();
-        let err_code = self.fp_v1_0().create_render_pass(
+        let err_code = (self.fp_v1_0().create_render_pass)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1805,9 +1769,7 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         begin_info: &vk::CommandBufferBeginInfo,
     ) -> VkResult<()> {
-        let err_code = self
-            .fp_v1_0()
-            .begin_command_buffer(command_buffer, begin_info);
+        let err_code = (self.fp_v1_0().begin_command_buffer)(command_buffer, begin_info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1816,7 +1778,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkEndCommandBuffer.html>"]
     unsafe fn end_command_buffer(&self, command_buffer: vk::CommandBuffer) -> VkResult<()> {
-        let err_code = self.fp_v1_0().end_command_buffer(command_buffer);
+        let err_code = (self.fp_v1_0().end_command_buffer)(command_buffer);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1830,7 +1792,7 @@ pub trait DeviceV1_0 {
         wait_all: bool,
         timeout: u64,
     ) -> VkResult<()> {
-        let err_code = self.fp_v1_0().wait_for_fences(
+        let err_code = (self.fp_v1_0().wait_for_fences)(
             self.handle(),
             fences.len() as u32,
             fences.as_ptr(),
@@ -1845,7 +1807,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetFenceStatus.html>"]
     unsafe fn get_fence_status(&self, fence: vk::Fence) -> VkResult<bool> {
-        let err_code = self.fp_v1_0().get_fence_status(self.handle(), fence);
+        let err_code = (self.fp_v1_0().get_fence_status)(self.handle(), fence);
         match err_code {
             vk::Result::SUCCESS => Ok(true),
             vk::Result::NOT_READY => Ok(false),
@@ -1855,7 +1817,7 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkQueueWaitIdle.html>"]
     unsafe fn queue_wait_idle(&self, queue: vk::Queue) -> VkResult<()> {
-        let err_code = self.fp_v1_0().queue_wait_idle(queue);
+        let err_code = (self.fp_v1_0().queue_wait_idle)(queue);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1870,8 +1832,7 @@ pub trait DeviceV1_0 {
         fence: vk::Fence,
     ) -> VkResult<()> {
         let err_code =
-            self.fp_v1_0()
-                .queue_submit(queue, submits.len() as u32, submits.as_ptr(), fence);
+            (self.fp_v1_0().queue_submit)(queue, submits.len() as u32, submits.as_ptr(), fence);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -1885,7 +1846,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::BufferView> {
         let mut buffer_view = mem::zeroed();
-        let err_code = self.fp_v1_0().create_buffer_view(
+        let err_code = (self.fp_v1_0().create_buffer_view)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1903,7 +1864,7 @@ pub trait DeviceV1_0 {
         buffer_view: vk::BufferView,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.fp_v1_0().destroy_buffer_view(
+        (self.fp_v1_0().destroy_buffer_view)(
             self.handle(),
             buffer_view,
             allocation_callbacks.as_raw_ptr(),
@@ -1917,7 +1878,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::ImageView> {
         let mut image_view = mem::zeroed();
-        let err_code = self.fp_v1_0().create_image_view(
+        let err_code = (self.fp_v1_0().create_image_view)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1935,7 +1896,7 @@ pub trait DeviceV1_0 {
         create_info: &vk::CommandBufferAllocateInfo,
     ) -> VkResult<Vec<vk::CommandBuffer>> {
         let mut buffers = Vec::with_capacity(create_info.command_buffer_count as usize);
-        let err_code = self.fp_v1_0().allocate_command_buffers(
+        let err_code = (self.fp_v1_0().allocate_command_buffers)(
             self.handle(),
             create_info,
             buffers.as_mut_ptr(),
@@ -1954,7 +1915,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::CommandPool> {
         let mut pool = mem::zeroed();
-        let err_code = self.fp_v1_0().create_command_pool(
+        let err_code = (self.fp_v1_0().create_command_pool)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1973,7 +1934,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::QueryPool> {
         let mut pool = mem::zeroed();
-        let err_code = self.fp_v1_0().create_query_pool(
+        let err_code = (self.fp_v1_0().create_query_pool)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -1992,7 +1953,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Image> {
         let mut image = mem::zeroed();
-        let err_code = self.fp_v1_0().create_image(
+        let err_code = (self.fp_v1_0().create_image)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -2011,7 +1972,7 @@ pub trait DeviceV1_0 {
         subresource: vk::ImageSubresource,
     ) -> vk::SubresourceLayout {
         let mut layout = mem::zeroed();
-        self.fp_v1_0().get_image_subresource_layout(
+        (self.fp_v1_0().get_image_subresource_layout)(
             self.handle(),
             image,
             &subresource,
@@ -2023,16 +1984,14 @@ pub trait DeviceV1_0 {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetImageMemoryRequirements.html>"]
     unsafe fn get_image_memory_requirements(&self, image: vk::Image) -> vk::MemoryRequirements {
         let mut mem_req = mem::zeroed();
-        self.fp_v1_0()
-            .get_image_memory_requirements(self.handle(), image, &mut mem_req);
+        (self.fp_v1_0().get_image_memory_requirements)(self.handle(), image, &mut mem_req);
         mem_req
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetBufferMemoryRequirements.html>"]
     unsafe fn get_buffer_memory_requirements(&self, buffer: vk::Buffer) -> vk::MemoryRequirements {
         let mut mem_req = mem::zeroed();
-        self.fp_v1_0()
-            .get_buffer_memory_requirements(self.handle(), buffer, &mut mem_req);
+        (self.fp_v1_0().get_buffer_memory_requirements)(self.handle(), buffer, &mut mem_req);
         mem_req
     }
 
@@ -2043,7 +2002,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DeviceMemory> {
         let mut memory = mem::zeroed();
-        let err_code = self.fp_v1_0().allocate_memory(
+        let err_code = (self.fp_v1_0().allocate_memory)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -2062,7 +2021,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::ShaderModule> {
         let mut shader = mem::zeroed();
-        let err_code = self.fp_v1_0().create_shader_module(
+        let err_code = (self.fp_v1_0().create_shader_module)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -2081,7 +2040,7 @@ pub trait DeviceV1_0 {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Fence> {
         let mut fence = mem::zeroed();
-        let err_code = self.fp_v1_0().create_fence(
+        let err_code = (self.fp_v1_0().create_fence)(
             self.handle(),
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -2101,8 +2060,7 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
     ) -> VkResult<()> {
         let err_code =
-            self.fp_v1_0()
-                .bind_buffer_memory(self.handle(), buffer, device_memory, offset);
+            (self.fp_v1_0().bind_buffer_memory)(self.handle(), buffer, device_memory, offset);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -2117,8 +2075,7 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
     ) -> VkResult<()> {
         let err_code =
-            self.fp_v1_0()
-                .bind_image_memory(self.handle(), image, device_memory, offset);
+            (self.fp_v1_0().bind_image_memory)(self.handle(), image, device_memory, offset);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -2131,25 +2088,31 @@ pub trait DeviceV1_0 {
 pub struct Device {
     handle: vk::Device,
     device_fn_1_0: vk::DeviceFnV1_0,
+    #[cfg(feature = "V1_1")]
     device_fn_1_1: vk::DeviceFnV1_1,
+    #[cfg(feature = "V1_2")]
     device_fn_1_2: vk::DeviceFnV1_2,
 }
 
 impl Device {
     pub unsafe fn load(instance_fn: &vk::InstanceFnV1_0, device: vk::Device) -> Self {
         let device_fn_1_0 = vk::DeviceFnV1_0::load(|name| {
-            mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
+            mem::transmute((instance_fn.get_device_proc_addr)(device, name.as_ptr()))
         });
+        #[cfg(feature = "V1_1")]
         let device_fn_1_1 = vk::DeviceFnV1_1::load(|name| {
-            mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
+            mem::transmute((instance_fn.get_device_proc_addr)(device, name.as_ptr()))
         });
+        #[cfg(feature = "V1_2")]
         let device_fn_1_2 = vk::DeviceFnV1_2::load(|name| {
-            mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
+            mem::transmute((instance_fn.get_device_proc_addr)(device, name.as_ptr()))
         });
         Device {
             handle: device,
             device_fn_1_0,
+            #[cfg(feature = "V1_1")]
             device_fn_1_1,
+            #[cfg(feature = "V1_2")]
             device_fn_1_2,
         }
     }
@@ -2165,12 +2128,14 @@ impl DeviceV1_0 for Device {
     }
 }
 
+#[cfg(feature = "V1_1")]
 impl DeviceV1_1 for Device {
     fn fp_v1_1(&self) -> &vk::DeviceFnV1_1 {
         &self.device_fn_1_1
     }
 }
 
+#[cfg(feature = "V1_2")]
 impl DeviceV1_2 for Device {
     fn fp_v1_2(&self) -> &vk::DeviceFnV1_2 {
         &self.device_fn_1_2
@@ -35,7 +35,9 @@ pub type Entry = EntryCustom<Arc<Library>>;
 pub struct EntryCustom<L> {
     static_fn: vk::StaticFn,
     entry_fn_1_0: vk::EntryFnV1_0,
+    #[cfg(feature = "V1_1")]
     entry_fn_1_1: vk::EntryFnV1_1,
+    #[cfg(feature = "V1_2")]
     entry_fn_1_2: vk::EntryFnV1_2,
     lib: L,
 }
@@ -93,13 +95,11 @@ pub trait EntryV1_0 {
     fn enumerate_instance_layer_properties(&self) -> VkResult<Vec<vk::LayerProperties>> {
         unsafe {
             let mut num = 0;
-            self.fp_v1_0()
-                .enumerate_instance_layer_properties(&mut num, ptr::null_mut());
+            (self.fp_v1_0().enumerate_instance_layer_properties)(&mut num, ptr::null_mut());
 
             let mut v = Vec::with_capacity(num as usize);
-            let err_code = self
-                .fp_v1_0()
-                .enumerate_instance_layer_properties(&mut num, v.as_mut_ptr());
+            let err_code =
+                (self.fp_v1_0().enumerate_instance_layer_properties)(&mut num, v.as_mut_ptr());
             v.set_len(num as usize);
             match err_code {
                 vk::Result::SUCCESS => Ok(v),
@@ -112,13 +112,13 @@ pub trait EntryV1_0 {
     fn enumerate_instance_extension_properties(&self) -> VkResult<Vec<vk::ExtensionProperties>> {
         unsafe {
             let mut num = 0;
-            self.fp_v1_0().enumerate_instance_extension_properties(
+            (self.fp_v1_0().enumerate_instance_extension_properties)(
                 ptr::null(),
                 &mut num,
                 ptr::null_mut(),
             );
             let mut data = Vec::with_capacity(num as usize);
-            let err_code = self.fp_v1_0().enumerate_instance_extension_properties(
+            let err_code = (self.fp_v1_0().enumerate_instance_extension_properties)(
                 ptr::null(),
                 &mut num,
                 data.as_mut_ptr(),
@@ -155,7 +155,7 @@ impl<L> EntryV1_0 for EntryCustom<L> {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Self::Instance, InstanceError> {
         let mut instance: vk::Instance = mem::zeroed();
-        let err_code = self.fp_v1_0().create_instance(
+        let err_code = (self.fp_v1_0().create_instance)(
             create_info,
             allocation_callbacks.as_raw_ptr(),
             &mut instance,
@@ -173,6 +173,7 @@ impl<L> EntryV1_0 for EntryCustom<L> {
     }
 }
 
+#[cfg(feature = "V1_1")]
 #[allow(non_camel_case_types)]
 pub trait EntryV1_1: EntryV1_0 {
     fn fp_v1_1(&self) -> &vk::EntryFnV1_1;
@@ -182,7 +183,7 @@ pub trait EntryV1_1: EntryV1_0 {
     fn enumerate_instance_version(&self) -> VkResult<u32> {
         unsafe {
             let mut api_version = 0;
-            let err_code = self.fp_v1_1().enumerate_instance_version(&mut api_version);
+            let err_code = (self.fp_v1_1().enumerate_instance_version)(&mut api_version);
             match err_code {
                 vk::Result::SUCCESS => Ok(api_version),
                 _ => Err(err_code),
@@ -191,17 +192,20 @@ pub trait EntryV1_1: EntryV1_0 {
     }
 }
 
+#[cfg(feature = "V1_1")]
 impl<L> EntryV1_1 for EntryCustom<L> {
     fn fp_v1_1(&self) -> &vk::EntryFnV1_1 {
         &self.entry_fn_1_1
     }
 }
 
+#[cfg(feature = "V1_2")]
 #[allow(non_camel_case_types)]
 pub trait EntryV1_2: EntryV1_1 {
     fn fp_v1_2(&self) -> &vk::EntryFnV1_2;
 }
 
+#[cfg(feature = "V1_2")]
 impl<L> EntryV1_2 for EntryCustom<L> {
     fn fp_v1_2(&self) -> &vk::EntryFnV1_2 {
         &self.entry_fn_1_2
@@ -214,7 +218,7 @@ impl EntryCustom<Arc<Library>> {
     /// # fn main() -> Result<(), Box<std::error::Error>> {
     /// let entry = Entry::new()?;
     /// let app_info = vk::ApplicationInfo {
-    ///     api_version: vk::make_version(1, 0, 0),
+    ///     api_version: vk::make_api_version(0, 1, 0, 0),
     ///     ..Default::default()
     /// };
     /// let create_info = vk::InstanceCreateInfo {
@@ -251,21 +255,34 @@ impl<L> EntryCustom<L> {
         let static_fn = vk::StaticFn::load(|name| load(&mut lib, name));
 
         let entry_fn_1_0 = vk::EntryFnV1_0::load(|name| unsafe {
-            mem::transmute(static_fn.get_instance_proc_addr(vk::Instance::null(), name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(
+                vk::Instance::null(),
+                name.as_ptr(),
+            ))
         });
 
+        #[cfg(feature = "V1_1")]
         let entry_fn_1_1 = vk::EntryFnV1_1::load(|name| unsafe {
-            mem::transmute(static_fn.get_instance_proc_addr(vk::Instance::null(), name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(
+                vk::Instance::null(),
+                name.as_ptr(),
+            ))
         });
 
+        #[cfg(feature = "V1_2")]
         let entry_fn_1_2 = vk::EntryFnV1_2::load(|name| unsafe {
-            mem::transmute(static_fn.get_instance_proc_addr(vk::Instance::null(), name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(
+                vk::Instance::null(),
+                name.as_ptr(),
+            ))
         });
 
         Ok(EntryCustom {
             static_fn,
             entry_fn_1_0,
+            #[cfg(feature = "V1_1")]
             entry_fn_1_1,
+            #[cfg(feature = "V1_2")]
             entry_fn_1_2,
             lib,
         })
@@ -279,9 +296,9 @@ impl<L> EntryCustom<L> {
     /// match entry.try_enumerate_instance_version()? {
     ///     // Vulkan 1.1+
     ///     Some(version) => {
-    ///         let major = vk::version_major(version);
-    ///         let minor = vk::version_minor(version);
-    ///         let patch = vk::version_patch(version);
+    ///         let major = vk::api_version_major(version);
+    ///         let minor = vk::api_version_minor(version);
+    ///         let patch = vk::api_version_patch(version);
     ///     },
     ///     // Vulkan 1.0
     ///     None => {},
@@ -28,9 +28,7 @@ impl DebugMarker {
         device: vk::Device,
         name_info: &vk::DebugMarkerObjectNameInfoEXT,
     ) -> VkResult<()> {
-        let err_code = self
-            .debug_marker_fn
-            .debug_marker_set_object_name_ext(device, name_info);
+        let err_code = (self.debug_marker_fn.debug_marker_set_object_name_ext)(device, name_info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -43,14 +41,12 @@ impl DebugMarker {
         command_buffer: vk::CommandBuffer,
         marker_info: &vk::DebugMarkerMarkerInfoEXT,
     ) {
-        self.debug_marker_fn
-            .cmd_debug_marker_begin_ext(command_buffer, marker_info);
+        (self.debug_marker_fn.cmd_debug_marker_begin_ext)(command_buffer, marker_info);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDebugMarkerEndEXT.html>"]
     pub unsafe fn cmd_debug_marker_end(&self, command_buffer: vk::CommandBuffer) {
-        self.debug_marker_fn
-            .cmd_debug_marker_end_ext(command_buffer);
+        (self.debug_marker_fn.cmd_debug_marker_end_ext)(command_buffer);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDebugMarkerInsertEXT.html>"]
@@ -59,8 +55,7 @@ impl DebugMarker {
         command_buffer: vk::CommandBuffer,
         marker_info: &vk::DebugMarkerMarkerInfoEXT,
     ) {
-        self.debug_marker_fn
-            .cmd_debug_marker_insert_ext(command_buffer, marker_info);
+        (self.debug_marker_fn.cmd_debug_marker_insert_ext)(command_buffer, marker_info);
     }
 
     pub fn fp(&self) -> &vk::ExtDebugMarkerFn {
@@ -6,12 +6,15 @@ use crate::RawPtr;
 use std::ffi::CStr;
 use std::mem;
 
+// The extension is deprecated, but stays available to existing users.
+#[allow(deprecated)]
 #[derive(Clone)]
 pub struct DebugReport {
     handle: vk::Instance,
     debug_report_fn: vk::ExtDebugReportFn,
 }
 
+#[allow(deprecated)]
 impl DebugReport {
     pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> DebugReport {
         let debug_report_fn = vk::ExtDebugReportFn::load(|name| unsafe {
@@ -33,7 +36,7 @@ impl DebugReport {
         debug: vk::DebugReportCallbackEXT,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.debug_report_fn.destroy_debug_report_callback_ext(
+        (self.debug_report_fn.destroy_debug_report_callback_ext)(
             self.handle,
             debug,
             allocation_callbacks.as_raw_ptr(),
@@ -47,7 +50,7 @@ impl DebugReport {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DebugReportCallbackEXT> {
         let mut debug_cb = mem::zeroed();
-        let err_code = self.debug_report_fn.create_debug_report_callback_ext(
+        let err_code = (self.debug_report_fn.create_debug_report_callback_ext)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -32,9 +32,7 @@ impl DebugUtils {
         device: vk::Device,
         name_info: &vk::DebugUtilsObjectNameInfoEXT,
     ) -> VkResult<()> {
-        let err_code = self
-            .debug_utils_fn
-            .set_debug_utils_object_name_ext(device, name_info);
+        let err_code = (self.debug_utils_fn.set_debug_utils_object_name_ext)(device, name_info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -47,9 +45,7 @@ impl DebugUtils {
         device: vk::Device,
         tag_info: &vk::DebugUtilsObjectTagInfoEXT,
     ) -> VkResult<()> {
-        let err_code = self
-            .debug_utils_fn
-            .set_debug_utils_object_tag_ext(device, tag_info);
+        let err_code = (self.debug_utils_fn.set_debug_utils_object_tag_ext)(device, tag_info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -62,14 +58,12 @@ impl DebugUtils {
         command_buffer: vk::CommandBuffer,
         label: &vk::DebugUtilsLabelEXT,
     ) {
-        self.debug_utils_fn
-            .cmd_begin_debug_utils_label_ext(command_buffer, label);
+        (self.debug_utils_fn.cmd_begin_debug_utils_label_ext)(command_buffer, label);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html>"]
     pub unsafe fn cmd_end_debug_utils_label(&self, command_buffer: vk::CommandBuffer) {
-        self.debug_utils_fn
-            .cmd_end_debug_utils_label_ext(command_buffer);
+        (self.debug_utils_fn.cmd_end_debug_utils_label_ext)(command_buffer);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdInsertDebugUtilsLabelEXT.html>"]
@@ -78,8 +72,7 @@ impl DebugUtils {
         command_buffer: vk::CommandBuffer,
         label: &vk::DebugUtilsLabelEXT,
     ) {
-        self.debug_utils_fn
-            .cmd_insert_debug_utils_label_ext(command_buffer, label);
+        (self.debug_utils_fn.cmd_insert_debug_utils_label_ext)(command_buffer, label);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkQueueBeginDebugUtilsLabelEXT.html>"]
@@ -88,13 +81,12 @@ impl DebugUtils {
         queue: vk::Queue,
         label: &vk::DebugUtilsLabelEXT,
     ) {
-        self.debug_utils_fn
-            .queue_begin_debug_utils_label_ext(queue, label);
+        (self.debug_utils_fn.queue_begin_debug_utils_label_ext)(queue, label);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkQueueEndDebugUtilsLabelEXT.html>"]
     pub unsafe fn queue_end_debug_utils_label(&self, queue: vk::Queue) {
-        self.debug_utils_fn.queue_end_debug_utils_label_ext(queue);
+        (self.debug_utils_fn.queue_end_debug_utils_label_ext)(queue);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkQueueInsertDebugUtilsLabelEXT.html>"]
@@ -103,8 +95,7 @@ impl DebugUtils {
         queue: vk::Queue,
         label: &vk::DebugUtilsLabelEXT,
     ) {
-        self.debug_utils_fn
-            .queue_insert_debug_utils_label_ext(queue, label);
+        (self.debug_utils_fn.queue_insert_debug_utils_label_ext)(queue, label);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>"]
@@ -114,7 +105,7 @@ impl DebugUtils {
         allocator: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DebugUtilsMessengerEXT> {
         let mut messenger = mem::zeroed();
-        let err_code = self.debug_utils_fn.create_debug_utils_messenger_ext(
+        let err_code = (self.debug_utils_fn.create_debug_utils_messenger_ext)(
             self.handle,
             create_info,
             allocator.as_raw_ptr(),
@@ -132,7 +123,7 @@ impl DebugUtils {
         messenger: vk::DebugUtilsMessengerEXT,
         allocator: Option<&vk::AllocationCallbacks>,
     ) {
-        self.debug_utils_fn.destroy_debug_utils_messenger_ext(
+        (self.debug_utils_fn.destroy_debug_utils_messenger_ext)(
             self.handle,
             messenger,
             allocator.as_raw_ptr(),
@@ -147,7 +138,7 @@ impl DebugUtils {
         message_types: vk::DebugUtilsMessageTypeFlagsEXT,
         callback_data: &vk::DebugUtilsMessengerCallbackDataEXT,
     ) {
-        self.debug_utils_fn.submit_debug_utils_message_ext(
+        (self.debug_utils_fn.submit_debug_utils_message_ext)(
             instance,
             message_severity,
             message_types,
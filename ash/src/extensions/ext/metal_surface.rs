@@ -34,7 +34,7 @@ impl MetalSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.metal_surface_fn.create_metal_surface_ext(
+        let err_code = (self.metal_surface_fn.create_metal_surface_ext)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
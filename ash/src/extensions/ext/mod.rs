@@ -1,9 +1,11 @@
 pub use self::debug_marker::DebugMarker;
 pub use self::debug_report::DebugReport;
 pub use self::debug_utils::DebugUtils;
+#[cfg(feature = "VK_USE_PLATFORM_METAL_EXT")]
 pub use self::metal_surface::MetalSurface;
 
 mod debug_marker;
 mod debug_report;
 mod debug_utils;
+#[cfg(feature = "VK_USE_PLATFORM_METAL_EXT")]
 mod metal_surface;
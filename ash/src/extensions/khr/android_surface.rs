@@ -34,7 +34,7 @@ impl AndroidSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.android_surface_fn.create_android_surface_khr(
+        let err_code = (self.android_surface_fn.create_android_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -33,13 +33,13 @@ impl Display {
         physical_device: vk::PhysicalDevice,
     ) -> VkResult<Vec<vk::DisplayPropertiesKHR>> {
         let mut count = 0;
-        self.display_fn.get_physical_device_display_properties_khr(
+        (self.display_fn.get_physical_device_display_properties_khr)(
             physical_device,
             &mut count,
             ptr::null_mut(),
         );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.display_fn.get_physical_device_display_properties_khr(
+        let err_code = (self.display_fn.get_physical_device_display_properties_khr)(
             physical_device,
             &mut count,
             v.as_mut_ptr(),
@@ -57,20 +57,21 @@ impl Display {
         physical_device: vk::PhysicalDevice,
     ) -> VkResult<Vec<vk::DisplayPlanePropertiesKHR>> {
         let mut count = 0;
-        self.display_fn
-            .get_physical_device_display_plane_properties_khr(
-                physical_device,
-                &mut count,
-                ptr::null_mut(),
-            );
+        (self
+            .display_fn
+            .get_physical_device_display_plane_properties_khr)(
+            physical_device,
+            &mut count,
+            ptr::null_mut(),
+        );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self
+        let err_code = (self
             .display_fn
-            .get_physical_device_display_plane_properties_khr(
-                physical_device,
-                &mut count,
-                v.as_mut_ptr(),
-            );
+            .get_physical_device_display_plane_properties_khr)(
+            physical_device,
+            &mut count,
+            v.as_mut_ptr(),
+        );
         v.set_len(count as usize);
         match err_code {
             vk::Result::SUCCESS => Ok(v),
@@ -85,14 +86,14 @@ impl Display {
         plane_index: u32,
     ) -> VkResult<Vec<vk::DisplayKHR>> {
         let mut count = 0;
-        self.display_fn.get_display_plane_supported_displays_khr(
+        (self.display_fn.get_display_plane_supported_displays_khr)(
             physical_device,
             plane_index,
             &mut count,
             ptr::null_mut(),
         );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.display_fn.get_display_plane_supported_displays_khr(
+        let err_code = (self.display_fn.get_display_plane_supported_displays_khr)(
             physical_device,
             plane_index,
             &mut count,
@@ -112,14 +113,14 @@ impl Display {
         display: vk::DisplayKHR,
     ) -> VkResult<Vec<vk::DisplayModePropertiesKHR>> {
         let mut count = 0;
-        self.display_fn.get_display_mode_properties_khr(
+        (self.display_fn.get_display_mode_properties_khr)(
             physical_device,
             display,
             &mut count,
             ptr::null_mut(),
         );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.display_fn.get_display_mode_properties_khr(
+        let err_code = (self.display_fn.get_display_mode_properties_khr)(
             physical_device,
             display,
             &mut count,
@@ -141,7 +142,7 @@ impl Display {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DisplayModeKHR> {
         let mut display_mode = mem::MaybeUninit::zeroed();
-        let err_code = self.display_fn.create_display_mode_khr(
+        let err_code = (self.display_fn.create_display_mode_khr)(
             physical_device,
             display,
             create_info,
@@ -162,7 +163,7 @@ impl Display {
         plane_index: u32,
     ) -> VkResult<vk::DisplayPlaneCapabilitiesKHR> {
         let mut display_plane_capabilities = mem::MaybeUninit::zeroed();
-        let err_code = self.display_fn.get_display_plane_capabilities_khr(
+        let err_code = (self.display_fn.get_display_plane_capabilities_khr)(
             physical_device,
             mode,
             plane_index,
@@ -181,7 +182,7 @@ impl Display {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::MaybeUninit::zeroed();
-        let err_code = self.display_fn.create_display_plane_surface_khr(
+        let err_code = (self.display_fn.create_display_plane_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -29,8 +29,7 @@ impl ExternalMemoryFd {
     pub unsafe fn get_memory_fd(&self, create_info: &vk::MemoryGetFdInfoKHR) -> VkResult<i32> {
         let mut fd = -1;
         let err_code =
-            self.external_memory_fd_fn
-                .get_memory_fd_khr(self.handle, create_info, &mut fd);
+            (self.external_memory_fd_fn.get_memory_fd_khr)(self.handle, create_info, &mut fd);
         match err_code {
             vk::Result::SUCCESS => Ok(fd),
             _ => Err(err_code),
@@ -44,7 +43,7 @@ impl ExternalMemoryFd {
         fd: i32,
     ) -> VkResult<vk::MemoryFdPropertiesKHR> {
         let mut memory_fd_properties = mem::zeroed();
-        let err_code = self.external_memory_fd_fn.get_memory_fd_properties_khr(
+        let err_code = (self.external_memory_fd_fn.get_memory_fd_properties_khr)(
             self.handle,
             handle_type,
             fd,
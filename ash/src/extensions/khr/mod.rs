@@ -1,3 +1,4 @@
+#[cfg(feature = "VK_USE_PLATFORM_ANDROID_KHR")]
 pub use self::android_surface::AndroidSurface;
 pub use self::display::Display;
 pub use self::display_swapchain::DisplaySwapchain;
@@ -8,11 +9,16 @@ pub use self::ray_tracing::RayTracing;
 pub use self::surface::Surface;
 pub use self::swapchain::Swapchain;
 pub use self::timeline_semaphore::TimelineSemaphore;
+#[cfg(feature = "VK_USE_PLATFORM_WAYLAND_KHR")]
 pub use self::wayland_surface::WaylandSurface;
+#[cfg(feature = "VK_USE_PLATFORM_WIN32_KHR")]
 pub use self::win32_surface::Win32Surface;
+#[cfg(feature = "VK_USE_PLATFORM_XCB_KHR")]
 pub use self::xcb_surface::XcbSurface;
+#[cfg(feature = "VK_USE_PLATFORM_XLIB_KHR")]
 pub use self::xlib_surface::XlibSurface;
 
+#[cfg(feature = "VK_USE_PLATFORM_ANDROID_KHR")]
 mod android_surface;
 mod display;
 mod display_swapchain;
@@ -23,7 +29,11 @@ mod ray_tracing;
 mod surface;
 mod swapchain;
 mod timeline_semaphore;
+#[cfg(feature = "VK_USE_PLATFORM_WAYLAND_KHR")]
 mod wayland_surface;
+#[cfg(feature = "VK_USE_PLATFORM_WIN32_KHR")]
 mod win32_surface;
+#[cfg(feature = "VK_USE_PLATFORM_XCB_KHR")]
 mod xcb_surface;
+#[cfg(feature = "VK_USE_PLATFORM_XLIB_KHR")]
 mod xlib_surface;
@@ -36,7 +36,7 @@ impl PushDescriptor {
         set: u32,
         descriptor_writes: &[vk::WriteDescriptorSet],
     ) {
-        self.push_descriptors_fn.cmd_push_descriptor_set_khr(
+        (self.push_descriptors_fn.cmd_push_descriptor_set_khr)(
             command_buffer,
             pipeline_bind_point,
             layout,
@@ -55,14 +55,15 @@ impl PushDescriptor {
         set: u32,
         p_data: *const c_void,
     ) {
-        self.push_descriptors_fn
-            .cmd_push_descriptor_set_with_template_khr(
-                command_buffer,
-                descriptor_update_template,
-                layout,
-                set,
-                p_data,
-            );
+        (self
+            .push_descriptors_fn
+            .cmd_push_descriptor_set_with_template_khr)(
+            command_buffer,
+            descriptor_update_template,
+            layout,
+            set,
+            p_data,
+        );
     }
 
     pub fn fp(&self) -> &vk::KhrPushDescriptorFn {
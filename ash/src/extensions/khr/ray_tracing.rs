@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 use crate::prelude::*;
-use crate::version::{DeviceV1_0, InstanceV1_0, InstanceV1_1};
+#[cfg(feature = "V1_1")]
+use crate::version::InstanceV1_1;
+use crate::version::{DeviceV1_0, InstanceV1_0};
 use crate::vk;
 use crate::RawPtr;
 use std::ffi::CStr;
@@ -23,6 +25,7 @@ impl RayTracing {
         }
     }
 
+    #[cfg(feature = "V1_1")]
     pub unsafe fn get_properties<I: InstanceV1_1>(
         instance: &I,
         pdevice: vk::PhysicalDevice,
@@ -42,7 +45,7 @@ impl RayTracing {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::AccelerationStructureKHR> {
         let mut accel_struct = mem::zeroed();
-        let err_code = self.ray_tracing_fn.create_acceleration_structure_khr(
+        let err_code = (self.ray_tracing_fn.create_acceleration_structure_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -60,7 +63,7 @@ impl RayTracing {
         accel_struct: vk::AccelerationStructureKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.ray_tracing_fn.destroy_acceleration_structure_khr(
+        (self.ray_tracing_fn.destroy_acceleration_structure_khr)(
             self.handle,
             accel_struct,
             allocation_callbacks.as_raw_ptr(),
@@ -73,12 +76,13 @@ impl RayTracing {
         info: &vk::AccelerationStructureMemoryRequirementsInfoKHR,
     ) -> vk::MemoryRequirements2KHR {
         let mut requirements = mem::zeroed();
-        self.ray_tracing_fn
-            .get_acceleration_structure_memory_requirements_khr(
-                self.handle,
-                info,
-                &mut requirements,
-            );
+        (self
+            .ray_tracing_fn
+            .get_acceleration_structure_memory_requirements_khr)(
+            self.handle,
+            info,
+            &mut requirements,
+        );
         requirements
     }
 
@@ -87,7 +91,7 @@ impl RayTracing {
         &self,
         bind_info: &[vk::BindAccelerationStructureMemoryInfoKHR],
     ) -> VkResult<()> {
-        let err_code = self.ray_tracing_fn.bind_acceleration_structure_memory_khr(
+        let err_code = (self.ray_tracing_fn.bind_acceleration_structure_memory_khr)(
             self.handle,
             bind_info.len() as u32,
             bind_info.as_ptr(),
@@ -110,7 +114,7 @@ impl RayTracing {
             .map(|slice| slice.as_ptr())
             .collect::<Vec<*const vk::AccelerationStructureBuildOffsetInfoKHR>>();
 
-        self.ray_tracing_fn.cmd_build_acceleration_structure_khr(
+        (self.ray_tracing_fn.cmd_build_acceleration_structure_khr)(
             command_buffer,
             infos.len() as u32,
             infos.as_ptr(),
@@ -124,8 +128,7 @@ impl RayTracing {
         command_buffer: vk::CommandBuffer,
         info: &vk::CopyAccelerationStructureInfoKHR,
     ) {
-        self.ray_tracing_fn
-            .cmd_copy_acceleration_structure_khr(command_buffer, info);
+        (self.ray_tracing_fn.cmd_copy_acceleration_structure_khr)(command_buffer, info);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdTraceRaysKHR.html>"]
@@ -140,7 +143,7 @@ impl RayTracing {
         height: u32,
         depth: u32,
     ) {
-        self.ray_tracing_fn.cmd_trace_rays_khr(
+        (self.ray_tracing_fn.cmd_trace_rays_khr)(
             command_buffer,
             raygen_shader_binding_tables.as_ptr(),
             miss_shader_binding_tables.as_ptr(),
@@ -160,7 +163,7 @@ impl RayTracing {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<Vec<vk::Pipeline>> {
         let mut pipelines = vec![mem::zeroed(); create_info.len()];
-        let err_code = self.ray_tracing_fn.create_ray_tracing_pipelines_khr(
+        let err_code = (self.ray_tracing_fn.create_ray_tracing_pipelines_khr)(
             self.handle,
             pipeline_cache,
             create_info.len() as u32,
@@ -182,16 +185,14 @@ impl RayTracing {
         group_count: u32,
         data: &mut [u8],
     ) -> VkResult<()> {
-        let err_code = self
-            .ray_tracing_fn
-            .get_ray_tracing_shader_group_handles_khr(
-                self.handle,
-                pipeline,
-                first_group,
-                group_count,
-                data.len(),
-                data.as_mut_ptr() as *mut std::ffi::c_void,
-            );
+        let err_code = (self.ray_tracing_fn.get_ray_tracing_shader_group_handles_khr)(
+            self.handle,
+            pipeline,
+            first_group,
+            group_count,
+            data.len(),
+            data.as_mut_ptr() as *mut std::ffi::c_void,
+        );
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -203,8 +204,9 @@ impl RayTracing {
         &self,
         info: &vk::AccelerationStructureDeviceAddressInfoKHR,
     ) -> vk::DeviceAddress {
-        self.ray_tracing_fn
-            .get_acceleration_structure_device_address_khr(self.handle, info)
+        (self
+            .ray_tracing_fn
+            .get_acceleration_structure_device_address_khr)(self.handle, info)
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesKHR.html>"]
@@ -216,15 +218,16 @@ impl RayTracing {
         query_pool: vk::QueryPool,
         first_query: u32,
     ) {
-        self.ray_tracing_fn
-            .cmd_write_acceleration_structures_properties_khr(
-                command_buffer,
-                structures.len() as u32,
-                structures.as_ptr(),
-                query_type,
-                query_pool,
-                first_query,
-            );
+        (self
+            .ray_tracing_fn
+            .cmd_write_acceleration_structures_properties_khr)(
+            command_buffer,
+            structures.len() as u32,
+            structures.as_ptr(),
+            query_type,
+            query_pool,
+            first_query,
+        );
     }
 
     pub unsafe fn cmd_build_acceleration_structure_indirect(
@@ -235,14 +238,15 @@ impl RayTracing {
         indirect_offset: vk::DeviceSize,
         indirect_stride: u32,
     ) {
-        self.ray_tracing_fn
-            .cmd_build_acceleration_structure_indirect_khr(
-                command_buffer,
-                info,
-                indirect_buffer,
-                indirect_offset,
-                indirect_stride,
-            );
+        (self
+            .ray_tracing_fn
+            .cmd_build_acceleration_structure_indirect_khr)(
+            command_buffer,
+            info,
+            indirect_buffer,
+            indirect_offset,
+            indirect_stride,
+        );
     }
 
     pub unsafe fn copy_acceleration_structure_to_memory(
@@ -250,9 +254,9 @@ impl RayTracing {
         device: vk::Device,
         info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
     ) -> VkResult<()> {
-        let err_code = self
+        let err_code = (self
             .ray_tracing_fn
-            .copy_acceleration_structure_to_memory_khr(device, info);
+            .copy_acceleration_structure_to_memory_khr)(device, info);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
@@ -264,9 +268,9 @@ impl RayTracing {
         device: vk::Device,
         info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
     ) -> VkResult<()> {
-        let err_code = self
+        let err_code = (self
             .ray_tracing_fn
-            .copy_memory_to_acceleration_structure_khr(device, info);
+            .copy_memory_to_acceleration_structure_khr)(device, info);
 
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -279,8 +283,9 @@ impl RayTracing {
         command_buffer: vk::CommandBuffer,
         info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
     ) {
-        self.ray_tracing_fn
-            .cmd_copy_acceleration_structure_to_memory_khr(command_buffer, info);
+        (self
+            .ray_tracing_fn
+            .cmd_copy_acceleration_structure_to_memory_khr)(command_buffer, info);
     }
 
     pub unsafe fn cmd_copy_memory_to_acceleration_structure(
@@ -288,8 +293,9 @@ impl RayTracing {
         command_buffer: vk::CommandBuffer,
         info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
     ) {
-        self.ray_tracing_fn
-            .cmd_copy_memory_to_acceleration_structure_khr(command_buffer, info);
+        (self
+            .ray_tracing_fn
+            .cmd_copy_memory_to_acceleration_structure_khr)(command_buffer, info);
     }
 
     pub unsafe fn get_ray_tracing_capture_replay_shader_group_handles(
@@ -302,16 +308,16 @@ impl RayTracing {
     ) -> VkResult<Vec<u8>> {
         let mut data: Vec<u8> = Vec::with_capacity(data_size);
 
-        let err_code = self
+        let err_code = (self
             .ray_tracing_fn
-            .get_ray_tracing_capture_replay_shader_group_handles_khr(
-                device,
-                pipeline,
-                first_group,
-                group_count,
-                data_size,
-                data.as_mut_ptr() as *mut _,
-            );
+            .get_ray_tracing_capture_replay_shader_group_handles_khr)(
+            device,
+            pipeline,
+            first_group,
+            group_count,
+            data_size,
+            data.as_mut_ptr() as *mut _,
+        );
 
         match err_code {
             vk::Result::SUCCESS => Ok(data),
@@ -329,7 +335,7 @@ impl RayTracing {
         buffer: vk::Buffer,
         offset: vk::DeviceSize,
     ) {
-        self.ray_tracing_fn.cmd_trace_rays_indirect_khr(
+        (self.ray_tracing_fn.cmd_trace_rays_indirect_khr)(
             command_buffer,
             raygen_shader_binding_table.as_ptr(),
             miss_shader_binding_table.as_ptr(),
@@ -345,9 +351,10 @@ impl RayTracing {
         device: vk::Device,
         version: &vk::AccelerationStructureVersionKHR,
     ) -> VkResult<()> {
-        let err_code = self
-            .ray_tracing_fn
-            .get_device_acceleration_structure_compatibility_khr(device, version);
+        let err_code =
+            (self
+                .ray_tracing_fn
+                .get_device_acceleration_structure_compatibility_khr)(device, version);
 
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -36,7 +36,7 @@ impl Surface {
         surface: vk::SurfaceKHR,
     ) -> VkResult<bool> {
         let mut b = mem::zeroed();
-        let err_code = self.surface_fn.get_physical_device_surface_support_khr(
+        let err_code = (self.surface_fn.get_physical_device_surface_support_khr)(
             physical_device,
             queue_index,
             surface,
@@ -56,22 +56,23 @@ impl Surface {
         surface: vk::SurfaceKHR,
     ) -> VkResult<Vec<vk::PresentModeKHR>> {
         let mut count = 0;
-        self.surface_fn
-            .get_physical_device_surface_present_modes_khr(
-                physical_device,
-                surface,
-                &mut count,
-                ptr::null_mut(),
-            );
+        (self
+            .surface_fn
+            .get_physical_device_surface_present_modes_khr)(
+            physical_device,
+            surface,
+            &mut count,
+            ptr::null_mut(),
+        );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self
+        let err_code = (self
             .surface_fn
-            .get_physical_device_surface_present_modes_khr(
-                physical_device,
-                surface,
-                &mut count,
-                v.as_mut_ptr(),
-            );
+            .get_physical_device_surface_present_modes_khr)(
+            physical_device,
+            surface,
+            &mut count,
+            v.as_mut_ptr(),
+        );
         v.set_len(count as usize);
         match err_code {
             vk::Result::SUCCESS => Ok(v),
@@ -86,13 +87,11 @@ impl Surface {
         surface: vk::SurfaceKHR,
     ) -> VkResult<vk::SurfaceCapabilitiesKHR> {
         let mut surface_capabilities = mem::zeroed();
-        let err_code = self
-            .surface_fn
-            .get_physical_device_surface_capabilities_khr(
-                physical_device,
-                surface,
-                &mut surface_capabilities,
-            );
+        let err_code = (self.surface_fn.get_physical_device_surface_capabilities_khr)(
+            physical_device,
+            surface,
+            &mut surface_capabilities,
+        );
         match err_code {
             vk::Result::SUCCESS => Ok(surface_capabilities),
             _ => Err(err_code),
@@ -106,14 +105,14 @@ impl Surface {
         surface: vk::SurfaceKHR,
     ) -> VkResult<Vec<vk::SurfaceFormatKHR>> {
         let mut count = 0;
-        self.surface_fn.get_physical_device_surface_formats_khr(
+        (self.surface_fn.get_physical_device_surface_formats_khr)(
             physical_device,
             surface,
             &mut count,
             ptr::null_mut(),
         );
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.surface_fn.get_physical_device_surface_formats_khr(
+        let err_code = (self.surface_fn.get_physical_device_surface_formats_khr)(
             physical_device,
             surface,
             &mut count,
@@ -132,7 +131,7 @@ impl Surface {
         surface: vk::SurfaceKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.surface_fn.destroy_surface_khr(
+        (self.surface_fn.destroy_surface_khr)(
             self.handle,
             surface,
             allocation_callbacks.as_raw_ptr(),
@@ -34,7 +34,7 @@ impl Swapchain {
         swapchain: vk::SwapchainKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.swapchain_fn.destroy_swapchain_khr(
+        (self.swapchain_fn.destroy_swapchain_khr)(
             self.handle,
             swapchain,
             allocation_callbacks.as_raw_ptr(),
@@ -51,7 +51,7 @@ impl Swapchain {
         fence: vk::Fence,
     ) -> VkResult<(u32, bool)> {
         let mut index = mem::zeroed();
-        let err_code = self.swapchain_fn.acquire_next_image_khr(
+        let err_code = (self.swapchain_fn.acquire_next_image_khr)(
             self.handle,
             swapchain,
             timeout,
@@ -73,7 +73,7 @@ impl Swapchain {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SwapchainKHR> {
         let mut swapchain = mem::zeroed();
-        let err_code = self.swapchain_fn.create_swapchain_khr(
+        let err_code = (self.swapchain_fn.create_swapchain_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -92,7 +92,7 @@ impl Swapchain {
         queue: vk::Queue,
         create_info: &vk::PresentInfoKHR,
     ) -> VkResult<bool> {
-        let err_code = self.swapchain_fn.queue_present_khr(queue, create_info);
+        let err_code = (self.swapchain_fn.queue_present_khr)(queue, create_info);
         match err_code {
             vk::Result::SUCCESS => Ok(false),
             vk::Result::SUBOPTIMAL_KHR => Ok(true),
@@ -106,7 +106,7 @@ impl Swapchain {
         swapchain: vk::SwapchainKHR,
     ) -> VkResult<Vec<vk::Image>> {
         let mut count = 0;
-        self.swapchain_fn.get_swapchain_images_khr(
+        (self.swapchain_fn.get_swapchain_images_khr)(
             self.handle,
             swapchain,
             &mut count,
@@ -114,7 +114,7 @@ impl Swapchain {
         );
 
         let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.swapchain_fn.get_swapchain_images_khr(
+        let err_code = (self.swapchain_fn.get_swapchain_images_khr)(
             self.handle,
             swapchain,
             &mut count,
@@ -34,7 +34,7 @@ impl WaylandSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.wayland_surface_fn.create_wayland_surface_khr(
+        let err_code = (self.wayland_surface_fn.create_wayland_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -53,13 +53,13 @@ impl WaylandSurface {
         queue_family_index: u32,
         wl_display: &mut vk::wl_display,
     ) -> bool {
-        let b = self
+        let b = (self
             .wayland_surface_fn
-            .get_physical_device_wayland_presentation_support_khr(
-                physical_device,
-                queue_family_index,
-                wl_display,
-            );
+            .get_physical_device_wayland_presentation_support_khr)(
+            physical_device,
+            queue_family_index,
+            wl_display,
+        );
 
         b > 0
     }
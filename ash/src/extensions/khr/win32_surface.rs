@@ -34,7 +34,7 @@ impl Win32Surface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.win32_surface_fn.create_win32_surface_khr(
+        let err_code = (self.win32_surface_fn.create_win32_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -52,12 +52,12 @@ impl Win32Surface {
         physical_device: vk::PhysicalDevice,
         queue_family_index: u32,
     ) -> bool {
-        let b = self
+        let b = (self
             .win32_surface_fn
-            .get_physical_device_win32_presentation_support_khr(
-                physical_device,
-                queue_family_index,
-            );
+            .get_physical_device_win32_presentation_support_khr)(
+            physical_device,
+            queue_family_index,
+        );
 
         b > 0
     }
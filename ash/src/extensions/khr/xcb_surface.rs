@@ -34,7 +34,7 @@ impl XcbSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.xcb_surface_fn.create_xcb_surface_khr(
+        let err_code = (self.xcb_surface_fn.create_xcb_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -54,14 +54,14 @@ impl XcbSurface {
         connection: &mut vk::xcb_connection_t,
         visual_id: vk::xcb_visualid_t,
     ) -> bool {
-        let b = self
+        let b = (self
             .xcb_surface_fn
-            .get_physical_device_xcb_presentation_support_khr(
-                physical_device,
-                queue_family_index,
-                connection,
-                visual_id,
-            );
+            .get_physical_device_xcb_presentation_support_khr)(
+            physical_device,
+            queue_family_index,
+            connection,
+            visual_id,
+        );
 
         b > 0
     }
@@ -34,7 +34,7 @@ impl XlibSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.xlib_surface_fn.create_xlib_surface_khr(
+        let err_code = (self.xlib_surface_fn.create_xlib_surface_khr)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -54,14 +54,14 @@ impl XlibSurface {
         display: &mut vk::Display,
         visual_id: vk::VisualID,
     ) -> bool {
-        let b = self
+        let b = (self
             .xlib_surface_fn
-            .get_physical_device_xlib_presentation_support_khr(
-                physical_device,
-                queue_family_index,
-                display,
-                visual_id,
-            );
+            .get_physical_device_xlib_presentation_support_khr)(
+            physical_device,
+            queue_family_index,
+            display,
+            visual_id,
+        );
 
         b > 0
     }
@@ -6,12 +6,15 @@ use crate::RawPtr;
 use std::ffi::CStr;
 use std::mem;
 
+// The extension is deprecated, but stays available to existing users.
+#[allow(deprecated)]
 #[derive(Clone)]
 pub struct IOSSurface {
     handle: vk::Instance,
     ios_surface_fn: vk::MvkIosSurfaceFn,
 }
 
+#[allow(deprecated)]
 impl IOSSurface {
     pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> IOSSurface {
         let surface_fn = vk::MvkIosSurfaceFn::load(|name| unsafe {
@@ -34,7 +37,7 @@ impl IOSSurface {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
         let mut surface = mem::zeroed();
-        let err_code = self.ios_surface_fn.create_ios_surface_mvk(
+        let err_code = (self.ios_surface_fn.create_ios_surface_mvk)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
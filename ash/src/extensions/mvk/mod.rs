@@ -1,5 +1,9 @@
+#[cfg(feature = "VK_USE_PLATFORM_IOS_MVK")]
 pub use self::ios_surface::IOSSurface;
+#[cfg(feature = "VK_USE_PLATFORM_MACOS_MVK")]
 pub use self::macos_surface::MacOSSurface;
 
+#[cfg(feature = "VK_USE_PLATFORM_IOS_MVK")]
 mod ios_surface;
+#[cfg(feature = "VK_USE_PLATFORM_MACOS_MVK")]
 mod macos_surface;
@@ -23,8 +23,7 @@ impl MeshShader {
         task_count: u32,
         first_task: u32,
     ) {
-        self.mesh_shader_fn
-            .cmd_draw_mesh_tasks_nv(command_buffer, task_count, first_task);
+        (self.mesh_shader_fn.cmd_draw_mesh_tasks_nv)(command_buffer, task_count, first_task);
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html>"]
     pub unsafe fn cmd_draw_mesh_tasks_indirect(
@@ -35,7 +34,7 @@ impl MeshShader {
         draw_count: u32,
         stride: u32,
     ) {
-        self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_nv(
+        (self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_nv)(
             command_buffer,
             buffer,
             offset,
@@ -54,7 +53,7 @@ impl MeshShader {
         max_draw_count: u32,
         stride: u32,
     ) {
-        self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_count_nv(
+        (self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_count_nv)(
             command_buffer,
             buffer,
             offset,
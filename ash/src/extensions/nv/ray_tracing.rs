@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 use crate::prelude::*;
-use crate::version::{DeviceV1_0, InstanceV1_0, InstanceV1_1};
+#[cfg(feature = "V1_1")]
+use crate::version::InstanceV1_1;
+use crate::version::{DeviceV1_0, InstanceV1_0};
 use crate::vk;
 use crate::RawPtr;
 use std::ffi::CStr;
@@ -23,6 +25,7 @@ impl RayTracing {
         }
     }
 
+    #[cfg(feature = "V1_1")]
     pub unsafe fn get_properties<I: InstanceV1_1>(
         instance: &I,
         pdevice: vk::PhysicalDevice,
@@ -42,7 +45,7 @@ impl RayTracing {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::AccelerationStructureNV> {
         let mut accel_struct = mem::zeroed();
-        let err_code = self.ray_tracing_fn.create_acceleration_structure_nv(
+        let err_code = (self.ray_tracing_fn.create_acceleration_structure_nv)(
             self.handle,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -60,7 +63,7 @@ impl RayTracing {
         accel_struct: vk::AccelerationStructureNV,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) {
-        self.ray_tracing_fn.destroy_acceleration_structure_nv(
+        (self.ray_tracing_fn.destroy_acceleration_structure_nv)(
             self.handle,
             accel_struct,
             allocation_callbacks.as_raw_ptr(),
@@ -73,12 +76,13 @@ impl RayTracing {
         info: &vk::AccelerationStructureMemoryRequirementsInfoNV,
     ) -> vk::MemoryRequirements2KHR {
         let mut requirements = mem::zeroed();
-        self.ray_tracing_fn
-            .get_acceleration_structure_memory_requirements_nv(
-                self.handle,
-                info,
-                &mut requirements,
-            );
+        (self
+            .ray_tracing_fn
+            .get_acceleration_structure_memory_requirements_nv)(
+            self.handle,
+            info,
+            &mut requirements,
+        );
         requirements
     }
 
@@ -87,7 +91,7 @@ impl RayTracing {
         &self,
         bind_info: &[vk::BindAccelerationStructureMemoryInfoNV],
     ) -> VkResult<()> {
-        let err_code = self.ray_tracing_fn.bind_acceleration_structure_memory_nv(
+        let err_code = (self.ray_tracing_fn.bind_acceleration_structure_memory_nv)(
             self.handle,
             bind_info.len() as u32,
             bind_info.as_ptr(),
@@ -111,7 +115,7 @@ impl RayTracing {
         scratch: vk::Buffer,
         scratch_offset: vk::DeviceSize,
     ) {
-        self.ray_tracing_fn.cmd_build_acceleration_structure_nv(
+        (self.ray_tracing_fn.cmd_build_acceleration_structure_nv)(
             command_buffer,
             info,
             instance_data,
@@ -132,8 +136,7 @@ impl RayTracing {
         src: vk::AccelerationStructureNV,
         mode: vk::CopyAccelerationStructureModeNV,
     ) {
-        self.ray_tracing_fn
-            .cmd_copy_acceleration_structure_nv(command_buffer, dst, src, mode);
+        (self.ray_tracing_fn.cmd_copy_acceleration_structure_nv)(command_buffer, dst, src, mode);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdTraceRaysNV.html>"]
@@ -155,7 +158,7 @@ impl RayTracing {
         height: u32,
         depth: u32,
     ) {
-        self.ray_tracing_fn.cmd_trace_rays_nv(
+        (self.ray_tracing_fn.cmd_trace_rays_nv)(
             command_buffer,
             raygen_shader_binding_table_buffer,
             raygen_shader_binding_offset,
@@ -182,7 +185,7 @@ impl RayTracing {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<Vec<vk::Pipeline>> {
         let mut pipelines = vec![mem::zeroed(); create_info.len()];
-        let err_code = self.ray_tracing_fn.create_ray_tracing_pipelines_nv(
+        let err_code = (self.ray_tracing_fn.create_ray_tracing_pipelines_nv)(
             self.handle,
             pipeline_cache,
             create_info.len() as u32,
@@ -204,7 +207,7 @@ impl RayTracing {
         group_count: u32,
         data: &mut [u8],
     ) -> VkResult<()> {
-        let err_code = self.ray_tracing_fn.get_ray_tracing_shader_group_handles_nv(
+        let err_code = (self.ray_tracing_fn.get_ray_tracing_shader_group_handles_nv)(
             self.handle,
             pipeline,
             first_group,
@@ -225,7 +228,7 @@ impl RayTracing {
     ) -> VkResult<u64> {
         let mut handle: u64 = 0;
         let handle_ptr: *mut u64 = &mut handle;
-        let err_code = self.ray_tracing_fn.get_acceleration_structure_handle_nv(
+        let err_code = (self.ray_tracing_fn.get_acceleration_structure_handle_nv)(
             self.handle,
             accel_struct,
             std::mem::size_of::<u64>(),
@@ -246,22 +249,21 @@ impl RayTracing {
         query_pool: vk::QueryPool,
         first_query: u32,
     ) {
-        self.ray_tracing_fn
-            .cmd_write_acceleration_structures_properties_nv(
-                command_buffer,
-                structures.len() as u32,
-                structures.as_ptr(),
-                query_type,
-                query_pool,
-                first_query,
-            );
+        (self
+            .ray_tracing_fn
+            .cmd_write_acceleration_structures_properties_nv)(
+            command_buffer,
+            structures.len() as u32,
+            structures.as_ptr(),
+            query_type,
+            query_pool,
+            first_query,
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCompileDeferredNV.html>"]
     pub unsafe fn compile_deferred(&self, pipeline: vk::Pipeline, shader: u32) -> VkResult<()> {
-        let err_code = self
-            .ray_tracing_fn
-            .compile_deferred_nv(self.handle, pipeline, shader);
+        let err_code = (self.ray_tracing_fn.compile_deferred_nv)(self.handle, pipeline, shader);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
             _ => Err(err_code),
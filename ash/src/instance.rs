@@ -12,25 +12,31 @@ use std::ptr;
 pub struct Instance {
     handle: vk::Instance,
     instance_fn_1_0: vk::InstanceFnV1_0,
+    #[cfg(feature = "V1_1")]
     instance_fn_1_1: vk::InstanceFnV1_1,
+    #[cfg(feature = "V1_2")]
     instance_fn_1_2: vk::InstanceFnV1_2,
 }
 impl Instance {
     pub unsafe fn load(static_fn: &vk::StaticFn, instance: vk::Instance) -> Self {
         let instance_fn_1_0 = vk::InstanceFnV1_0::load(|name| {
-            mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(instance, name.as_ptr()))
         });
+        #[cfg(feature = "V1_1")]
         let instance_fn_1_1 = vk::InstanceFnV1_1::load(|name| {
-            mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(instance, name.as_ptr()))
         });
+        #[cfg(feature = "V1_2")]
         let instance_fn_1_2 = vk::InstanceFnV1_2::load(|name| {
-            mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
+            mem::transmute((static_fn.get_instance_proc_addr)(instance, name.as_ptr()))
         });
 
         Instance {
             handle: instance,
             instance_fn_1_0,
+            #[cfg(feature = "V1_1")]
             instance_fn_1_1,
+            #[cfg(feature = "V1_2")]
             instance_fn_1_2,
         }
     }
@@ -51,7 +57,7 @@ impl InstanceV1_0 for Instance {
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Self::Device, vk::Result> {
         let mut device: vk::Device = mem::zeroed();
-        let err_code = self.fp_v1_0().create_device(
+        let err_code = (self.fp_v1_0().create_device)(
             physical_device,
             create_info,
             allocation_callbacks.as_raw_ptr(),
@@ -71,30 +77,34 @@ impl InstanceV1_0 for Instance {
     }
 }
 
+#[cfg(feature = "V1_1")]
 impl InstanceV1_1 for Instance {
     fn fp_v1_1(&self) -> &vk::InstanceFnV1_1 {
         &self.instance_fn_1_1
     }
 }
 
+#[cfg(feature = "V1_2")]
 impl InstanceV1_2 for Instance {
     fn fp_v1_2(&self) -> &vk::InstanceFnV1_2 {
         &self.instance_fn_1_2
     }
 }
 
+#[cfg(feature = "V1_2")]
 #[allow(non_camel_case_types)]
 pub trait InstanceV1_2: InstanceV1_1 {
     fn fp_v1_2(&self) -> &vk::InstanceFnV1_2;
 }
 
+#[cfg(feature = "V1_1")]
 #[allow(non_camel_case_types)]
 pub trait InstanceV1_1: InstanceV1_0 {
     fn fp_v1_1(&self) -> &vk::InstanceFnV1_1;
 
     unsafe fn enumerate_physical_device_groups_len(&self) -> usize {
         let mut group_count = mem::zeroed();
-        self.fp_v1_1().enumerate_physical_device_groups(
+        (self.fp_v1_1().enumerate_physical_device_groups)(
             self.handle(),
             &mut group_count,
             ptr::null_mut(),
@@ -109,7 +119,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
     ) -> VkResult<()> {
         unsafe {
             let mut group_count = out.len() as u32;
-            let err_code = self.fp_v1_1().enumerate_physical_device_groups(
+            let err_code = (self.fp_v1_1().enumerate_physical_device_groups)(
                 self.handle(),
                 &mut group_count,
                 out.as_mut_ptr(),
@@ -128,8 +138,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         features: &mut vk::PhysicalDeviceFeatures2,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_features2(physical_device, features);
+        (self.fp_v1_1().get_physical_device_features2)(physical_device, features);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
@@ -138,8 +147,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         prop: &mut vk::PhysicalDeviceProperties2,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_properties2(physical_device, prop);
+        (self.fp_v1_1().get_physical_device_properties2)(physical_device, prop);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
@@ -149,8 +157,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format: vk::Format,
         out: &mut vk::FormatProperties2,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_format_properties2(physical_device, format, out);
+        (self.fp_v1_1().get_physical_device_format_properties2)(physical_device, format, out);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
@@ -160,7 +167,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format_info: &vk::PhysicalDeviceImageFormatInfo2,
         image_format_prop: &mut vk::ImageFormatProperties2,
     ) -> VkResult<()> {
-        let err_code = self.fp_v1_1().get_physical_device_image_format_properties2(
+        let err_code = (self.fp_v1_1().get_physical_device_image_format_properties2)(
             physical_device,
             format_info,
             image_format_prop,
@@ -177,7 +184,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
     ) -> usize {
         let mut queue_count = 0;
-        self.fp_v1_1().get_physical_device_queue_family_properties2(
+        (self.fp_v1_1().get_physical_device_queue_family_properties2)(
             physical_device,
             &mut queue_count,
             ptr::null_mut(),
@@ -192,7 +199,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         queue_family_props: &mut [vk::QueueFamilyProperties2],
     ) {
         let mut queue_count = queue_family_props.len() as u32;
-        self.fp_v1_1().get_physical_device_queue_family_properties2(
+        (self.fp_v1_1().get_physical_device_queue_family_properties2)(
             physical_device,
             &mut queue_count,
             queue_family_props.as_mut_ptr(),
@@ -205,8 +212,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         out: &mut vk::PhysicalDeviceMemoryProperties2,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_memory_properties2(physical_device, out);
+        (self.fp_v1_1().get_physical_device_memory_properties2)(physical_device, out);
     }
 
     unsafe fn get_physical_device_sparse_image_format_properties2_len(
@@ -215,13 +221,14 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
     ) -> usize {
         let mut format_count = 0;
-        self.fp_v1_1()
-            .get_physical_device_sparse_image_format_properties2(
-                physical_device,
-                format_info,
-                &mut format_count,
-                ptr::null_mut(),
-            );
+        (self
+            .fp_v1_1()
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            &mut format_count,
+            ptr::null_mut(),
+        );
         format_count as usize
     }
 
@@ -233,13 +240,14 @@ pub trait InstanceV1_1: InstanceV1_0 {
         out: &mut [vk::SparseImageFormatProperties2],
     ) {
         let mut format_count = out.len() as u32;
-        self.fp_v1_1()
-            .get_physical_device_sparse_image_format_properties2(
-                physical_device,
-                format_info,
-                &mut format_count,
-                out.as_mut_ptr(),
-            );
+        (self
+            .fp_v1_1()
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            &mut format_count,
+            out.as_mut_ptr(),
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html>"]
@@ -249,12 +257,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo,
         out: &mut vk::ExternalBufferProperties,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_external_buffer_properties(
-                physical_device,
-                external_buffer_info,
-                out,
-            );
+        (self
+            .fp_v1_1()
+            .get_physical_device_external_buffer_properties)(
+            physical_device,
+            external_buffer_info,
+            out,
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
@@ -264,12 +273,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_fence_info: &vk::PhysicalDeviceExternalFenceInfo,
         out: &mut vk::ExternalFenceProperties,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_external_fence_properties(
-                physical_device,
-                external_fence_info,
-                out,
-            );
+        (self.fp_v1_1().get_physical_device_external_fence_properties)(
+            physical_device,
+            external_fence_info,
+            out,
+        );
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
@@ -279,12 +287,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo,
         out: &mut vk::ExternalSemaphoreProperties,
     ) {
-        self.fp_v1_1()
-            .get_physical_device_external_semaphore_properties(
-                physical_device,
-                external_semaphore_info,
-                out,
-            );
+        (self
+            .fp_v1_1()
+            .get_physical_device_external_semaphore_properties)(
+            physical_device,
+            external_semaphore_info,
+            out,
+        );
     }
 }
 
@@ -312,13 +321,12 @@ pub trait InstanceV1_0 {
         device: vk::Device,
         p_name: *const c_char,
     ) -> vk::PFN_vkVoidFunction {
-        self.fp_v1_0().get_device_proc_addr(device, p_name)
+        (self.fp_v1_0().get_device_proc_addr)(device, p_name)
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyInstance.html>"]
     unsafe fn destroy_instance(&self, allocation_callbacks: Option<&vk::AllocationCallbacks>) {
-        self.fp_v1_0()
-            .destroy_instance(self.handle(), allocation_callbacks.as_raw_ptr());
+        (self.fp_v1_0().destroy_instance)(self.handle(), allocation_callbacks.as_raw_ptr());
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceFormatProperties.html>"]
@@ -328,7 +336,7 @@ pub trait InstanceV1_0 {
         format: vk::Format,
     ) -> vk::FormatProperties {
         let mut format_prop = mem::zeroed();
-        self.fp_v1_0().get_physical_device_format_properties(
+        (self.fp_v1_0().get_physical_device_format_properties)(
             physical_device,
             format,
             &mut format_prop,
@@ -347,7 +355,7 @@ pub trait InstanceV1_0 {
         flags: vk::ImageCreateFlags,
     ) -> VkResult<vk::ImageFormatProperties> {
         let mut image_format_prop = mem::zeroed();
-        let err_code = self.fp_v1_0().get_physical_device_image_format_properties(
+        let err_code = (self.fp_v1_0().get_physical_device_image_format_properties)(
             physical_device,
             format,
             typ,
@@ -369,8 +377,7 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceMemoryProperties {
         let mut memory_prop = mem::zeroed();
-        self.fp_v1_0()
-            .get_physical_device_memory_properties(physical_device, &mut memory_prop);
+        (self.fp_v1_0().get_physical_device_memory_properties)(physical_device, &mut memory_prop);
         memory_prop
     }
 
@@ -380,8 +387,7 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceProperties {
         let mut prop = mem::zeroed();
-        self.fp_v1_0()
-            .get_physical_device_properties(physical_device, &mut prop);
+        (self.fp_v1_0().get_physical_device_properties)(physical_device, &mut prop);
         prop
     }
 
@@ -391,13 +397,13 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
     ) -> Vec<vk::QueueFamilyProperties> {
         let mut queue_count = 0;
-        self.fp_v1_0().get_physical_device_queue_family_properties(
+        (self.fp_v1_0().get_physical_device_queue_family_properties)(
             physical_device,
             &mut queue_count,
             ptr::null_mut(),
         );
         let mut queue_families_vec = Vec::with_capacity(queue_count as usize);
-        self.fp_v1_0().get_physical_device_queue_family_properties(
+        (self.fp_v1_0().get_physical_device_queue_family_properties)(
             physical_device,
             &mut queue_count,
             queue_families_vec.as_mut_ptr(),
@@ -412,18 +418,16 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceFeatures {
         let mut prop = mem::zeroed();
-        self.fp_v1_0()
-            .get_physical_device_features(physical_device, &mut prop);
+        (self.fp_v1_0().get_physical_device_features)(physical_device, &mut prop);
         prop
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkEnumeratePhysicalDevices.html>"]
     unsafe fn enumerate_physical_devices(&self) -> VkResult<Vec<vk::PhysicalDevice>> {
         let mut num = mem::zeroed();
-        self.fp_v1_0()
-            .enumerate_physical_devices(self.handle(), &mut num, ptr::null_mut());
+        (self.fp_v1_0().enumerate_physical_devices)(self.handle(), &mut num, ptr::null_mut());
         let mut physical_devices = Vec::<vk::PhysicalDevice>::with_capacity(num as usize);
-        let err_code = self.fp_v1_0().enumerate_physical_devices(
+        let err_code = (self.fp_v1_0().enumerate_physical_devices)(
             self.handle(),
             &mut num,
             physical_devices.as_mut_ptr(),
@@ -441,14 +445,14 @@ pub trait InstanceV1_0 {
         device: vk::PhysicalDevice,
     ) -> Result<Vec<vk::ExtensionProperties>, vk::Result> {
         let mut num = 0;
-        self.fp_v1_0().enumerate_device_extension_properties(
+        (self.fp_v1_0().enumerate_device_extension_properties)(
             device,
             ptr::null(),
             &mut num,
             ptr::null_mut(),
         );
         let mut data = Vec::with_capacity(num as usize);
-        let err_code = self.fp_v1_0().enumerate_device_extension_properties(
+        let err_code = (self.fp_v1_0().enumerate_device_extension_properties)(
             device,
             ptr::null(),
             &mut num,
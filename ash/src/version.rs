@@ -1,3 +1,15 @@
-pub use crate::device::{DeviceV1_0, DeviceV1_1, DeviceV1_2};
-pub use crate::entry::{EntryV1_0, EntryV1_1, EntryV1_2};
-pub use crate::instance::{InstanceV1_0, InstanceV1_1, InstanceV1_2};
+pub use crate::device::DeviceV1_0;
+#[cfg(feature = "V1_1")]
+pub use crate::device::DeviceV1_1;
+#[cfg(feature = "V1_2")]
+pub use crate::device::DeviceV1_2;
+pub use crate::entry::EntryV1_0;
+#[cfg(feature = "V1_1")]
+pub use crate::entry::EntryV1_1;
+#[cfg(feature = "V1_2")]
+pub use crate::entry::EntryV1_2;
+pub use crate::instance::InstanceV1_0;
+#[cfg(feature = "V1_1")]
+pub use crate::instance::InstanceV1_1;
+#[cfg(feature = "V1_2")]
+pub use crate::instance::InstanceV1_2;
@@ -1,4 +1,8 @@
-#![allow(clippy::too_many_arguments, clippy::cognitive_complexity, clippy::wrong_self_convention)]
+#![allow(
+    clippy::too_many_arguments,
+    clippy::cognitive_complexity,
+    clippy::wrong_self_convention
+)]
 #[macro_use]
 mod macros;
 pub use macros::*;
@@ -6,14 +10,17 @@ mod aliases;
 pub use aliases::*;
 mod bitflags;
 pub use bitflags::*;
+#[allow(deprecated)]
 mod const_debugs;
 pub(crate) use const_debugs::*;
 mod constants;
 pub use constants::*;
+#[allow(deprecated)]
 mod definitions;
 pub use definitions::*;
 mod enums;
 pub use enums::*;
+#[allow(deprecated)]
 mod extensions;
 pub use extensions::*;
 mod feature_extensions;
@@ -39,5 +46,12 @@ pub(crate) unsafe fn ptr_chain_iter<T>(ptr: &mut T) -> impl Iterator<Item = *mut
 pub trait Handle {
     const TYPE: ObjectType;
     fn as_raw(self) -> u64;
-    fn from_raw(_: u64) -> Self;
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `x` must be null or a valid handle of this type."]
+    unsafe fn from_raw(x: u64) -> Self;
+}
+#[doc = r" Structs that are identified by their `s_type`, which always equals `STRUCTURE_TYPE`."]
+pub trait TaggedStructure {
+    const STRUCTURE_TYPE: StructureType;
 }
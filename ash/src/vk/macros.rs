@@ -1,18 +1,42 @@
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_MAKE_API_VERSION.html>"]
+pub const fn make_api_version(variant: u32, major: u32, minor: u32, patch: u32) -> u32 {
+    (variant << 29) | (major << 22) | (minor << 12) | patch
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_VARIANT.html>"]
+pub const fn api_version_variant(version: u32) -> u32 {
+    version >> 29
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_MAJOR.html>"]
+pub const fn api_version_major(version: u32) -> u32 {
+    (version >> 22) & 0x7f
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_MINOR.html>"]
+pub const fn api_version_minor(version: u32) -> u32 {
+    (version >> 12) & 0x3ff
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_PATCH.html>"]
+pub const fn api_version_patch(version: u32) -> u32 {
+    version & 0xfff
+}
+#[deprecated = "This define is deprecated. make_api_version should be used instead."]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_MAKE_VERSION.html>"]
 pub const fn make_version(major: u32, minor: u32, patch: u32) -> u32 {
-    (major << 22) | (minor << 12) | patch
+    make_api_version(0, major, minor, patch)
 }
+#[deprecated = "This define is deprecated. api_version_major should be used instead."]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_MAJOR.html>"]
 pub const fn version_major(version: u32) -> u32 {
     version >> 22
 }
+#[deprecated = "This define is deprecated. api_version_minor should be used instead."]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_MINOR.html>"]
 pub const fn version_minor(version: u32) -> u32 {
-    (version >> 12) & 0x3ff
+    api_version_minor(version)
 }
+#[deprecated = "This define is deprecated. api_version_patch should be used instead."]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_PATCH.html>"]
 pub const fn version_patch(version: u32) -> u32 {
-    version & 0xfff
+    api_version_patch(version)
 }
 #[macro_export]
 macro_rules! vk_bitflags_wrapped {
@@ -133,10 +157,15 @@ macro_rules! handle_nondispatchable {
             fn as_raw(self) -> u64 {
                 self.0 as u64
             }
-            fn from_raw(x: u64) -> Self {
+            unsafe fn from_raw(x: u64) -> Self {
                 $name(x as _)
             }
         }
+        impl From<$name> for u64 {
+            fn from(handle: $name) -> u64 {
+                handle.as_raw()
+            }
+        }
         impl $name {
             pub const fn null() -> $name {
                 $name(0)
@@ -174,10 +203,15 @@ macro_rules! define_handle {
             fn as_raw(self) -> u64 {
                 self.0 as u64
             }
-            fn from_raw(x: u64) -> Self {
+            unsafe fn from_raw(x: u64) -> Self {
                 $name(x as _)
             }
         }
+        impl From<$name> for u64 {
+            fn from(handle: $name) -> u64 {
+                handle.as_raw()
+            }
+        }
         unsafe impl Send for $name {}
         unsafe impl Sync for $name {}
         impl $name {
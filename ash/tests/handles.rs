@@ -0,0 +1,18 @@
+use ash::vk::{self, Handle};
+
+#[test]
+fn non_dispatchable_handle_raw_round_trip() {
+    let buffer = unsafe { vk::Buffer::from_raw(0xdead_beef) };
+    assert_eq!(buffer.as_raw(), 0xdead_beef);
+    assert_eq!(unsafe { vk::Buffer::from_raw(buffer.as_raw()) }, buffer);
+    assert_eq!(vk::Buffer::null().as_raw(), 0);
+}
+
+#[test]
+fn handles_convert_into_u64() {
+    let buffer = unsafe { vk::Buffer::from_raw(0xdead_beef) };
+    assert_eq!(u64::from(buffer), 0xdead_beef);
+    let device = unsafe { vk::Device::from_raw(0x1000) };
+    assert_eq!(u64::from(device), 0x1000);
+    assert_eq!(u64::from(vk::Device::null()), 0);
+}
@@ -3,12 +3,13 @@ use std::path::Path;
 
 fn main() {
     let cwd = std::env::current_dir().unwrap();
-    if cwd.ends_with("generator") {
-        write_source_code(Path::new("Vulkan-Headers/registry/vk.xml"), "../ash/src");
+    let generated = if cwd.ends_with("generator") {
+        write_source_code(Path::new("Vulkan-Headers/registry/vk.xml"), "../ash/src")
     } else {
         write_source_code(
             Path::new("generator/Vulkan-Headers/registry/vk.xml"),
             "ash/src",
-        );
-    }
+        )
+    };
+    println!("{}", generated);
 }
@@ -11,37 +11,714 @@ pub use vkxml;
 
 use heck::{CamelCase, ShoutySnakeCase, SnakeCase};
 use itertools::Itertools;
+use nom::types::CompleteStr;
 use proc_macro2::{Literal, Term};
 use quote::Tokens;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::BuildHasher;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use syn::Ident;
 
-pub trait ExtensionExt {}
-#[derive(Copy, Clone, Debug)]
+/// The data that the generator derives from an extension of the registry. `vk_parse::Extension`
+/// is defined by `vk_parse` and can't gain fields, so it is computed from its attributes on
+/// demand instead.
+pub trait ExtensionExt {
+    /// Returns the `specialuse` tags of the extension, like `debugging` or `glemulation`.
+    /// Extensions with a special use are not intended to be used in production.
+    fn special_use(&self) -> Vec<&str>;
+
+    /// Returns the define that guards the platform specific parts of the extension, like
+    /// `VK_USE_PLATFORM_WIN32_KHR`. Newer registries only name the `platform`, whose define is
+    /// looked up in `platforms`.
+    fn platform_protect<'a>(&'a self, platforms: &'a [vk_parse::Platform]) -> Option<&'a str>;
+
+    /// Returns true if the extension is supported by the `vulkan` api, and not `disabled`.
+    fn is_supported(&self) -> bool;
+
+    /// Returns true if the extension is released provisionally, its API may still change.
+    fn is_provisional(&self) -> bool;
+
+    /// Returns the `sortorder` of the extension, which moves it relative to the other extensions.
+    fn sort_order(&self) -> Option<u32>;
+
+    /// Returns the core version that the extension was promoted to, if it was promoted into core
+    /// rather than into another extension.
+    fn promoted_to(&self) -> Option<Version>;
+
+    /// Returns the deprecation note of an extension that the registry marks as `deprecatedby` or
+    /// `obsoletedby` another extension or core version.
+    fn deprecation_note(&self) -> Option<String>;
+
+    /// Maps each `feature` that a `<require>` block depends on to the names of the items it
+    /// requires. Those items are only available if the feature is enabled as well.
+    fn feature_requirements(&self) -> BTreeMap<&str, Vec<&str>>;
+
+    /// Returns everything that the `<require>` blocks of the extension add.
+    fn provided_items(&self) -> ExtensionItems<'_>;
+}
+
+/// The items that an extension adds, see [`ExtensionExt::provided_items`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ExtensionItems<'a> {
+    pub commands: Vec<&'a str>,
+    /// Constants with a literal value, like the spec version and the name of the extension.
+    pub constants: Vec<(&'a str, EnumLiteral)>,
+    pub types: Vec<&'a str>,
+    /// Enumerants that are added to existing enums, as `(enum, enumerant)`.
+    pub enum_extensions: Vec<(&'a str, &'a str)>,
+}
+
+impl ExtensionExt for vk_parse::Extension {
+    fn special_use(&self) -> Vec<&str> {
+        self.specialuse
+            .as_ref()
+            .map(|special_use| special_use.split(',').collect())
+            .unwrap_or_default()
+    }
+
+    fn platform_protect<'a>(&'a self, platforms: &'a [vk_parse::Platform]) -> Option<&'a str> {
+        self.protect.as_deref().or_else(|| {
+            let platform = self.platform.as_ref()?;
+            platforms
+                .iter()
+                .find(|p| &p.name == platform)
+                .map(|p| p.protect.as_str())
+        })
+    }
+
+    fn is_supported(&self) -> bool {
+        self.supported
+            .as_deref()
+            .unwrap_or_default()
+            .split('|')
+            .any(|api| api == "vulkan")
+    }
+
+    fn is_provisional(&self) -> bool {
+        self.provisional
+    }
+
+    fn sort_order(&self) -> Option<u32> {
+        self.sortorder.map(|order| order as u32)
+    }
+
+    fn promoted_to(&self) -> Option<Version> {
+        let version = self.promotedto.as_ref()?.strip_prefix("VK_VERSION_")?;
+        let (major, minor) = version.split_at(version.find('_')?);
+        Some(Version::new(major.parse().ok()?, minor[1..].parse().ok()?))
+    }
+
+    fn deprecation_note(&self) -> Option<String> {
+        let (reason, by) = match (&self.deprecatedby, &self.obsoletedby) {
+            (Some(by), _) => ("Deprecated", by),
+            (None, Some(by)) => ("Obsoleted", by),
+            (None, None) => return None,
+        };
+        if by.is_empty() {
+            Some(reason.to_string())
+        } else {
+            Some(format!("{} by {}", reason, by))
+        }
+    }
+
+    fn feature_requirements(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut requirements = BTreeMap::new();
+        for child in &self.children {
+            let (feature, items) = match child {
+                vk_parse::ExtensionChild::Require {
+                    feature: Some(feature),
+                    items,
+                    ..
+                } => (feature.as_str(), items),
+                _ => continue,
+            };
+            let names = items.iter().filter_map(|item| match item {
+                vk_parse::InterfaceItem::Type { name, .. } => Some(name.as_str()),
+                vk_parse::InterfaceItem::Enum(_enum) => Some(_enum.name.as_str()),
+                vk_parse::InterfaceItem::Command { name, .. } => Some(name.as_str()),
+                _ => None,
+            });
+            requirements
+                .entry(feature)
+                .or_insert_with(Vec::new)
+                .extend(names);
+        }
+        requirements
+    }
+
+    fn provided_items(&self) -> ExtensionItems<'_> {
+        use vk_parse::EnumSpec;
+        let mut provided = ExtensionItems::default();
+        let items = self.children.iter().filter_map(|child| match child {
+            vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+            _ => None,
+        });
+        for item in items.flatten() {
+            match item {
+                vk_parse::InterfaceItem::Type { name, .. } => provided.types.push(name),
+                vk_parse::InterfaceItem::Command { name, .. } => provided.commands.push(name),
+                vk_parse::InterfaceItem::Enum(_enum) => {
+                    let extends = match &_enum.spec {
+                        EnumSpec::Offset { extends, .. } => Some(extends),
+                        EnumSpec::Alias { extends, .. }
+                        | EnumSpec::Bitpos { extends, .. }
+                        | EnumSpec::Value { extends, .. } => extends.as_ref(),
+                        _ => None,
+                    };
+                    if let Some(extends) = extends {
+                        provided.enum_extensions.push((extends, &_enum.name));
+                    } else if let EnumSpec::Value { value, .. } = &_enum.spec {
+                        if let Some(literal) = EnumLiteral::parse(value) {
+                            provided.constants.push((&_enum.name, literal));
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        provided
+    }
+}
+
+/// Returns the extensions that were promoted into the core version `major.minor`, in registry
+/// order.
+pub fn promotions_into(extensions: &[vk_parse::Extension], major: u32, minor: u32) -> Vec<&str> {
+    extensions
+        .iter()
+        .filter(|ext| ext.promoted_to() == Some(Version::new(major, minor)))
+        .map(|ext| ext.name.as_str())
+        .collect()
+}
+
+/// Returns the extensions in the order the reference generator emits them: by number, then by
+/// `sortorder`, which defaults to 0.
+pub fn sorted_extensions(extensions: &[vk_parse::Extension]) -> Vec<&vk_parse::Extension> {
+    let mut sorted: Vec<_> = extensions.iter().collect();
+    sorted.sort_by_key(|ext| (ext.number, ext.sort_order().unwrap_or(0)));
+    sorted
+}
+
+/// Generates `EXTENSIONS`, the metadata of every supported extension sorted by name, along with
+/// `extension_metadata` to look an extension up at runtime.
+pub fn generate_extension_table(extensions: &[vk_parse::Extension]) -> Tokens {
+    let mut supported: Vec<_> = extensions
+        .iter()
+        .filter(|ext| ext.is_supported())
+        .filter_map(|ext| {
+            let kind = match ext.ext_type.as_deref()? {
+                "instance" => quote!(ExtensionKind::Instance),
+                "device" => quote!(ExtensionKind::Device),
+                _ => return None,
+            };
+            let spec_version = ext
+                .provided_items()
+                .constants
+                .into_iter()
+                .find_map(|(name, value)| match value {
+                    EnumLiteral::Int(version) if name.ends_with("_SPEC_VERSION") => {
+                        Some(version as u32)
+                    }
+                    _ => None,
+                })
+                .unwrap_or(0);
+            Some((ext.name.as_str(), ext.number? as u32, kind, spec_version))
+        })
+        .collect();
+    supported.sort_by_key(|&(name, ..)| name);
+    let entries = supported.iter().map(|(name, number, kind, spec_version)| {
+        quote! {
+            ExtensionMetadata {
+                name: #name,
+                number: #number,
+                kind: #kind,
+                spec_version: #spec_version,
+            },
+        }
+    });
+    quote! {
+        /// Whether an extension is enabled on the instance or on a device.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum ExtensionKind {
+            Instance,
+            Device,
+        }
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct ExtensionMetadata {
+            pub name: &'static str,
+            pub number: u32,
+            pub kind: ExtensionKind,
+            pub spec_version: u32,
+        }
+        /// The metadata of every supported extension, sorted by name.
+        pub const EXTENSIONS: &[ExtensionMetadata] = &[#(#entries)*];
+        /// Looks up the metadata of an extension by its name, like `"VK_KHR_swapchain"`.
+        pub fn extension_metadata(name: &str) -> Option<&'static ExtensionMetadata> {
+            EXTENSIONS
+                .binary_search_by(|ext| ext.name.cmp(name))
+                .ok()
+                .map(|index| &EXTENSIONS[index])
+        }
+    }
+}
+
+pub trait EnumsExt {
+    /// Returns the inclusive range of values that is reserved for the enum, if it declares one
+    /// with `start` and `end`.
+    fn reserved_range(&self) -> Option<(i64, i64)>;
+}
+
+impl EnumsExt for vk_parse::Enums {
+    fn reserved_range(&self) -> Option<(i64, i64)> {
+        Some((self.start?, self.end?))
+    }
+}
+
+/// Returns the platform defines that are used by supported extensions. Each of them is exposed as
+/// a cargo feature, see [`platform_cfg`].
+pub fn platform_protects<'a>(
+    extensions: &'a [vk_parse::Extension],
+    platforms: &'a [vk_parse::Platform],
+) -> BTreeSet<&'a str> {
+    extensions
+        .iter()
+        .filter(|extension| extension.is_supported())
+        .filter_map(|extension| extension.platform_protect(platforms))
+        .collect()
+}
+
+/// Maps each command to the supported extension that introduces it, core commands are not part
+/// of the table. Commands that are required by multiple extensions map to the first one.
+pub fn command_extensions(extensions: &[vk_parse::Extension]) -> HashMap<&str, &str> {
+    let mut table = HashMap::new();
+    for extension in extensions
+        .iter()
+        .filter(|extension| extension.is_supported())
+    {
+        let commands = extension
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                vk_parse::InterfaceItem::Command { ref name, .. } => Some(name.as_str()),
+                _ => None,
+            });
+        for command in commands {
+            table
+                .entry(command)
+                .or_insert_with(|| extension.name.as_str());
+        }
+    }
+    table
+}
+
+/// Maps the commands whose `PFN_` type is declared behind a cfg to that cfg. The type is declared
+/// by the first extension that requires the command or one of its `cmd_aliases`, in the order
+/// that `write_source_code` generates them. Of those, only provisional extensions are gated, see
+/// [`generate_extension`]. The features come after the extensions, the commands that they declare
+/// are gated by version but can't be re-exported by an alias anymore.
+pub fn pfn_cfgs(
+    registry: &vk_parse::Registry,
+    cmd_aliases: &HashMap<String, String, impl BuildHasher>,
+) -> HashMap<String, Tokens> {
+    let required_commands = |children: &[vk_parse::ExtensionChild]| -> Vec<String> {
+        children
+            .iter()
+            .filter_map(|child| match child {
+                vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                vk_parse::InterfaceItem::Command { ref name, .. } => {
+                    Some(cmd_aliases.get(name).unwrap_or(name).clone())
+                }
+                _ => None,
+            })
+            .collect()
+    };
+    let mut declared = HashSet::new();
+    let extensions = registry.0.iter().filter_map(|item| match item {
+        vk_parse::RegistryChild::Extensions(ref extensions) => Some(&extensions.children),
+        _ => None,
+    });
+    let mut cfgs = HashMap::new();
+    for extension in extensions.flat_map(|extensions| sorted_extensions(extensions)) {
+        if extension.name.contains("RESERVED") {
+            continue;
+        }
+        for command in required_commands(&extension.children) {
+            if declared.insert(command.clone()) && extension.is_provisional() {
+                cfgs.insert(command, quote!(#[cfg(feature = "provisional")]));
+            }
+        }
+    }
+    cfgs
+}
+
+/// A command of the registry. Aliases, like `vkGetPhysicalDeviceFeatures2KHR`, are commands of
+/// their own that share the definition of the command they alias.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandItem<'a> {
+    pub name: &'a str,
+    /// The command that defines this alias, following aliases of aliases. `None` if this command
+    /// has a definition of its own.
+    pub alias_of: Option<&'a str>,
+}
+impl<'a> CommandItem<'a> {
+    pub fn is_alias(&self) -> bool {
+        self.alias_of.is_some()
+    }
+    /// Returns the name of the command that defines this one, which is its own name unless it is
+    /// an alias.
+    pub fn canonical_name(&self) -> &'a str {
+        self.alias_of.unwrap_or(self.name)
+    }
+}
+/// Returns every command of the registry, definitions and aliases, in registry order.
+pub fn command_items(registry: &vk_parse::Registry) -> Vec<CommandItem<'_>> {
+    let commands: Vec<(&str, Option<&str>)> = registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Commands(ref commands) => Some(commands.children.iter()),
+            _ => None,
+        })
+        .flatten()
+        .map(|command| match command {
+            vk_parse::Command::Alias { name, alias } => (name.as_str(), Some(alias.as_str())),
+            vk_parse::Command::Definition(definition) => (definition.proto.name.as_str(), None),
+        })
+        .collect();
+    let aliases: HashMap<&str, &str> = commands
+        .iter()
+        .filter_map(|&(name, alias)| Some((name, alias?)))
+        .collect();
+    commands
+        .iter()
+        .map(|&(name, alias)| {
+            let mut alias_of = alias;
+            // The chain is bounded, so a cycle in a broken registry can't hang the generator.
+            for _ in 0..aliases.len() {
+                match alias_of.and_then(|alias| aliases.get(alias)) {
+                    Some(&next) => alias_of = Some(next),
+                    None => break,
+                }
+            }
+            CommandItem { name, alias_of }
+        })
+        .collect()
+}
+
+/// Moves a `const` that follows the type of a declaration in front of it, so that
+/// `<type>char</type> const*` becomes `const <type>char</type>*`. The registry parser only
+/// understands the latter and would otherwise attach the `const` to the pointer.
+pub fn normalize_east_const(xml: &str) -> String {
+    let mut normalized = String::with_capacity(xml.len());
+    let mut rest = xml;
+    while let Some(end) = rest.find("</type>") {
+        let (head, tail) = rest.split_at(end + "</type>".len());
+        let after = tail.trim_start();
+        let is_east_const = after.starts_with("const")
+            && after["const".len()..].starts_with(|c: char| c.is_whitespace() || c == '*');
+        match head.rfind("<type>") {
+            Some(start) if is_east_const => {
+                normalized.push_str(&head[..start]);
+                normalized.push_str("const ");
+                normalized.push_str(&head[start..]);
+                rest = &after["const".len()..];
+            }
+            _ => {
+                normalized.push_str(head);
+                rest = tail;
+            }
+        }
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CType {
     USize,
     U32,
     U64,
     Float,
     Bool32,
+    Primitive(CPrimitive),
+    /// Any other named type, like `VkExtent2D` or `char`.
+    Named(String),
+    /// A pointer, `is_const` applies to the type it points to.
+    Pointer {
+        is_const: bool,
+        pointee: Box<CType>,
+    },
+    Array(Box<CType>, ArrayExtent),
+}
+
+/// The fixed-width integer and floating point types of C.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CPrimitive {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+}
+impl CPrimitive {
+    pub fn parse(name: &str) -> Option<CPrimitive> {
+        let primitive = match name {
+            "uint8_t" => CPrimitive::U8,
+            "uint16_t" => CPrimitive::U16,
+            "uint32_t" => CPrimitive::U32,
+            "uint64_t" => CPrimitive::U64,
+            "int8_t" => CPrimitive::I8,
+            "int16_t" => CPrimitive::I16,
+            "int32_t" => CPrimitive::I32,
+            "int64_t" => CPrimitive::I64,
+            "float" => CPrimitive::F32,
+            "double" => CPrimitive::F64,
+            _ => return None,
+        };
+        Some(primitive)
+    }
+
+    pub fn rust_name(self) -> &'static str {
+        match self {
+            CPrimitive::U8 => "u8",
+            CPrimitive::U16 => "u16",
+            CPrimitive::U32 => "u32",
+            CPrimitive::U64 => "u64",
+            CPrimitive::I8 => "i8",
+            CPrimitive::I16 => "i16",
+            CPrimitive::I32 => "i32",
+            CPrimitive::I64 => "i64",
+            CPrimitive::F32 => "f32",
+            CPrimitive::F64 => "f64",
+        }
+    }
+
+    /// Returns the size in bytes, which is also the alignment.
+    pub fn size(self) -> usize {
+        match self {
+            CPrimitive::U8 | CPrimitive::I8 => 1,
+            CPrimitive::U16 | CPrimitive::I16 => 2,
+            CPrimitive::U32 | CPrimitive::I32 | CPrimitive::F32 => 4,
+            CPrimitive::U64 | CPrimitive::I64 | CPrimitive::F64 => 8,
+        }
+    }
 }
 
 impl CType {
-    fn to_tokens(self) -> Tokens {
+    /// Returns the type with the given name, which is a `Primitive` if it is one.
+    pub fn from_name(name: &str) -> CType {
+        match CPrimitive::parse(name) {
+            Some(primitive) => CType::Primitive(primitive),
+            None => CType::Named(name.to_string()),
+        }
+    }
+
+    /// Returns an array of `element`s. Unsized arrays can't be held by value, they become a
+    /// pointer to their first element instead, which `is_const` applies to like for any pointer.
+    pub fn array(element: CType, extent: ArrayExtent, is_const: bool) -> CType {
+        match extent {
+            ArrayExtent::Unsized => CType::Pointer {
+                is_const,
+                pointee: Box::new(element),
+            },
+            extent => CType::Array(Box::new(element), extent),
+        }
+    }
+
+    pub fn to_tokens(&self) -> Tokens {
+        self.to_rust_tokens(&|name| {
+            let ident = name_to_tokens(name);
+            quote!(#ident)
+        })
+    }
+
+    /// Returns the Rust type, with `resolve` turning each named type into the path it should be
+    /// referred to by, like `vk::Extent2D` for `VkExtent2D`.
+    pub fn to_rust_tokens(&self, resolve: &impl Fn(&str) -> Tokens) -> Tokens {
         let term = match self {
             CType::USize => Term::intern("usize"),
             CType::U32 => Term::intern("u32"),
             CType::U64 => Term::intern("u64"),
             CType::Float => Term::intern("f32"),
             CType::Bool32 => Term::intern("Bool32"),
+            CType::Primitive(primitive) => Term::intern(primitive.rust_name()),
+            CType::Named(name) => return resolve(name),
+            CType::Pointer { is_const, pointee } => {
+                let pointee = pointee.to_rust_tokens(resolve);
+                return if *is_const {
+                    quote!(*const #pointee)
+                } else {
+                    quote!(*mut #pointee)
+                };
+            }
+            // Not built by `CType::array`, nothing is known to write through it
+            CType::Array(element, ArrayExtent::Unsized) => {
+                let element = element.to_rust_tokens(resolve);
+                return quote!(*const #element);
+            }
+            CType::Array(element, extent) => {
+                let element = element.to_rust_tokens(resolve);
+                return quote!([#element; #extent]);
+            }
         };
         quote! {#term}
     }
 }
 
+/// Parses a C declaration, like `const char* const* ppEnabledLayerNames` or
+/// `float matrix[3][4]`, into its name and type. `Foo* x[4]` is an array of pointers, while
+/// `Foo (*x)[4]` is a pointer to an array. The `const` of a pointer is kept by the type that points
+/// to it, a `const` on the declared name itself, like in `const uint32_t* const pCounts[4]`, has no
+/// Rust equivalent and only leaves the element pointing to const.
+pub fn parse_declaration(declaration: &str) -> Option<(String, CType)> {
+    match c_declaration(CompleteStr(declaration)) {
+        Ok((_, declaration)) => Some(declaration),
+        Err(_) => None,
+    }
+}
+
+/// A typedef of the `basetype` category, like `typedef uint64_t VkDeviceSize;` or
+/// `typedef void* VkRemoteAddressNV;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BaseType {
+    pub name: String,
+    pub ty: CType,
+}
+impl BaseType {
+    /// Parses the code of a base type. Returns `None` for anything that isn't a typedef of a single
+    /// name, like the opaque `struct ANativeWindow;` or `typedef uint32_t A, *B;`.
+    pub fn parse(code: &str) -> Option<BaseType> {
+        let mut base_types = BaseType::parse_all(code)?;
+        if base_types.len() == 1 {
+            base_types.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Parses a typedef with any number of declarators, `typedef uint32_t A, *B;` defines both
+    /// `A` and the pointer `B`. Returns `None` if any of the declarators can't be parsed, rather
+    /// than only the ones that can.
+    pub fn parse_all(code: &str) -> Option<Vec<BaseType>> {
+        let declaration = code.trim().strip_prefix("typedef ")?.strip_suffix(';')?;
+        split_declarators(declaration)
+            .iter()
+            .map(|declaration| {
+                let (name, ty) = parse_declaration(declaration)?;
+                Some(BaseType { name, ty })
+            })
+            .collect()
+    }
+}
+
+/// Splits a declaration with several declarators, like `const uint32_t a, *b`, into one
+/// declaration per declarator that repeats the type specifier, `const uint32_t a` and
+/// `const uint32_t *b`.
+fn split_declarators(declaration: &str) -> Vec<String> {
+    let mut declarators = declaration.split(',');
+    let first = declarators.next().unwrap_or_default().trim();
+    let base = match first.strip_prefix("const") {
+        Some(rest) if rest.starts_with(char::is_whitespace) => rest.trim_start(),
+        _ => first,
+    };
+    let base_len = base
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(base.len());
+    let specifier = &first[..first.len() - base.len() + base_len];
+    std::iter::once(first.to_string())
+        .chain(declarators.map(|declarator| format!("{} {}", specifier, declarator.trim())))
+        .collect()
+}
+/// Returns every base type of the registry that is a typedef, by name. Unlike vkxml, this keeps
+/// the pointer of typedefs like `VkRemoteAddressNV`.
+pub fn base_types(registry: &vk_parse::Registry) -> HashMap<String, BaseType> {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref types) => Some(types.children.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|child| match child {
+            vk_parse::TypesChild::Type(ty) if ty.category.as_deref() == Some("basetype") => {
+                match ty.spec {
+                    vk_parse::TypeSpec::Code(ref code) => BaseType::parse_all(&code.code),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .flatten()
+        .map(|base_type| (base_type.name.clone(), base_type))
+        .collect()
+}
+
+named!(c_identifier<CompleteStr, CompleteStr>,
+    take_while1!(|c: char| c.is_alphanumeric() || c == '_')
+);
+
+named!(c_const<CompleteStr, CompleteStr>,
+    verify!(c_identifier, |ident: CompleteStr| ident.0 == "const")
+);
+
+named!(c_declaration<CompleteStr, (String, CType)>,
+    ws!(do_parse!(
+        is_const: opt!(c_const) >>
+        base: c_identifier >>
+        pointers: many0!(ws!(do_parse!(
+            char!('*') >>
+            is_const: opt!(c_const) >>
+            (is_const.is_some())
+        ))) >>
+        declarator: alt!(
+            ws!(do_parse!(
+                char!('(') >>
+                char!('*') >>
+                name: c_identifier >>
+                char!(')') >>
+                (name, true)
+            )) |
+            map!(c_identifier, |name| (name, false))
+        ) >>
+        extents: many0!(ws!(delimited!(char!('['), take_until!("]"), char!(']')))) >>
+        eof!() >>
+        ({
+            let mut ty = CType::from_name(base.0);
+            let mut is_const = is_const.is_some();
+            for pointer_is_const in pointers {
+                ty = CType::Pointer { is_const, pointee: Box::new(ty) };
+                is_const = pointer_is_const;
+            }
+            // `x[2][3]` is an array of two arrays of three elements
+            let (name, is_pointer_to_array) = declarator;
+            let ty = extents.iter().rev().fold(ty, |ty, extent| {
+                CType::array(ty, ArrayExtent::parse(extent.0), is_const)
+            });
+            let ty = if is_pointer_to_array {
+                CType::Pointer { is_const, pointee: Box::new(ty) }
+            } else {
+                ty
+            };
+            (name.0.to_string(), ty)
+        })
+    ))
+);
+
 named!(ctype<&str, CType>,
     alt!(
         tag!("ULL") => { |_| CType::U64 } |
@@ -115,7 +792,13 @@ pub fn define_handle_macro() -> Tokens {
                 impl Handle for $name {
                     const TYPE: ObjectType = ObjectType::$ty;
                     fn as_raw(self) -> u64 { self.0 as u64 }
-                    fn from_raw(x: u64) -> Self { $name(x as _) }
+                    unsafe fn from_raw(x: u64) -> Self { $name(x as _) }
+                }
+
+                impl From<$name> for u64 {
+                    fn from(handle: $name) -> u64 {
+                        handle.as_raw()
+                    }
                 }
 
                 unsafe impl Send for $name {}
@@ -159,7 +842,13 @@ pub fn handle_nondispatchable_macro() -> Tokens {
                 impl Handle for $name {
                     const TYPE: ObjectType = ObjectType::$ty;
                     fn as_raw(self) -> u64 { self.0 as u64 }
-                    fn from_raw(x: u64) -> Self { $name(x as _) }
+                    unsafe fn from_raw(x: u64) -> Self { $name(x as _) }
+                }
+
+                impl From<$name> for u64 {
+                    fn from(handle: $name) -> u64 {
+                        handle.as_raw()
+                    }
                 }
 
                 impl $name{
@@ -185,24 +874,53 @@ pub fn handle_nondispatchable_macro() -> Tokens {
 }
 pub fn vk_version_macros() -> Tokens {
     quote! {
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_MAKE_API_VERSION.html>"]
+        pub const fn make_api_version(variant: u32, major: u32, minor: u32, patch: u32) -> u32 {
+            (variant << 29) | (major << 22) | (minor << 12) | patch
+        }
+
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_VARIANT.html>"]
+        pub const fn api_version_variant(version: u32) -> u32 {
+            version >> 29
+        }
+
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_MAJOR.html>"]
+        pub const fn api_version_major(version: u32) -> u32 {
+            (version >> 22) & 0x7f
+        }
+
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_MINOR.html>"]
+        pub const fn api_version_minor(version: u32) -> u32 {
+            (version >> 12) & 0x3ff
+        }
+
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_API_VERSION_PATCH.html>"]
+        pub const fn api_version_patch(version: u32) -> u32 {
+            version & 0xfff
+        }
+
+        #[deprecated = "This define is deprecated. make_api_version should be used instead."]
         #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_MAKE_VERSION.html>"]
         pub const fn make_version(major: u32, minor: u32, patch: u32) -> u32 {
-            (major << 22) | (minor << 12) | patch
+            make_api_version(0, major, minor, patch)
         }
 
+        #[deprecated = "This define is deprecated. api_version_major should be used instead."]
         #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_MAJOR.html>"]
         pub const fn version_major(version: u32) -> u32 {
             version >> 22
         }
 
+        #[deprecated = "This define is deprecated. api_version_minor should be used instead."]
         #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_MINOR.html>"]
         pub const fn version_minor(version: u32) -> u32 {
-            (version >> 12) & 0x3ff
+            api_version_minor(version)
         }
 
+        #[deprecated = "This define is deprecated. api_version_patch should be used instead."]
         #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VK_VERSION_PATCH.html>"]
         pub const fn version_patch(version: u32) -> u32 {
-            version & 0xfff
+            api_version_patch(version)
         }
     }
 }
@@ -393,6 +1111,10 @@ pub trait ConstantExt {
     fn variant_ident(&self, enum_name: &str) -> Ident;
     fn to_tokens(&self, ident: Option<Ident>) -> Tokens;
     fn notation(&self) -> Option<&str>;
+    /// Returns the platform define that guards this constant, if any.
+    fn protect(&self) -> Option<&str>;
+    /// Returns the deprecation note of this constant, if it is deprecated.
+    fn deprecated(&self) -> Option<&str>;
 }
 
 impl ConstantExt for vkxml::ExtensionEnum {
@@ -408,6 +1130,12 @@ impl ConstantExt for vkxml::ExtensionEnum {
     fn notation(&self) -> Option<&str> {
         self.notation.as_deref()
     }
+    fn protect(&self) -> Option<&str> {
+        None
+    }
+    fn deprecated(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl ConstantExt for vkxml::Constant {
@@ -421,6 +1149,12 @@ impl ConstantExt for vkxml::Constant {
     fn notation(&self) -> Option<&str> {
         self.notation.as_deref()
     }
+    fn protect(&self) -> Option<&str> {
+        None
+    }
+    fn deprecated(&self) -> Option<&str> {
+        None
+    }
 }
 
 #[derive(Debug)]
@@ -512,6 +1246,21 @@ impl Constant {
         }
     }
 
+    /// Returns the names of the constants that the value of this constant refers to, like
+    /// `VK_MAX_EXTENSION_NAME_SIZE` in `(VK_MAX_EXTENSION_NAME_SIZE - 1)`, in order of appearance.
+    /// Only C expressions can refer to other constants, suffixes like the `U` of `(~0U)` are part
+    /// of a number and not names.
+    pub fn referenced_constants(&self) -> Vec<&str> {
+        match self {
+            Constant::CExpr(expr) => expr
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .filter(|word| word.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+                .unique()
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     pub fn from_extension_enum(constant: &vkxml::ExtensionEnum) -> Option<Self> {
         let number = constant.number.map(Constant::Number);
         let hex = constant.hex.as_ref().map(|hex| Constant::Hex(hex.clone()));
@@ -535,15 +1284,52 @@ impl Constant {
     }
 }
 
-pub trait FeatureExt {
-    fn version_string(&self) -> String;
-    fn is_version(&self, major: u32, minor: u32) -> bool;
+/// The literal `value` of an `<enum>`, which is either an integer or a quoted string like the
+/// name of an extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumLiteral {
+    Int(i64),
+    /// The string without its quotes.
+    Str(String),
 }
-impl FeatureExt for vkxml::Feature {
-    fn is_version(&self, major: u32, minor: u32) -> bool {
-        let self_major = self.version as u32;
-        let self_minor = (self.version * 10.0) as u32 - self_major * 10;
-        major == self_major && self_minor == minor
+impl EnumLiteral {
+    /// Returns `None` for values that aren't literals, like references to other enums or C
+    /// expressions.
+    pub fn parse(value: &str) -> Option<EnumLiteral> {
+        if let Ok(n) = value.parse::<i64>() {
+            return Some(EnumLiteral::Int(n));
+        }
+        if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+            return Some(EnumLiteral::Str(value[1..value.len() - 1].to_string()));
+        }
+        None
+    }
+}
+
+/// The api version of a feature, ordered by `major` first and `minor` second.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+impl Version {
+    pub fn new(major: u32, minor: u32) -> Version {
+        Version { major, minor }
+    }
+}
+pub trait FeatureExt {
+    fn version_string(&self) -> String;
+    fn version(&self) -> Version;
+    fn is_version(&self, major: u32, minor: u32) -> bool;
+}
+impl FeatureExt for vkxml::Feature {
+    fn version(&self) -> Version {
+        let major = self.version as u32;
+        let minor = (self.version * 10.0) as u32 - major * 10;
+        Version::new(major, minor)
+    }
+    fn is_version(&self, major: u32, minor: u32) -> bool {
+        self.version() == Version::new(major, minor)
     }
     fn version_string(&self) -> String {
         let mut version = format!("{}", self.version);
@@ -560,6 +1346,14 @@ pub enum FunctionType {
     Instance,
     Device,
 }
+/// The dispatchable object that a command is loaded through.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DispatchLevel {
+    /// Loaded without an instance, like `vkCreateInstance`.
+    Entry,
+    Instance,
+    Device,
+}
 pub trait CommandExt {
     /// Returns the ident in snake_case and without the 'vk' prefix.
     fn function_type(&self) -> FunctionType;
@@ -567,13 +1361,101 @@ pub trait CommandExt {
     /// Returns true if the command is a device level command. This is indicated by
     /// the type of the first parameter.
     fn command_ident(&self) -> Ident;
+    /// Returns the index of every array parameter, along with where its length comes from.
+    fn length_pairs(&self) -> Vec<(usize, LenSource)>;
+    /// Returns the queues, render pass scope and command buffer levels the command may be
+    /// recorded or submitted with.
+    fn capabilities(&self) -> CommandCapabilities<'_>;
+    /// Returns the level the command is dispatched at, `extension` is the extension that
+    /// provides it. Commands of instance extensions are loaded through the instance, even if
+    /// their first parameter is a device.
+    fn dispatch_level(&self, extension: Option<&vk_parse::Extension>) -> DispatchLevel;
+}
+
+/// The `queues`, `renderpass` and `cmdbufferlevel` attributes of a command. All of them are
+/// empty for commands that aren't recorded into a command buffer or submitted to a queue.
+#[derive(Debug)]
+pub struct CommandCapabilities<'a> {
+    /// Queue types that support the command, like `graphics` or `compute`.
+    pub queues: Vec<&'a str>,
+    pub render_pass: Option<&'a vkxml::Renderpass>,
+    /// Command buffer levels the command can be recorded into, `primary` and/or `secondary`.
+    pub command_buffer_levels: Vec<&'a str>,
+}
+
+/// Where the length of a dynamic array member comes from, see [`FieldExt::member_len`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberLen<'a> {
+    /// Another member, like `enabledLayerCount`, or a member of one, like `pInfo->count`.
+    Member(&'a str),
+    Literal(u64),
+    NullTerminated,
+    /// The C form of a `latexmath` length, taken from `altlen`, like
+    /// `(rasterizationSamples + 31) / 32`.
+    Expression(&'a str),
+    /// A `latexmath` length without a C form, which has to be set by hand.
+    Manual,
+}
+
+/// Where the length of an array parameter comes from, described by its `len` attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LenSource {
+    /// The parameter with the given index, like `descriptorWriteCount`.
+    Param(usize),
+    /// A member of the struct parameter with the given index, like
+    /// `pAllocateInfo->commandBufferCount`.
+    Member(usize, String),
+    /// Anything that doesn't refer to a parameter, like a `latexmath` expression.
+    Expression(String),
+}
+
+fn split_identifiers(list: &Option<vkxml::CommaSeparatedIdentifiers>) -> Vec<&str> {
+    list.iter()
+        .flat_map(|list| list.split(','))
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
 }
 
 impl CommandExt for vkxml::Command {
+    fn capabilities(&self) -> CommandCapabilities<'_> {
+        CommandCapabilities {
+            queues: split_identifiers(&self.queues),
+            render_pass: self.renderpass.as_ref(),
+            command_buffer_levels: split_identifiers(&self.cmdbufferlevel),
+        }
+    }
+
     fn command_ident(&self) -> Ident {
         Ident::from(self.name[2..].to_snake_case().as_str())
     }
 
+    fn length_pairs(&self) -> Vec<(usize, LenSource)> {
+        let param_index = |name: &str| {
+            self.param
+                .iter()
+                .position(|param| param.name.as_deref() == Some(name))
+        };
+        self.param
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, param)| {
+                let len = param.size.as_ref()?;
+                let source = match len.split("->").collect::<Vec<_>>()[..] {
+                    [name] => param_index(name).map(LenSource::Param),
+                    [name, member] => {
+                        param_index(name).map(|idx| LenSource::Member(idx, member.to_string()))
+                    }
+                    _ => None,
+                };
+                Some((
+                    idx,
+                    source.unwrap_or_else(|| LenSource::Expression(len.clone())),
+                ))
+            })
+            .collect()
+    }
+
     fn function_type(&self) -> FunctionType {
         let is_first_param_device = self
             .param
@@ -600,6 +1482,86 @@ impl CommandExt for vkxml::Command {
             }
         }
     }
+
+    fn dispatch_level(&self, extension: Option<&vk_parse::Extension>) -> DispatchLevel {
+        let is_instance_extension =
+            extension.and_then(|ext| ext.ext_type.as_deref()) == Some("instance");
+        match self.function_type() {
+            FunctionType::Static | FunctionType::Entry => DispatchLevel::Entry,
+            FunctionType::Instance => DispatchLevel::Instance,
+            FunctionType::Device if is_instance_extension => DispatchLevel::Instance,
+            FunctionType::Device => DispatchLevel::Device,
+        }
+    }
+}
+
+/// The extent of a static array, like `[4]`, `[VK_UUID_SIZE]` or the sum of those.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrayExtent {
+    Literal(u64),
+    /// A named constant, which is resolved later on.
+    Constant(String),
+    Sum(Vec<ArrayExtent>),
+    /// The empty extent of `[]`, like a flexible array member. It has no length.
+    Unsized,
+}
+impl ArrayExtent {
+    pub fn parse(extent: &str) -> ArrayExtent {
+        if extent.trim().is_empty() {
+            return ArrayExtent::Unsized;
+        }
+        let mut terms: Vec<_> = extent
+            .split('+')
+            .map(|term| {
+                let term = term.trim();
+                term.parse()
+                    .map(ArrayExtent::Literal)
+                    .unwrap_or_else(|_| ArrayExtent::Constant(term.to_string()))
+            })
+            .collect();
+        if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            ArrayExtent::Sum(terms)
+        }
+    }
+
+    /// Returns the length of the array, or `None` if it references an unknown constant.
+    pub fn resolve<S: BuildHasher>(&self, array_sizes: &HashMap<&str, u64, S>) -> Option<u64> {
+        match self {
+            ArrayExtent::Literal(n) => Some(*n),
+            ArrayExtent::Constant(name) => array_sizes.get(name.as_str()).cloned(),
+            ArrayExtent::Sum(terms) => terms.iter().map(|term| term.resolve(array_sizes)).sum(),
+            ArrayExtent::Unsized => None,
+        }
+    }
+
+    /// Like `to_tokens`, but with constants qualified as `vk::MAX_EXTENSION_NAME_SIZE`, see
+    /// [`FieldExt::qualified_type_tokens`].
+    pub fn qualified_tokens(&self) -> Tokens {
+        match self {
+            ArrayExtent::Constant(name) => {
+                let name = Term::intern(&constant_name(name));
+                quote!(vk::#name)
+            }
+            ArrayExtent::Sum(terms) => {
+                let terms = terms.iter().map(ArrayExtent::qualified_tokens);
+                quote!(#(#terms)+*)
+            }
+            _ => quote!(#self),
+        }
+    }
+}
+impl quote::ToTokens for ArrayExtent {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        match self {
+            ArrayExtent::Literal(n) => Term::intern(&n.to_string()).to_tokens(tokens),
+            ArrayExtent::Constant(name) => Term::intern(&constant_name(name)).to_tokens(tokens),
+            ArrayExtent::Sum(terms) => quote!(#(#terms)+*).to_tokens(tokens),
+            // Has no length, `CType::array` turns unsized arrays into pointers
+            ArrayExtent::Unsized => (),
+        }
+    }
 }
 
 pub trait FieldExt {
@@ -612,6 +1574,37 @@ pub trait FieldExt {
     /// which is needed for `C` function parameters. Set to `false` for struct definitions.
     fn type_tokens(&self, is_ffi_param: bool) -> Tokens;
     fn is_clone(&self) -> bool;
+
+    /// Returns true if the Rust type of this field is `Copy`. Pointers always are, while members
+    /// held by value and fixed-size arrays are unless their type is one of `non_copy_types`,
+    /// regardless of the length of the array.
+    fn is_copy(&self, non_copy_types: &HashSet<&str, impl BuildHasher>) -> bool;
+
+    /// Returns true if the field is a null-terminated C string (`const char*`), as opposed to
+    /// a pointer to a buffer of bytes or an array of characters with an explicit length.
+    fn is_c_string(&self) -> bool;
+
+    /// Returns true if the field is a fixed-size `char` array that holds a null-terminated
+    /// string, like `char deviceName[VK_MAX_PHYSICAL_DEVICE_NAME_SIZE]`. Byte arrays, like a
+    /// `uint8_t` UUID, are not strings.
+    fn is_c_string_buffer(&self) -> bool;
+
+    /// Returns where the length of a dynamic array member comes from, described by its `len`.
+    fn member_len(&self) -> Option<MemberLen<'_>>;
+
+    /// Returns true if the field is a single `VkBool32` value, which can be converted from and
+    /// into a Rust `bool`.
+    fn is_bool32(&self) -> bool;
+
+    /// Returns the extent of the field if it is a static array.
+    fn array_extent(&self) -> Option<ArrayExtent>;
+
+    /// Like `type_tokens`, but with fully qualified paths such as `*const vk::ApplicationInfo`,
+    /// for describing a field outside of the generated `vk` module.
+    fn qualified_type_tokens(&self) -> Tokens;
+
+    /// Returns the C type of the field, with all of its pointers and their constness.
+    fn c_type(&self) -> CType;
 }
 
 pub trait ToTokens {
@@ -642,22 +1635,31 @@ impl ToTokens for vkxml::ReferenceType {
         }
     }
 }
-fn name_to_tokens(type_name: &str) -> Ident {
+/// Renames identifiers that are Rust keywords. `type` is common enough to get its own short name,
+/// every other keyword gets a trailing underscore.
+pub fn escape_keyword(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+        "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+        "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "return", "self", "static", "struct", "super", "trait", "true", "try", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    match name {
+        "type" => "ty".to_string(),
+        _ if KEYWORDS.contains(&name) => format!("{}_", name),
+        _ => name.to_string(),
+    }
+}
+pub fn name_to_tokens(type_name: &str) -> Ident {
+    if let Some(primitive) = CPrimitive::parse(type_name) {
+        return Ident::from(primitive.rust_name());
+    }
     let new_name = match type_name {
-        "uint8_t" => "u8",
-        "uint16_t" => "u16",
-        "uint32_t" => "u32",
-        "uint64_t" => "u64",
-        "int8_t" => "i8",
-        "int16_t" => "i16",
-        "int32_t" => "i32",
-        "int64_t" => "i64",
         "size_t" => "usize",
         "int" => "c_int",
         "void" => "c_void",
         "char" => "c_char",
-        "float" => "f32",
-        "double" => "f64",
         "long" => "c_ulong",
         _ => {
             if type_name.starts_with("Vk") {
@@ -670,6 +1672,18 @@ fn name_to_tokens(type_name: &str) -> Ident {
     let new_name = new_name.replace("FlagBits", "Flags");
     Ident::from(new_name.as_str())
 }
+/// Like `name_to_tokens`, but C types are qualified with `::std::os::raw` and every type that is
+/// not a Rust primitive with `vk`.
+pub fn qualified_name_to_tokens(type_name: &str) -> Tokens {
+    let ident = name_to_tokens(type_name);
+    match ident.as_ref() {
+        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "usize" | "f32" | "f64" => {
+            quote!(#ident)
+        }
+        "c_int" | "c_void" | "c_char" | "c_ulong" => quote!(::std::os::raw::#ident),
+        _ => quote!(vk::#ident),
+    }
+}
 fn to_type_tokens(type_name: &str, reference: Option<&vkxml::ReferenceType>) -> Tokens {
     let new_name = name_to_tokens(type_name);
     let ptr_name = reference.map(|r| r.to_tokens(false)).unwrap_or(quote! {});
@@ -680,13 +1694,64 @@ impl FieldExt for vkxml::Field {
     fn is_clone(&self) -> bool {
         true
     }
+    fn is_copy(&self, non_copy_types: &HashSet<&str, impl BuildHasher>) -> bool {
+        self.reference.is_some() || !non_copy_types.contains(self.basetype.as_str())
+    }
+    fn is_c_string(&self) -> bool {
+        self.basetype == "char"
+            && self.is_const
+            && self.size.is_none()
+            && matches!(self.reference, Some(vkxml::ReferenceType::Pointer))
+    }
+    fn member_len(&self) -> Option<MemberLen<'_>> {
+        if !matches!(self.array, Some(vkxml::ArrayType::Dynamic)) {
+            return None;
+        }
+        let len = match self.size.as_deref() {
+            Some(len) => len,
+            None if self.null_terminate => return Some(MemberLen::NullTerminated),
+            None => return None,
+        };
+        if len.starts_with("latexmath") {
+            return Some(match self.c_size.as_deref() {
+                Some(expression) => MemberLen::Expression(expression),
+                None => MemberLen::Manual,
+            });
+        }
+        Some(match len.parse() {
+            Ok(n) => MemberLen::Literal(n),
+            Err(_) => MemberLen::Member(len),
+        })
+    }
+    fn is_c_string_buffer(&self) -> bool {
+        self.basetype == "char" && self.reference.is_none() && is_static_array(self)
+    }
+    fn is_bool32(&self) -> bool {
+        self.basetype == "VkBool32" && self.reference.is_none() && self.array.is_none()
+    }
+    fn c_type(&self) -> CType {
+        let pointer = |is_const, pointee| CType::Pointer {
+            is_const,
+            pointee: Box::new(pointee),
+        };
+        let ty = CType::from_name(&self.basetype);
+        let ty = match self.reference {
+            Some(vkxml::ReferenceType::Pointer) => pointer(self.is_const, ty),
+            Some(vkxml::ReferenceType::PointerToPointer) => pointer(false, pointer(false, ty)),
+            Some(vkxml::ReferenceType::PointerToConstPointer) => {
+                pointer(self.is_const, pointer(true, ty))
+            }
+            None => ty,
+        };
+        match self.array_extent() {
+            // The `const` of an array of pointers belongs to what they point to
+            Some(extent) => CType::array(ty, extent, self.is_const && self.reference.is_none()),
+            None => ty,
+        }
+    }
     fn param_ident(&self) -> Ident {
         let name = self.name.as_deref().unwrap_or("field");
-        let name_corrected = match name {
-            "type" => "ty",
-            _ => name,
-        };
-        Ident::from(name_corrected.to_snake_case().as_str())
+        Ident::from(escape_keyword(&name.to_snake_case()))
     }
 
     fn type_tokens(&self, is_ffi_param: bool) -> Tokens {
@@ -699,40 +1764,129 @@ impl FieldExt for vkxml::Field {
         let pointer_ty = quote! {
             #pointer #ty
         };
-        let array = self.array.as_ref().and_then(|arraytype| match arraytype {
-            vkxml::ArrayType::Static => {
+        let array = self.array_extent().map(|size| {
+            // arrays in c are always passed as a pointer, which is only writable without `const`
+            if size == ArrayExtent::Unsized {
+                self.c_type().to_tokens()
+            } else if is_ffi_param && self.is_const {
+                quote! {
+                    &[#ty; #size]
+                }
+            } else if is_ffi_param {
+                quote! {
+                    &mut [#ty; #size]
+                }
+            } else {
+                quote! {
+                    [#ty; #size]
+                }
+            }
+        });
+        array.unwrap_or(pointer_ty)
+    }
+
+    fn qualified_type_tokens(&self) -> Tokens {
+        let ty = qualified_name_to_tokens(&self.basetype);
+        match self.array_extent() {
+            Some(ArrayExtent::Unsized) => {
+                return self
+                    .c_type()
+                    .to_rust_tokens(&|name| qualified_name_to_tokens(name))
+            }
+            Some(size) => {
+                let size = size.qualified_tokens();
+                return quote!([#ty; #size]);
+            }
+            None => (),
+        }
+        let pointer = self
+            .reference
+            .as_ref()
+            .map(|r| r.to_tokens(self.is_const))
+            .unwrap_or(quote! {});
+        quote!(#pointer #ty)
+    }
+
+    fn array_extent(&self) -> Option<ArrayExtent> {
+        match self.array {
+            Some(vkxml::ArrayType::Static) => {
                 let size = self
                     .size
                     .as_ref()
                     .or_else(|| self.size_enumref.as_ref())
                     .expect("Should have size");
-                // Make sure we also rename the constant, that is
-                // used inside the static array
-                let size = constant_name(size);
-                let size = Term::intern(&size);
-                // arrays in c are always passed as a pointer
-                if is_ffi_param {
-                    Some(quote! {
-                        &[#ty; #size]
-                    })
-                } else {
-                    Some(quote! {
-                        [#ty; #size]
-                    })
-                }
+                Some(ArrayExtent::parse(size))
             }
             _ => None,
-        });
-        array.unwrap_or(pointer_ty)
+        }
+    }
+}
+
+pub trait StructExt {
+    /// Returns a hash of the names and types of the members, in order. Comments and attributes
+    /// like `optional` are not part of it, so the hash only changes with the layout of the struct.
+    fn content_hash(&self) -> u32;
+    /// Returns true for `returnedonly` structs, which are only ever filled in by the
+    /// implementation.
+    fn is_returned_only(&self) -> bool;
+    /// Returns the offset in bytes of the member `member_name` on the target of `layouts`, which
+    /// also provides the layouts of the member types. `None` if there is no such member, or if a
+    /// member before it has no known layout.
+    fn member_offset(&self, member_name: &str, layouts: &TypeLayouts) -> Option<usize>;
+}
+impl StructExt for vkxml::Struct {
+    fn is_returned_only(&self) -> bool {
+        self.is_return
+    }
+
+    fn member_offset(&self, member_name: &str, layouts: &TypeLayouts) -> Option<usize> {
+        let (offsets, _) = layouts.struct_offsets(self);
+        offsets
+            .into_iter()
+            .find(|(name, _)| *name == member_name)
+            .map(|(_, offset)| offset)
+    }
+
+    fn content_hash(&self) -> u32 {
+        let members = self
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::StructElement::Member(field) => Some(field),
+                _ => None,
+            })
+            .map(|field| {
+                format!(
+                    "{}:{:?}",
+                    field.name.as_deref().unwrap_or_default(),
+                    field.c_type()
+                )
+            })
+            .join(";");
+        fnv1a(&members)
     }
 }
 
 pub type CommandMap<'a> = HashMap<vkxml::Identifier, &'a vkxml::Command>;
 
+/// Returns the sorted names of all commands that list `error`, like `VK_ERROR_DEVICE_LOST`, in
+/// their `errorcodes`.
+pub fn commands_returning<'a>(commands: &CommandMap<'a>, error: &str) -> Vec<&'a str> {
+    let mut names: Vec<_> = commands
+        .values()
+        .filter(|cmd| split_identifiers(&cmd.return_type.errorcodes).contains(&error))
+        .map(|cmd| cmd.name.as_str())
+        .collect();
+    names.sort_unstable();
+    names
+}
+
 fn generate_function_pointers<'a>(
     ident: Ident,
+    attributes: Tokens,
     commands: &[&'a vkxml::Command],
-    aliases: &HashMap<String, String, impl BuildHasher>,
+    aliases: &HashMap<String, &'a str, impl BuildHasher>,
+    pfn_cfgs: &HashMap<String, Tokens, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
 ) -> quote::Tokens {
     // Commands can have duplicates inside them because they are declared per features. But we only
@@ -764,6 +1918,25 @@ fn generate_function_pointers<'a>(
         })
         .collect();
 
+    // Aliases have the same signature as the command they alias, so its function pointer type is
+    // re-exported under their name instead of being declared again, behind the same cfg.
+    let pfn_aliases: Vec<_> = commands
+        .iter()
+        .filter_map(|cmd| {
+            let alias = *aliases.get(&cmd.name)?;
+            if !fn_cache.insert(alias) {
+                return None;
+            }
+            let pfn_name = Ident::from(format!("PFN_{}", cmd.name));
+            let pfn_alias = Ident::from(format!("PFN_{}", alias));
+            let cfg = pfn_cfgs.get(&cmd.name);
+            Some(quote! {
+                #cfg
+                pub use crate::vk::#pfn_name as #pfn_alias;
+            })
+        })
+        .collect();
+
     let function_name_raw = |name: &str| -> String {
         if let Some(alias_name) = aliases.get(name) {
             alias_name.to_string()
@@ -788,8 +1961,6 @@ fn generate_function_pointers<'a>(
         .map(|cmd| Ident::from(function_name_raw(cmd.name.as_str()).as_str()))
         .collect();
     let raw_names_ref = &raw_names;
-    let names_left = &names;
-    let names_right = &names;
     let khronos_links: Vec<_> = raw_names.iter().map(|name| khronos_link(name)).collect();
 
     let params: Vec<Vec<(Ident, Tokens)>> = commands
@@ -808,16 +1979,6 @@ fn generate_function_pointers<'a>(
         })
         .collect();
 
-    let params_names: Vec<Vec<_>> = params
-        .iter()
-        .map(|inner_params| {
-            inner_params
-                .iter()
-                .map(|&(param_name, _)| param_name)
-                .collect()
-        })
-        .collect();
-    let param_names_ref = &params_names;
     let expanded_params: Vec<_> = params
         .iter()
         .map(|inner_params| {
@@ -843,12 +2004,46 @@ fn generate_function_pointers<'a>(
         .collect();
     let expanded_params_ref = &expanded_params;
 
+    // Enumerate helpers and wrappers take the name of their command, instead of the method with
+    // the C signature. Its function pointer is still available as the field of the table.
+    let helpers: Vec<_> = commands
+        .iter()
+        .zip(names_ref)
+        .zip(&khronos_links)
+        .map(|((cmd, name), link)| {
+            generate_enumerate_helper(name, cmd)
+                .or_else(|| generate_command_wrapper(name, link, cmd))
+        })
+        .collect();
+    let methods: Vec<_> = commands
+        .iter()
+        .zip(names_ref)
+        .zip(&khronos_links)
+        .zip(&helpers)
+        .filter(|(_, helper)| helper.is_none())
+        .map(|(((cmd, name), link), _)| {
+            let params = cmd.param.iter().map(|field| {
+                let name = field.param_ident();
+                let ty = field.type_tokens(true);
+                quote!(#name: #ty)
+            });
+            let args = cmd.param.iter().map(|field| field.param_ident());
+            let return_ty = cmd.return_type.type_tokens(true);
+            let capability_docs = capability_docs(cmd);
+            quote! {
+                #[doc = #link]
+                #capability_docs
+                pub unsafe fn #name(&self, #(#params,)*) -> #return_ty {
+                    (self.#name)(#(#args,)*)
+                }
+            }
+        })
+        .collect();
     let return_types: Vec<_> = commands
         .iter()
         .map(|cmd| cmd.return_type.type_tokens(true))
         .collect();
     let return_types_ref = &return_types;
-
     let pfn_names: Vec<_> = commands_pfn
         .iter()
         .map(|cmd| Ident::from(format!("PFN_{}", cmd.name.as_str())))
@@ -883,7 +2078,11 @@ fn generate_function_pointers<'a>(
             #[allow(non_camel_case_types)]
             pub type #pfn_names_ref = extern "system" fn(#(#signature_params_ref),*) -> #pfn_return_types_ref;
         )*
+        #(#pfn_aliases)*
 
+        // Function pointers are `Copy`, which lets the loaders that hold a table derive `Clone`.
+        #[derive(Clone, Copy)]
+        #attributes
         pub struct #ident {
             #(
                 pub #names_ref: extern "system" fn(#expanded_params_ref) -> #return_types_ref,
@@ -892,14 +2091,6 @@ fn generate_function_pointers<'a>(
 
         unsafe impl Send for #ident {}
         unsafe impl Sync for #ident {}
-
-        impl ::std::clone::Clone for #ident {
-            fn clone(&self) -> Self {
-                #ident{
-                    #(#names_left: self.#names_right,)*
-                }
-            }
-        }
         impl #ident {
             pub fn load<F>(mut _f: F) -> Self
                 where F: FnMut(&::std::ffi::CStr) -> *const c_void
@@ -924,18 +2115,287 @@ fn generate_function_pointers<'a>(
                     )*
                 }
             }
-            #(
-                #[doc = #khronos_links]
-                pub unsafe fn #names_ref(&self, #expanded_params_ref) -> #return_types_ref {
-                    (self.#names_left)(#(#param_names_ref,)*)
+            #(#methods)*
+        }
+        impl #ident {
+            #(#helpers)*
+        }
+    }
+}
+// Required C strings are passed as `&CStr` to the helpers. The function pointers on the tables
+// keep the raw `*const c_char`, which is what the loaders in ash pass to them.
+fn is_cstr_param(field: &vkxml::Field) -> bool {
+    field.is_c_string() && field.optional.is_none()
+}
+/// Generates the method that calls the function pointer of a command. Input arrays are taken as a
+/// slice that also sets their count parameter, slices that share a count must have the same length
+/// or the method panics. An output array whose length is known up front, from such a slice or from
+/// a member of a struct parameter, is returned as a `Vec`. Required C strings are taken as `&CStr`.
+///
+/// Returns `None` if the method would keep the C signature.
+fn generate_command_wrapper(name: &Ident, link: &Literal, cmd: &vkxml::Command) -> Option<Tokens> {
+    let params = &cmd.param;
+    let is_array_pointer = |field: &vkxml::Field| {
+        field.basetype != "void" && matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+    };
+    let is_count = |field: &vkxml::Field| field.basetype == "uint32_t" && field.reference.is_none();
+    let length_pairs = cmd.length_pairs();
+
+    // Maps each hidden count parameter to the slices that share it, the first one sets it.
+    let mut counts: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let mut slices = HashSet::new();
+    for (data_idx, source) in &length_pairs {
+        let data = &params[*data_idx];
+        if let LenSource::Param(count_idx) = *source {
+            if data.is_const && is_array_pointer(data) && is_count(&params[count_idx]) {
+                counts.entry(count_idx).or_default().push(*data_idx);
+                slices.insert(*data_idx);
+            }
+        }
+    }
+    // Only a single array can be returned, next to a `Result` at most.
+    let can_return_array = matches!(cmd.return_type.basetype.as_str(), "VkResult" | "void");
+    let outputs: Vec<_> = length_pairs
+        .iter()
+        .filter(|(data_idx, _)| {
+            let data = &params[*data_idx];
+            !data.is_const && is_array_pointer(data)
+        })
+        .filter_map(|(data_idx, source)| {
+            let len = match source {
+                LenSource::Param(count_idx) => {
+                    let slice = params[*counts.get(count_idx)?.first()?].param_ident();
+                    quote!(#slice.len())
                 }
-            )*
+                LenSource::Member(param_idx, member) => {
+                    let param = params[*param_idx].param_ident();
+                    let member = Term::intern(&escape_keyword(&member.to_snake_case()));
+                    quote!((*#param).#member as usize)
+                }
+                LenSource::Expression(_) => return None,
+            };
+            Some((*data_idx, len))
+        })
+        .collect();
+    let output = match outputs[..] {
+        [ref output] if can_return_array => Some(output),
+        _ => None,
+    };
+    if counts.is_empty() && output.is_none() && !params.iter().any(is_cstr_param) {
+        return None;
+    }
+
+    let shared_counts: Vec<_> = counts
+        .values()
+        .filter(|slices| slices.len() > 1)
+        .map(|slices| {
+            let names: Vec<_> = slices
+                .iter()
+                .map(|&idx| params[idx].param_ident())
+                .collect();
+            names
+        })
+        .collect();
+    let length_checks = shared_counts.iter().flat_map(|names| {
+        let first = &names[0];
+        names[1..]
+            .iter()
+            .map(move |slice| quote!(assert_eq!(#first.len(), #slice.len());))
+    });
+    let panics_docs = if shared_counts.is_empty() {
+        quote!()
+    } else {
+        let lines = shared_counts.iter().map(|names| {
+            let names: Vec<_> = names.iter().map(|name| format!("`{}`", name)).collect();
+            format!("Panics if the lengths of {} differ.", names.join(", "))
+        });
+        quote! {
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #(#[doc = #lines])*
+        }
+    };
+    let (method_params, method_args) =
+        wrapper_params(cmd, &counts, &slices, output.map(|&(idx, _)| idx));
+    let (return_ty, body) = match output {
+        None => {
+            let return_ty = cmd.return_type.type_tokens(true);
+            (return_ty, quote!((self.#name)(#method_args)))
+        }
+        Some((data_idx, len)) => {
+            let data_ident = params[*data_idx].param_ident();
+            let data_ty = name_to_tokens(&params[*data_idx].basetype);
+            let alloc = quote!(let mut #data_ident = vec![#data_ty::default(); #len];);
+            if cmd.return_type.basetype == "VkResult" {
+                (
+                    quote!(VkResult<Vec<#data_ty>>),
+                    quote! {
+                        #alloc
+                        (self.#name)(#method_args).result_with_success(#data_ident)
+                    },
+                )
+            } else {
+                (
+                    quote!(Vec<#data_ty>),
+                    quote! {
+                        #alloc
+                        (self.#name)(#method_args);
+                        #data_ident
+                    },
+                )
+            }
+        }
+    };
+    let capability_docs = capability_docs(cmd);
+    Some(quote! {
+        #[doc = #link]
+        #capability_docs
+        #panics_docs
+        pub unsafe fn #name(&self, #method_params) -> #return_ty {
+            #(#length_checks)*
+            #body
+        }
+    })
+}
+/// Documents the queues, render pass scope and command buffer levels a command is restricted to.
+fn capability_docs(cmd: &vkxml::Command) -> Tokens {
+    let capabilities = cmd.capabilities();
+    let mut lines = Vec::new();
+    if !capabilities.queues.is_empty() {
+        lines.push(format!(
+            "Supported queue types: {}",
+            capabilities.queues.join(", ")
+        ));
+    }
+    match capabilities.render_pass {
+        Some(vkxml::Renderpass::Inside) => lines.push("Must be called inside a render pass".into()),
+        Some(vkxml::Renderpass::Outside) => {
+            lines.push("Must be called outside of a render pass".into())
+        }
+        Some(vkxml::Renderpass::Both) => {
+            lines.push("Can be called inside and outside of a render pass".into())
+        }
+        None => (),
+    }
+    if !capabilities.command_buffer_levels.is_empty() {
+        lines.push(format!(
+            "Supported command buffer levels: {}",
+            capabilities.command_buffer_levels.join(", ")
+        ));
+    }
+    if lines.is_empty() {
+        return quote!();
+    }
+    quote! {
+        #[doc = ""]
+        #(#[doc = #lines])*
+    }
+}
+/// Returns the parameters of a command wrapper, and the arguments that it passes to the function
+/// pointer.
+fn wrapper_params(
+    cmd: &vkxml::Command,
+    counts: &BTreeMap<usize, Vec<usize>>,
+    slices: &HashSet<usize>,
+    output: Option<usize>,
+) -> (Tokens, Tokens) {
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for (idx, field) in cmd.param.iter().enumerate() {
+        let name = field.param_ident();
+        if let Some(count_slices) = counts.get(&idx) {
+            let slice = cmd.param[count_slices[0]].param_ident();
+            args.push(quote!(#slice.len() as _));
+        } else if slices.contains(&idx) {
+            let ty = name_to_tokens(&field.basetype);
+            params.push(quote!(#name: &[#ty]));
+            args.push(quote!(#name.as_ptr()));
+        } else if output == Some(idx) {
+            args.push(quote!(#name.as_mut_ptr()));
+        } else if is_cstr_param(field) {
+            params.push(quote!(#name: &::std::ffi::CStr));
+            args.push(quote!(#name.as_ptr()));
+        } else {
+            let ty = field.type_tokens(true);
+            params.push(quote!(#name: #ty));
+            args.push(quote!(#name));
         }
     }
+    (quote!(#(#params,)*), quote!(#(#args,)*))
+}
+/// Generates a helper for `vkEnumerate*` commands, which end in a count and an output array that is
+/// sized by it. Like `read_into_uninitialized_vector` in ash, the helper queries the count first
+/// and returns the filled `Vec`, querying again while the count grew in between and the command
+/// returns `INCOMPLETE`.
+fn generate_enumerate_helper(name: &Ident, cmd: &vkxml::Command) -> Option<Tokens> {
+    if !cmd.name.starts_with("vkEnumerate") || cmd.return_type.basetype != "VkResult" {
+        return None;
+    }
+    let (data, rest) = cmd.param.split_last()?;
+    let (count, params) = rest.split_last()?;
+    let is_out_pointer = |field: &vkxml::Field| {
+        !field.is_const && matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+    };
+    if count.basetype != "uint32_t"
+        || !is_out_pointer(count)
+        || !is_out_pointer(data)
+        || data.size.as_ref() != count.name.as_ref()
+    {
+        return None;
+    }
+    let link = khronos_link(&cmd.name);
+    let data_ty = name_to_tokens(&data.basetype);
+    let method_params = params.iter().map(|field| {
+        let name = field.param_ident();
+        if is_cstr_param(field) {
+            quote! {#name: &::std::ffi::CStr}
+        } else {
+            let ty = field.type_tokens(true);
+            quote! {#name: #ty}
+        }
+    });
+    let method_args: Vec<_> = params
+        .iter()
+        .map(|field| {
+            let name = field.param_ident();
+            if is_cstr_param(field) {
+                quote! {#name.as_ptr()}
+            } else {
+                quote! {#name}
+            }
+        })
+        .collect();
+    let method_args_ref = &method_args;
+    Some(quote! {
+        #[doc = #link]
+        pub unsafe fn #name(&self, #(#method_params,)*) -> VkResult<Vec<#data_ty>> {
+            loop {
+                let mut count = 0;
+                (self.#name)(#(#method_args_ref,)* &mut count, ::std::ptr::null_mut()).result()?;
+                // Output structs need their `s_type` set, so the elements are default initialized.
+                let mut data = vec![#data_ty::default(); count as usize];
+                let err_code = (self.#name)(#(#method_args_ref,)* &mut count, data.as_mut_ptr());
+                if err_code != Result::INCOMPLETE {
+                    data.truncate(count as usize);
+                    break err_code.result_with_success(data);
+                }
+            }
+        }
+    })
 }
 pub struct ExtensionConstant<'a> {
     pub name: &'a str,
     pub constant: Constant,
+    /// The name of the enum that is extended by this constant.
+    pub extends: &'a str,
+    pub is_alias: bool,
+    /// The platform define that guards this constant. Constants inherit it from the extension
+    /// that adds them.
+    pub protect: Option<&'a str>,
+    /// Constants of extensions that are deprecated or obsoleted are deprecated as well, see
+    /// [`ExtensionExt::deprecation_note`].
+    pub deprecated: Option<String>,
 }
 impl<'a> ConstantExt for ExtensionConstant<'a> {
     fn variant_ident(&self, enum_name: &str) -> Ident {
@@ -947,101 +2407,205 @@ impl<'a> ConstantExt for ExtensionConstant<'a> {
     fn notation(&self) -> Option<&str> {
         None
     }
+    fn protect(&self) -> Option<&str> {
+        self.protect
+    }
+    fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
 }
 
-pub fn generate_extension_constants<'a>(
-    extension_name: &str,
+/// Collects the constants that an extension or feature adds to existing enums. They are
+/// `deprecated` with the given note if the extension is.
+pub fn extension_constants<'a>(
     extension_number: i64,
     extension_items: &'a [vk_parse::ExtensionChild],
-    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
-    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
-) -> quote::Tokens {
+    protect: Option<&'a str>,
+    deprecated: Option<&str>,
+) -> Vec<ExtensionConstant<'a>> {
     use vk_parse::EnumSpec;
-    let items = extension_items
+    extension_items
         .iter()
         .filter_map(|item| match item {
             vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
             _ => None,
         })
-        .flat_map(|iter| iter);
-    let enum_tokens = items.filter_map(|item| match item {
-        vk_parse::InterfaceItem::Enum(_enum) => {
-            if const_cache.contains(_enum.name.as_str()) {
-                return None;
-            }
-            let (constant, extends, is_alias) = match &_enum.spec {
-                EnumSpec::Bitpos { bitpos, extends } => {
-                    Some((Constant::BitPos(*bitpos as u32), extends.clone(), false))
-                }
-                EnumSpec::Offset {
-                    offset,
-                    extends,
-                    extnumber,
-                    dir: positive,
-                } => {
-                    let ext_base = 1_000_000_000;
-                    let ext_block_size = 1000;
-                    let extnumber = extnumber.unwrap_or_else(|| extension_number);
-                    let value = ext_base + (extnumber - 1) * ext_block_size + offset;
-                    let value = if *positive { value } else { -value };
-                    Some((Constant::Number(value as i32), Some(extends.clone()), false))
-                }
-                EnumSpec::Value { value, extends } => {
-                    if let (Some(extends), Ok(value)) = (extends, value.parse::<i32>()) {
-                        Some((Constant::Number(value), Some(extends.clone()), false))
-                    } else {
-                        None
+        .flat_map(|iter| iter)
+        .filter_map(|item| match item {
+            vk_parse::InterfaceItem::Enum(_enum) => {
+                let (constant, extends, is_alias) = match &_enum.spec {
+                    EnumSpec::Bitpos { bitpos, extends } => {
+                        Some((Constant::BitPos(*bitpos as u32), extends.as_deref(), false))
                     }
-                }
-                EnumSpec::Alias { alias, extends } => {
-                    if let Some(extends) = extends {
-                        let ident = name_to_tokens(&extends);
-                        let key = variant_ident(&extends, &alias);
-                        if key == "DISPATCH_BASE" {
-                            None
+                    EnumSpec::Offset {
+                        offset,
+                        extends,
+                        extnumber,
+                        dir: positive,
+                    } => {
+                        let ext_base = 1_000_000_000;
+                        let ext_block_size = 1000;
+                        let extnumber = extnumber.unwrap_or_else(|| extension_number);
+                        let value = ext_base + (extnumber - 1) * ext_block_size + offset;
+                        let value = if *positive { value } else { -value };
+                        Some((
+                            Constant::Number(value as i32),
+                            Some(extends.as_str()),
+                            false,
+                        ))
+                    }
+                    EnumSpec::Value { value, extends } => {
+                        match (extends, EnumLiteral::parse(value)) {
+                            (Some(extends), Some(EnumLiteral::Int(value))) => Some((
+                                Constant::Number(value as i32),
+                                Some(extends.as_str()),
+                                false,
+                            )),
+                            _ => None,
+                        }
+                    }
+                    EnumSpec::Alias { alias, extends } => {
+                        if let Some(extends) = extends {
+                            let ident = name_to_tokens(&extends);
+                            let key = variant_ident(&extends, &alias);
+                            if key == "DISPATCH_BASE" {
+                                None
+                            } else {
+                                Some((Constant::Alias(ident, key), Some(extends.as_str()), true))
+                            }
                         } else {
-                            Some((Constant::Alias(ident, key), Some(extends.clone()), true))
+                            None
                         }
-                    } else {
-                        None
                     }
-                }
-                _ => None,
-            }?;
-            let extends = extends?;
-            let ext_constant = ExtensionConstant {
-                name: &_enum.name,
-                constant,
-            };
-            let ident = name_to_tokens(&extends);
+                    _ => None,
+                }?;
+                Some(ExtensionConstant {
+                    name: &_enum.name,
+                    constant,
+                    extends: extends?,
+                    is_alias,
+                    protect,
+                    deprecated: deprecated.map(str::to_string),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns every enumerant as `(enum, name, value)`, including the ones that extensions add to
+/// existing enums. Aliases are skipped because they don't have a value of their own.
+pub fn all_enumerants<'a>(
+    enums: &'a [&'a vkxml::Enumeration],
+    extensions: &'a [vk_parse::Extension],
+) -> impl Iterator<Item = (&'a str, &'a str, i64)> + 'a {
+    fn signed_value(constant: &Constant) -> Option<i64> {
+        match *constant {
+            Constant::Number(n) => Some(i64::from(n)),
+            _ => constant.value().map(|value| value.bits() as i64),
+        }
+    }
+    let core = enums.iter().flat_map(|_enum| {
+        _enum.elements.iter().filter_map(move |elem| match elem {
+            vkxml::EnumerationElement::Enum(ref constant) => {
+                let value = signed_value(&Constant::from_constant(constant))?;
+                Some((_enum.name.as_str(), constant.name.as_str(), value))
+            }
+            _ => None,
+        })
+    });
+    let extended = extensions
+        .iter()
+        .filter_map(|extension| Some((extension.number?, &extension.children)))
+        .flat_map(|(number, children)| extension_constants(number, children, None, None))
+        .filter_map(|ext_constant| {
+            let value = signed_value(&ext_constant.constant)?;
+            Some((ext_constant.extends, ext_constant.name, value))
+        });
+    core.chain(extended)
+}
+
+/// Maps every enum that declares a reserved range to that range.
+pub fn reserved_ranges(registry: &vk_parse::Registry) -> HashMap<&str, (i64, i64)> {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Enums(ref enums) => {
+                Some((enums.name.as_deref()?, enums.reserved_range()?))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Checks that every enumerant lies within the reserved range of its enum, which catches mistakes
+/// in the offset calculation of extension enumerants.
+pub fn check_reserved_ranges<'a>(
+    ranges: &HashMap<&str, (i64, i64), impl BuildHasher>,
+    mut enumerants: impl Iterator<Item = (&'a str, &'a str, i64)>,
+) -> Result<(), String> {
+    let out_of_range = enumerants.find(|&(_enum, _, value)| match ranges.get(_enum) {
+        Some(&(start, end)) => value < start || value > end,
+        None => false,
+    });
+    match out_of_range {
+        Some((_enum, name, value)) => {
+            let (start, end) = ranges[_enum];
+            Err(format!(
+                "{} = {} is outside of the reserved range {}..={} of {}",
+                name, value, start, end, _enum
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+pub fn generate_extension_constants<'a>(
+    extension_name: &str,
+    extension_number: i64,
+    extension_items: &'a [vk_parse::ExtensionChild],
+    protect: Option<&'a str>,
+    deprecated: Option<&str>,
+    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+) -> quote::Tokens {
+    let enum_tokens = extension_constants(extension_number, extension_items, protect, deprecated)
+        .into_iter()
+        .filter_map(|ext_constant| {
+            if const_cache.contains(ext_constant.name) {
+                return None;
+            }
+            let ident = name_to_tokens(ext_constant.extends);
             const_values
                 .get_mut(&ident)
                 .unwrap()
                 .push(ConstantMatchInfo {
-                    ident: ext_constant.variant_ident(&extends),
-                    is_alias,
+                    ident: ext_constant.variant_ident(ext_constant.extends),
+                    is_alias: ext_constant.is_alias,
+                    protect: ext_constant.protect.map(str::to_string),
                 });
-            let impl_block = bitflags_impl_block(ident, &extends, &[&ext_constant]);
+            let impl_block = bitflags_impl_block(ident, ext_constant.extends, &[&ext_constant]);
             let doc_string = format!("Generated from '{}'", extension_name);
             let q = quote! {
                 #[doc = #doc_string]
                 #impl_block
             };
 
-            const_cache.insert(_enum.name.as_str());
+            const_cache.insert(ext_constant.name);
             Some(q)
-        }
-        _ => None,
-    });
+        });
     quote! {
         #(#enum_tokens)*
     }
 }
 pub fn generate_extension_commands<'a>(
     extension_name: &str,
-    items: &[vk_parse::ExtensionChild],
+    attributes: Tokens,
+    items: &'a [vk_parse::ExtensionChild],
     cmd_map: &CommandMap<'a>,
     cmd_aliases: &HashMap<String, String, impl BuildHasher>,
+    pfn_cfgs: &HashMap<String, Tokens, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
 ) -> Tokens {
     let mut commands = Vec::new();
@@ -1065,14 +2629,14 @@ pub fn generate_extension_commands<'a>(
                 .get(name)
                 .and_then(|alias_name| cmd_map.get(alias_name).copied())
             {
-                aliases.insert(cmd.name.clone(), name.to_string());
+                aliases.insert(cmd.name.clone(), name.as_str());
                 commands.push(cmd);
             }
         });
 
     let name = format!("{}Fn", extension_name.to_camel_case());
     let ident = Ident::from(&name[2..]);
-    let fp = generate_function_pointers(ident, &commands, &aliases, fn_cache);
+    let fp = generate_function_pointers(ident, attributes, &commands, &aliases, pfn_cfgs, fn_cache);
     let byte_name = format!("{}\0", extension_name);
 
     let byte_name_ident =
@@ -1089,12 +2653,62 @@ pub fn generate_extension_commands<'a>(
         #fp
     }
 }
+/// Returns the attributes of the function pointer struct of an extension, which warn about
+/// extensions that are deprecated, provisional or only intended for special use.
+pub fn extension_attributes(extension: &vk_parse::Extension) -> Tokens {
+    let special_use = extension.special_use();
+    let special_use_doc = if special_use.is_empty() {
+        None
+    } else {
+        let doc = format!("⚠️ special use: {}", special_use.join(", "));
+        Some(quote! {
+            #[doc = #doc]
+        })
+    };
+    let provisional_doc = if extension.is_provisional() {
+        Some(quote! {
+            #[doc = "⚠️ provisional — API may change"]
+        })
+    } else {
+        None
+    };
+    let promoted_doc = extension.promoted_to().map(|version| {
+        let doc = format!(
+            "Promoted to Vulkan {}.{} core",
+            version.major, version.minor
+        );
+        quote! {
+            #[doc = #doc]
+        }
+    });
+    let deprecated = extension.deprecatedby.as_ref().map(|deprecated_by| {
+        if deprecated_by.is_empty() {
+            quote! {
+                #[deprecated]
+            }
+        } else {
+            let note = format!("Deprecated by {}", deprecated_by);
+            quote! {
+                #[deprecated(note = #note)]
+            }
+        }
+    });
+    quote! {
+        #special_use_doc
+        #provisional_doc
+        #promoted_doc
+        #deprecated
+    }
+}
+#[allow(clippy::too_many_arguments)]
 pub fn generate_extension<'a>(
     extension: &'a vk_parse::Extension,
+    platforms: &'a [vk_parse::Platform],
     cmd_map: &CommandMap<'a>,
     const_cache: &mut HashSet<&'a str, impl BuildHasher>,
     const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
     cmd_aliases: &HashMap<String, String, impl BuildHasher>,
+    pfn_cfgs: &HashMap<String, Tokens, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
 ) -> Option<quote::Tokens> {
     // Okay this is a little bit odd. We need to generate all extensions, even disabled ones,
@@ -1107,25 +2721,91 @@ pub fn generate_extension<'a>(
         &extension.name,
         extension.number.unwrap_or(0),
         &extension.children,
+        extension.platform_protect(platforms),
+        extension.deprecation_note().as_deref(),
         const_cache,
         const_values,
     );
+    let string_tokens = generate_extension_strings(&extension.children, const_cache);
     let fp = generate_extension_commands(
         &extension.name,
+        extension_attributes(extension),
         &extension.children,
         cmd_map,
         cmd_aliases,
+        pfn_cfgs,
         fn_cache,
     );
+    // The constants stay available, they are referenced by the debug impls of the enums.
+    let fp = if extension.is_provisional() {
+        gate_items(&quote!(#[cfg(feature = "provisional")]), fp)
+    } else {
+        fp
+    };
     let q = quote! {
         #fp
         #extension_tokens
+        #string_tokens
     };
     Some(q)
 }
-pub fn generate_typedef(typedef: &vkxml::Typedef) -> Tokens {
+
+/// Generates a `&CStr` constant for every string-valued enumerant of an extension, like
+/// `VK_KHR_SWAPCHAIN_EXTENSION_NAME`.
+pub fn generate_extension_strings<'a>(
+    extension_items: &'a [vk_parse::ExtensionChild],
+    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
+) -> Tokens {
+    let strings = extension_items
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|item| match item {
+            vk_parse::InterfaceItem::Enum(_enum) => match &_enum.spec {
+                vk_parse::EnumSpec::Value {
+                    value,
+                    extends: None,
+                } => match EnumLiteral::parse(value) {
+                    Some(EnumLiteral::Str(text)) => Some((_enum.name.as_str(), text)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect_vec();
+    let constants = strings.into_iter().filter_map(|(name, text)| {
+        if !const_cache.insert(name) {
+            return None;
+        }
+        let ident = Ident::from(constant_name(name).as_str());
+        let bytes = format!("{}\0", text);
+        let bytes = syn::LitByteStr::new(bytes.as_bytes(), proc_macro2::Span::call_site());
+        Some(quote! {
+            pub const #ident: &::std::ffi::CStr =
+                unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(#bytes) };
+        })
+    });
+    quote! {
+        #(#constants)*
+    }
+}
+/// Base types like `VkDeviceSize` and `VkDeviceAddress` are emitted as plain aliases of their
+/// integer type rather than newtypes. Sizes and addresses are offset and compared with other
+/// integers all the time, and `Bool32` relies on `From<bool> for u32`. Pointer typedefs, like
+/// `VkRemoteAddressNV`, take their type from `base_type` because vkxml drops the pointer.
+pub fn generate_typedef(typedef: &vkxml::Typedef, base_type: Option<&BaseType>) -> Tokens {
     let typedef_name = to_type_tokens(&typedef.name, None);
-    let typedef_ty = to_type_tokens(&typedef.basetype, None);
+    let typedef_ty = match base_type {
+        Some(BaseType {
+            ty: ty @ CType::Pointer { .. },
+            ..
+        }) => ty.to_tokens(),
+        _ => to_type_tokens(&typedef.basetype, None),
+    };
     let khronos_link = khronos_link(&typedef.name);
     quote! {
         #[doc = #khronos_link]
@@ -1199,38 +2879,40 @@ pub fn variant_ident(enum_name: &str, variant_name: &str) -> Ident {
     }
 }
 
+/// Returns the `cfg` attribute for items that are guarded by a platform define. Each platform
+/// define is exposed as a cargo feature with the same name.
+pub fn platform_cfg(protect: &str) -> Tokens {
+    quote! {
+        #[cfg(feature = #protect)]
+    }
+}
+
 pub fn bitflags_impl_block(
     ident: Ident,
     enum_name: &str,
     constants: &[&impl ConstantExt],
 ) -> Tokens {
-    let variants = constants
-        .iter()
-        .map(|constant| {
-            let variant_ident = constant.variant_ident(enum_name);
-            let tokens = constant.to_tokens(Some(ident));
-            (variant_ident, tokens)
-        })
-        .collect_vec();
-
-    let notations = constants.iter().map(|constant| {
-        constant.notation().map(|n| {
+    let variants = constants.iter().map(|constant| {
+        let variant_ident = constant.variant_ident(enum_name);
+        let value = constant.to_tokens(Some(ident));
+        let notation = constant.notation().map(|n| {
             quote! {
                 #[doc = #n]
             }
-        })
+        });
+        let deprecated = constant.deprecated().map(|note| {
+            quote! {
+                #[deprecated = #note]
+            }
+        });
+        let cfg = constant.protect().map(platform_cfg);
+        quote! {
+            #notation
+            #deprecated
+            #cfg
+            pub const #variant_ident: Self = #value;
+        }
     });
-
-    let variants =
-        variants
-            .iter()
-            .zip(notations.clone())
-            .map(|((variant_ident, value), ref notation)| {
-                quote! {
-                    #notation
-                    pub const #variant_ident: Self = #value;
-                }
-            });
     quote! {
         impl #ident {
             #(#variants)*
@@ -1238,6 +2920,15 @@ pub fn bitflags_impl_block(
     }
 }
 
+/// Returns the integer type that backs the newtype generated for `_enum`. Bitmasks, declared with
+/// `type="bitmask"`, are backed by `Flags`, every other enum by `i32`.
+pub fn enum_backing_type(_enum: &vkxml::Enumeration) -> Ident {
+    match _enum.purpose {
+        Some(vkxml::EnumerationPurpose::Bitmask) => Ident::from("Flags"),
+        None => Ident::from("i32"),
+    }
+}
+
 pub fn generate_enum<'a>(
     _enum: &'a vkxml::Enumeration,
     const_cache: &mut HashSet<&'a str, impl BuildHasher>,
@@ -1261,13 +2952,15 @@ pub fn generate_enum<'a>(
         values.push(ConstantMatchInfo {
             ident: constant.variant_ident(&_enum.name),
             is_alias: false,
+            protect: None,
         });
     }
     const_values.insert(ident, values);
 
     let khronos_link = khronos_link(&_enum.name);
+    let backing = enum_backing_type(_enum);
 
-    if name.contains("Bit") {
+    if let Some(vkxml::EnumerationPurpose::Bitmask) = _enum.purpose {
         let ident = Ident::from(_name.as_str());
         let all_bits = constants
             .iter()
@@ -1286,22 +2979,24 @@ pub fn generate_enum<'a>(
                 #[repr(transparent)]
                 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
                 #[doc = #khronos_link]
-                pub struct #ident(pub(crate) Flags);
-                vk_bitflags_wrapped!(#ident, #all_bits_term, Flags);
+                pub struct #ident(pub(crate) #backing);
+                vk_bitflags_wrapped!(#ident, #all_bits_term, #backing);
                 #impl_bitflags
             };
             EnumType::Bitflags(q)
         }
     } else {
         let impl_block = bitflags_impl_block(ident, &_enum.name, &constants);
+        // Vulkan enums are open, drivers may return values that are newer than the bindings. A
+        // newtype keeps any raw value, which a Rust enum couldn't represent.
         let enum_quote = quote! {
             #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
             #[repr(transparent)]
             #[doc = #khronos_link]
-            pub struct #ident(pub(crate) i32);
+            pub struct #ident(pub(crate) #backing);
             impl #ident {
-                pub const fn from_raw(x: i32) -> Self { #ident(x) }
-                pub const fn as_raw(self) -> i32 { self.0 }
+                pub const fn from_raw(x: #backing) -> Self { #ident(x) }
+                pub const fn as_raw(self) -> #backing { self.0 }
             }
             #impl_block
         };
@@ -1321,6 +3016,83 @@ pub fn generate_enum<'a>(
     }
 }
 
+/// Generates `Error`, which has a variant for every error code of `Result`, along with
+/// `VkResult` and `Result::result` to convert a raw result code into it. `values` are
+/// the variants of `Result` that were collected in `const_values`, extensions included.
+pub fn generate_result_error(values: &[ConstantMatchInfo]) -> Tokens {
+    // Every negative result code is named `VK_ERROR_*`, while success codes never are.
+    let errors: Vec<_> = values
+        .iter()
+        .filter(|value| !value.is_alias)
+        .filter_map(|value| {
+            let name = value.ident.as_ref().strip_prefix("ERROR_")?;
+            let cfg = value.protect.as_ref().map(|protect| platform_cfg(protect));
+            Some((cfg, value.ident, Ident::from(name)))
+        })
+        .collect();
+    let variants = errors
+        .iter()
+        .map(|(cfg, _, variant)| quote!(#cfg #variant,));
+    let into_result = errors
+        .iter()
+        .map(|(cfg, code, variant)| quote!(#cfg Error::#variant => Result::#code,));
+    let from_result = errors
+        .iter()
+        .map(|(cfg, code, variant)| quote!(#cfg Result::#code => Some(Error::#variant),));
+    quote! {
+        /// The error codes of [`Result`].
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Error {
+            #(#variants)*
+            /// A negative result code that is unknown to this version of the bindings.
+            Unknown(Result),
+        }
+        /// The outcome of a command that returns a [`Result`], negative codes are an [`Error`].
+        pub type VkResult<T> = ::std::result::Result<T, Error>;
+        impl From<Error> for Result {
+            fn from(err: Error) -> Result {
+                match err {
+                    #(#into_result)*
+                    Error::Unknown(code) => code,
+                }
+            }
+        }
+        impl Result {
+            /// Returns `Err` for negative result codes. Success codes such as `INCOMPLETE` or
+            /// `SUBOPTIMAL_KHR` are not errors and return `Ok`.
+            pub fn result(self) -> VkResult<()> {
+                self.result_with_success(())
+            }
+            /// Like `result`, but returns `value` on success.
+            pub fn result_with_success<T>(self, value: T) -> VkResult<T> {
+                match self.error() {
+                    Some(err) => Err(err),
+                    None => Ok(value),
+                }
+            }
+            /// Returns the `Error` of a negative result code.
+            pub fn error(self) -> Option<Error> {
+                match self {
+                    #(#from_result)*
+                    _ if self.0 < 0 => Some(Error::Unknown(self)),
+                    _ => None,
+                }
+            }
+        }
+        impl fmt::Debug for Error {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&Result::from(*self), f)
+            }
+        }
+        impl fmt::Display for Error {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&Result::from(*self), f)
+            }
+        }
+        impl ::std::error::Error for Error {}
+    }
+}
 pub fn generate_result(ident: Ident, _enum: &vkxml::Enumeration) -> Tokens {
     let notation = _enum.elements.iter().filter_map(|elem| {
         let (variant_name, notation) = match *elem {
@@ -1376,6 +3148,9 @@ fn is_static_array(field: &vkxml::Field) -> bool {
         })
         .unwrap_or(false)
 }
+fn is_next(field: &vkxml::Field) -> bool {
+    field.name.as_deref() == Some("pNext") && field.basetype == "void"
+}
 pub fn derive_default(_struct: &vkxml::Struct) -> Option<Tokens> {
     let name = name_to_tokens(&_struct.name);
     let members = _struct.elements.iter().filter_map(|elem| match *elem {
@@ -1411,6 +3186,16 @@ pub fn derive_default(_struct: &vkxml::Struct) -> Option<Tokens> {
                     #param_ident: unsafe { ::std::mem::zeroed() }
                 }
             }
+        } else if is_next(field) {
+            if next_is_mut(_struct) {
+                quote! {
+                    #param_ident: ::std::ptr::null_mut()
+                }
+            } else {
+                quote! {
+                    #param_ident: ::std::ptr::null()
+                }
+            }
         } else if let Some(ref reference) = field.reference {
             match reference {
                 vkxml::ReferenceType::Pointer => {
@@ -1481,9 +3266,7 @@ pub fn derive_debug(
             .map(|n| n.contains("pfn"))
             .unwrap_or(false)
     });
-    let contains_static_array = members
-        .clone()
-        .any(|x| is_static_array(x) && x.basetype == "char");
+    let contains_static_array = members.clone().any(|x| x.is_c_string_buffer());
     let contains_union = members
         .clone()
         .any(|field| union_types.contains(field.basetype.as_str()));
@@ -1493,7 +3276,7 @@ pub fn derive_debug(
     let debug_fields = members.clone().map(|field| {
         let param_ident = field.param_ident();
         let param_str = param_ident.as_ref();
-        let debug_value = if is_static_array(field) && field.basetype == "char" {
+        let debug_value = if field.is_c_string_buffer() {
             quote! {
                 &unsafe {
                     ::std::ffi::CStr::from_ptr(self.#param_ident.as_ptr() as *const c_char)
@@ -1529,7 +3312,8 @@ pub fn derive_debug(
 
 pub fn derive_setters(
     _struct: &vkxml::Struct,
-    root_struct_names: &HashSet<String, impl BuildHasher>,
+    root_structs: &HashMap<String, ChainDirection, impl BuildHasher>,
+    non_copy_types: &HashSet<&str, impl BuildHasher>,
 ) -> Option<Tokens> {
     if &_struct.name == "VkBaseInStructure"
         || &_struct.name == "VkBaseOutStructure"
@@ -1547,7 +3331,7 @@ pub fn derive_setters(
         _ => None,
     });
 
-    let (has_next, _is_next_const) = match members
+    let (has_next, is_next_const) = match members
         .clone()
         .find(|field| field.param_ident().as_ref() == "p_next")
     {
@@ -1610,7 +3394,7 @@ pub fn derive_setters(
         if param_ident_string.starts_with("pp_") {
             param_ident_short = &param_ident_string[3..];
         };
-        let param_ident_short = Term::intern(&param_ident_short);
+        let param_ident_short = Term::intern(&escape_keyword(param_ident_short));
 
         if let Some(name) = field.name.as_ref() {
             // Fiter
@@ -1641,7 +3425,7 @@ pub fn derive_setters(
 
         // TODO: Improve in future when https://github.com/rust-lang/rust/issues/53667 is merged id:6
         if param_ident_string.starts_with("p_") || param_ident_string.starts_with("pp_") {
-            if param_ty_string == "*const c_char" {
+            if field.is_c_string() {
                 return Some(quote!{
                         pub fn #param_ident_short(mut self, #param_ident_short: &'a ::std::ffi::CStr) -> #name_builder<'a> {
                             self.inner.#param_ident = #param_ident_short.as_ptr();
@@ -1650,10 +3434,36 @@ pub fn derive_setters(
                 });
             }
 
+            // The length is a formula of other members, which the builder can't derive from the
+            // slice. The caller has to set those members to match, hence `unsafe`.
+            if let Some(MemberLen::Expression(_)) | Some(MemberLen::Manual) = field.member_len() {
+                let (slice_ty, ptr) = match param_ty_string.strip_prefix("*const ") {
+                    Some(element) => (format!("&'a [{}]", element), ".as_ptr()"),
+                    None => (format!("&'a mut [{}]", &param_ty_string[5..]), ".as_mut_ptr()"),
+                };
+                let slice_ty = Term::intern(&slice_ty);
+                let ptr = Term::intern(ptr);
+                let len = field.size.as_deref().unwrap_or_default();
+                let doc = format!(
+                    "# Safety\n\nThe length of `{}` is `{}`, which the builder can't set from \
+                     the slice. The members that it depends on have to be set to match.",
+                    field.name.as_deref().unwrap_or_default(),
+                    field.c_size.as_deref().unwrap_or(len),
+                );
+                return Some(quote!{
+                        #[doc = #doc]
+                        pub unsafe fn #param_ident_short(mut self, #param_ident_short: #slice_ty) -> #name_builder<'a> {
+                            self.inner.#param_ident = #param_ident_short#ptr;
+                            self
+                        }
+                });
+            }
+
             if let Some(ref array_type) = field.array {
                 if let Some(ref array_size) = field.size {
                     if !array_size.starts_with("latexmath") {
-                        let array_size_ident = Ident::from(array_size.to_snake_case().as_str());
+                        let array_size_ident =
+                            Ident::from(escape_keyword(&array_size.to_snake_case()));
                         if param_ty_string == "*const *const c_char" {
                             return Some(quote!{
                                     pub fn #param_ident_short(mut self, #param_ident_short: &'a [*const c_char]) -> #name_builder<'a> {
@@ -1714,7 +3524,7 @@ pub fn derive_setters(
             }
         }
 
-        if param_ty_string == "Bool32" {
+        if field.is_bool32() {
             return Some(quote!{
                 pub fn #param_ident_short(mut self, #param_ident_short: bool) -> #name_builder<'a> {
                     self.inner.#param_ident = #param_ident_short.into();
@@ -1733,23 +3543,50 @@ pub fn derive_setters(
 
     let extends_name = name_to_tokens(&format!("Extends{}", name));
 
-    let root_structs: Vec<Ident> = _struct
+    // Structs that are only returned by the implementation can't be part of an input chain.
+    let direction = chain_direction(_struct);
+    let extended_structs: Vec<Ident> = _struct
         .extends
         .as_ref()
         .map(|extends| {
             extends
                 .split(',')
-                .filter(|extend| root_struct_names.contains(*extend))
+                .filter(|extend| match root_structs.get(*extend) {
+                    Some(ChainDirection::In) => direction != Some(ChainDirection::Out),
+                    Some(ChainDirection::Out) => true,
+                    None => false,
+                })
                 .map(|extends| name_to_tokens(&format!("Extends{}", name_to_tokens(&extends))))
                 .collect()
         })
         .unwrap_or_else(|| vec![]);
 
     // We only implement a next methods for root structs with a `pnext` field.
-    let next_function = if has_next && root_structs.is_empty() {
+    let next_function = if has_next && _struct.extends.is_none() && !is_next_const {
+        quote! {
+            /// Prepends the given extension struct between the root and the first pointer. This
+            /// method only exists on structs whose chain is written by the implementation. Only
+            /// valid extension structs can be pushed into the chain.
+            /// If the chain looks like `A -> B -> C`, and you call `builder.push_next_mut(&mut D)`, then the
+            /// chain will look like `A -> D -> B -> C`.
+            pub fn push_next_mut<T: #extends_name>(mut self, next: &'a mut T) -> #name_builder<'a> {
+                unsafe{
+                    let next_ptr = next as *mut T as *mut BaseOutStructure;
+                    let last_next = ptr_chain_iter(next).last().unwrap();
+                    (*last_next).p_next = self.inner.p_next as _;
+                    self.inner.p_next = next_ptr as _;
+                }
+                self
+            }
+            #[deprecated(note = "Chains that are written by the implementation use `push_next_mut`")]
+            pub fn push_next<T: #extends_name>(self, next: &'a mut T) -> #name_builder<'a> {
+                self.push_next_mut(next)
+            }
+        }
+    } else if has_next && _struct.extends.is_none() {
         quote! {
             /// Prepends the given extension struct between the root and the first pointer. This
-            /// method only exists on structs that can be passed to a function directly. Only
+            /// method only exists on structs whose chain is read by the implementation. Only
             /// valid extension structs can be pushed into the chain.
             /// If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the
             /// chain will look like `A -> D -> B -> C`.
@@ -1788,7 +3625,7 @@ pub fn derive_setters(
     };
 
     // If the struct extends something we need to implement the trait.
-    let impl_extend_trait = root_structs.iter().map(|extends| {
+    let impl_extend_trait = extended_structs.iter().map(|extends| {
         quote! {
             unsafe impl #extends for #name_builder<'_> {
             }
@@ -1797,6 +3634,131 @@ pub fn derive_setters(
         }
     });
 
+    // Returned-only structs are filled in by the implementation, so they only get getters.
+    let is_returned_only = _struct.is_returned_only();
+
+    // `Bool32` members can also be read and written as a `bool`, next to the raw field.
+    let bool_accessors = members
+        .clone()
+        .filter(|field| field.is_bool32())
+        .map(|field| {
+            let param_ident = field.param_ident();
+            let setter_ident = Ident::from(format!("set_{}", param_ident).as_str());
+            let setter = if is_returned_only {
+                quote! {}
+            } else {
+                quote! {
+                    pub fn #setter_ident(&mut self, #param_ident: bool) {
+                        self.#param_ident = #param_ident.into();
+                    }
+                }
+            };
+            quote! {
+                pub fn #param_ident(&self) -> bool {
+                    self.#param_ident != FALSE
+                }
+                #setter
+            }
+        });
+
+    // Fixed-size strings, like `deviceName`, can be set from a `CStr`. Names that don't fit are
+    // truncated, the array always stays null-terminated. The strings of returned-only structs are
+    // read as a `CStr` instead, which relies on the implementation having terminated them.
+    let string_accessors = members
+        .clone()
+        .filter(|field| field.is_c_string_buffer())
+        .map(|field| {
+            let param_ident = field.param_ident();
+            if is_returned_only {
+                let doc = format!(
+                    "# Safety\n\n`{}` has to be null-terminated, like it is after the \
+                     implementation filled it in.",
+                    field.name.as_deref().unwrap_or_default(),
+                );
+                return quote! {
+                    #[doc = #doc]
+                    pub unsafe fn #param_ident(&self) -> &::std::ffi::CStr {
+                        ::std::ffi::CStr::from_ptr(self.#param_ident.as_ptr())
+                    }
+                };
+            }
+            let setter_ident = Ident::from(format!("set_{}", param_ident).as_str());
+            quote! {
+                pub fn #setter_ident(&mut self, #param_ident: &::std::ffi::CStr) {
+                    let bytes = #param_ident.to_bytes();
+                    let len = bytes.len().min(self.#param_ident.len() - 1);
+                    for (i, c) in self.#param_ident.iter_mut().enumerate() {
+                        *c = if i < len { bytes[i] as c_char } else { 0 };
+                    }
+                }
+            }
+        });
+
+    // UUIDs and LUIDs are exposed as byte slices, which makes them easy to compare.
+    let is_id = |field: &vkxml::Field| {
+        let is_id_extent = match field.array_extent() {
+            Some(ArrayExtent::Constant(extent)) => {
+                extent == "VK_UUID_SIZE" || extent == "VK_LUID_SIZE"
+            }
+            _ => false,
+        };
+        field.basetype == "uint8_t" && field.reference.is_none() && is_id_extent
+    };
+    let id_accessors = members.clone().filter(|field| is_id(field)).map(|field| {
+        let param_ident = field.param_ident();
+        quote! {
+            pub fn #param_ident(&self) -> &[u8] {
+                &self.#param_ident
+            }
+        }
+    });
+
+    // The remaining members of returned-only structs are read by value, or by reference if they
+    // aren't `Copy`.
+    let value_getters = members
+        .clone()
+        .filter(|field| {
+            let param_ident = field.param_ident();
+            is_returned_only
+                && param_ident.as_ref() != "s_type"
+                && param_ident.as_ref() != "p_next"
+                && !field.is_bool32()
+                && !field.is_c_string_buffer()
+                && !is_id(field)
+        })
+        .map(|field| {
+            let param_ident = field.param_ident();
+            let param_ty_tokens = field.type_tokens(false);
+            if field.is_copy(non_copy_types) {
+                quote! {
+                    pub fn #param_ident(&self) -> #param_ty_tokens {
+                        self.#param_ident
+                    }
+                }
+            } else {
+                quote! {
+                    pub fn #param_ident(&self) -> &#param_ty_tokens {
+                        &self.#param_ident
+                    }
+                }
+            }
+        });
+
+    // Returned-only structs are never built by the application, unless they start or extend a
+    // chain that the implementation writes to. Those keep a builder for `push_next_mut`.
+    if _struct.is_returned_only() && !has_next && _struct.extends.is_none() {
+        return Some(quote! {
+            impl #name {
+                #(#bool_accessors)*
+                #(#string_accessors)*
+                #(#id_accessors)*
+                #(#value_getters)*
+            }
+        });
+    }
+
+    let setters = setters.filter(|_| !is_returned_only);
+
     let q = quote! {
         impl #name {
             pub fn builder<'a>() -> #name_builder<'a> {
@@ -1805,6 +3767,10 @@ pub fn derive_setters(
                     marker: ::std::marker::PhantomData,
                 }
             }
+            #(#bool_accessors)*
+            #(#string_accessors)*
+            #(#id_accessors)*
+            #(#value_getters)*
         }
 
         #[repr(transparent)]
@@ -1848,10 +3814,66 @@ pub fn derive_setters(
     Some(q)
 }
 
-/// At the moment `Ash` doesn't properly derive all the necessary drives
-/// like Eq, Hash etc.
-/// To Address some cases, you can add the name of the struct that you
-/// require and add the missing derives yourself.
+/// Generates a `const fn new` for small value structs like `Extent2D` or `Offset3D`, that don't
+/// contain a structure type or any pointers. Larger structs, like `PhysicalDeviceLimits`, would
+/// need too many positional arguments to be readable and are left to their builder.
+pub fn derive_constructor(_struct: &vkxml::Struct) -> Option<Tokens> {
+    const MAX_MEMBERS: usize = 4;
+    let name = name_to_tokens(&_struct.name);
+    let members = _struct.elements.iter().filter_map(|elem| match *elem {
+        vkxml::StructElement::Member(ref field) => Some(field),
+        _ => None,
+    });
+    let is_value_struct = members.clone().count() <= MAX_MEMBERS
+        && members
+            .clone()
+            .all(|field| field.basetype != "VkStructureType" && field.reference.is_none());
+    if !is_value_struct {
+        return None;
+    }
+    let params = members.clone().map(|field| {
+        let param_ident = field.param_ident();
+        let param_ty_tokens = field.type_tokens(false);
+        quote! {#param_ident: #param_ty_tokens}
+    });
+    let field_idents = members.map(|field| field.param_ident());
+    Some(quote! {
+        impl #name {
+            pub const fn new(#(#params),*) -> #name {
+                #name {
+                    #(#field_idents),*
+                }
+            }
+        }
+    })
+}
+
+/// Implements `TaggedStructure` for structs whose `sType` member has a fixed value.
+pub fn derive_tagged_structure(_struct: &vkxml::Struct) -> Option<Tokens> {
+    let name = name_to_tokens(&_struct.name);
+    let variant = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(field) => Some(field),
+            _ => None,
+        })
+        .find(|field| field.basetype == "VkStructureType")?
+        .type_enums
+        .as_ref()?
+        .split(',')
+        .next()?;
+    let variant_ident = variant_ident("VkStructureType", variant);
+    Some(quote! {
+        impl TaggedStructure for #name {
+            const STRUCTURE_TYPE: StructureType = StructureType::#variant_ident;
+        }
+    })
+}
+/// At the moment `Ash` doesn't properly derive all the necessary drives
+/// like Eq, Hash etc.
+/// To Address some cases, you can add the name of the struct that you
+/// require and add the missing derives yourself.
 pub fn manual_derives(_struct: &vkxml::Struct) -> Tokens {
     match _struct.name.as_str() {
         "VkClearRect" | "VkExtent2D" | "VkExtent3D" | "VkOffset2D" | "VkOffset3D" | "VkRect2D"
@@ -1859,16 +3881,31 @@ pub fn manual_derives(_struct: &vkxml::Struct) -> Tokens {
         _ => quote! {},
     }
 }
+/// Maps the API constants that can be used as an array extent to their value.
+pub fn array_sizes<'a>(constants: &[&'a vkxml::Constant]) -> HashMap<&'a str, u64> {
+    constants
+        .iter()
+        .filter_map(|constant| match Constant::from_constant(constant).value() {
+            Some(ConstVal::U64(n)) => Some((constant.name.as_str(), n)),
+            _ => None,
+        })
+        .collect()
+}
 pub fn generate_struct(
     _struct: &vkxml::Struct,
-    root_struct_names: &HashSet<String, impl BuildHasher>,
+    root_structs: &HashMap<String, ChainDirection, impl BuildHasher>,
     union_types: &HashSet<&str, impl BuildHasher>,
+    array_sizes: &HashMap<&str, u64, impl BuildHasher>,
+    non_copy_types: &HashSet<&str, impl BuildHasher>,
+    aliases: &[&str],
 ) -> Tokens {
     let name = name_to_tokens(&_struct.name);
+    let alias_docs = alias_docs(aliases);
     if &_struct.name == "VkTransformMatrixKHR" {
         return quote! {
             #[repr(C)]
             #[derive(Copy, Clone)]
+            #alias_docs
             pub struct TransformMatrixKHR {
                 pub matrix: [f32; 12],
             }
@@ -1879,6 +3916,7 @@ pub fn generate_struct(
         return quote! {
             #[repr(C)]
             #[derive(Copy, Clone)]
+            #alias_docs
             pub struct AccelerationStructureInstanceKHR {
                 pub transform: TransformMatrixKHR,
                 pub instance_custom_index_and_mask: u32,
@@ -1895,13 +3933,44 @@ pub fn generate_struct(
 
     let params = members.clone().map(|field| {
         let param_ident = field.param_ident();
-        let param_ty_tokens = field.type_tokens(false);
-        quote! {pub #param_ident: #param_ty_tokens}
+        // Inline `<comment>`s of members are kept as their docs.
+        let comment = field
+            .notation
+            .as_ref()
+            .map(|comment| comment.trim())
+            .map(|comment| quote!(#[doc = #comment]));
+        // Named extents are written out as a literal, the constant is kept in the docs.
+        let resolved = field.array_extent().and_then(|extent| match extent {
+            ArrayExtent::Literal(_) => None,
+            _ => extent.resolve(array_sizes).map(|len| (extent, len)),
+        });
+        match resolved {
+            Some((extent, len)) => {
+                let ty = name_to_tokens(&field.basetype);
+                let len = Term::intern(&len.to_string());
+                let doc = format!("Length of `{}`", quote!(#extent));
+                quote! {
+                    #comment
+                    #[doc = #doc]
+                    pub #param_ident: [#ty; #len]
+                }
+            }
+            None if is_next(field) => {
+                let pointer = vkxml::ReferenceType::Pointer.to_tokens(!next_is_mut(_struct));
+                quote! {#comment pub #param_ident: #pointer c_void}
+            }
+            None => {
+                let param_ty_tokens = field.type_tokens(false);
+                quote! {#comment pub #param_ident: #param_ty_tokens}
+            }
+        }
     });
 
     let debug_tokens = derive_debug(_struct, union_types);
     let default_tokens = derive_default(_struct);
-    let setter_tokens = derive_setters(_struct, root_struct_names);
+    let setter_tokens = derive_setters(_struct, root_structs, non_copy_types);
+    let constructor_tokens = derive_constructor(_struct);
+    let tagged_tokens = derive_tagged_structure(_struct);
     let manual_derive_tokens = manual_derives(_struct);
     let dbg_str = if debug_tokens.is_none() {
         quote!(Debug,)
@@ -1913,38 +3982,87 @@ pub fn generate_struct(
     } else {
         quote!()
     };
+    // Structs are plain old data, so they are `Copy` as long as all of their members are.
+    let copy_str = if members.clone().all(|field| field.is_copy(non_copy_types)) {
+        quote!(Copy,)
+    } else {
+        quote!()
+    };
     let khronos_link = khronos_link(&_struct.name);
     quote! {
         #[repr(C)]
-        #[derive(Copy, Clone, #default_str #dbg_str #manual_derive_tokens)]
+        #[derive(#copy_str Clone, #default_str #dbg_str #manual_derive_tokens)]
         #[doc = #khronos_link]
+        #alias_docs
         pub struct #name {
             #(#params,)*
         }
         #debug_tokens
         #default_tokens
         #setter_tokens
+        #constructor_tokens
+        #tagged_tokens
     }
 }
 
+/// Returns the `ObjectType` variant of a handle, like `BUFFER` for `VkBuffer`.
+pub fn object_type_variant(handle_name: &str) -> Ident {
+    Ident::from(handle_name[2..].to_shouty_snake_case())
+}
+/// A handle of the registry, along with what handle codegen and documentation need to know
+/// about it.
+#[derive(Debug, Clone)]
+pub struct HandleItem<'a> {
+    pub name: &'a str,
+    /// Dispatchable handles are pointers to an object that carries a dispatch table, like
+    /// `VkDevice`. Every other handle is an opaque 64-bit value.
+    pub is_dispatchable: bool,
+    /// The `ObjectType` variant of the handle.
+    pub object_type: Ident,
+    /// The handles that this handle is created from. Some handles, like `VkSwapchainKHR`, can be
+    /// created from more than one.
+    pub parents: Vec<&'a str>,
+}
+/// Returns every handle that is declared in `definitions`.
+pub fn handle_items<'a>(
+    definitions: &'a [&'a vkxml::DefinitionsElement],
+) -> impl Iterator<Item = HandleItem<'a>> + 'a {
+    definitions
+        .iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Handle(ref handle) if !handle.name.is_empty() => {
+                Some(handle)
+            }
+            _ => None,
+        })
+        .map(|handle| HandleItem {
+            name: &handle.name,
+            is_dispatchable: match handle.ty {
+                vkxml::HandleType::Dispatch => true,
+                vkxml::HandleType::NoDispatch => false,
+            },
+            object_type: object_type_variant(&handle.name),
+            parents: handle
+                .parent
+                .as_ref()
+                .map(|parents| parents.split(',').collect())
+                .unwrap_or_default(),
+        })
+}
 pub fn generate_handle(handle: &vkxml::Handle) -> Option<Tokens> {
     if handle.name == "" {
         return None;
     }
     let khronos_link = khronos_link(&handle.name);
+    let ty = object_type_variant(&handle.name);
+    let name = Ident::from(&handle.name[2..]);
     let tokens = match handle.ty {
         vkxml::HandleType::Dispatch => {
-            let name = &handle.name[2..];
-            let ty = Ident::from(name.to_shouty_snake_case());
-            let name = Ident::from(name);
             quote! {
                 define_handle!(#name, #ty, doc = #khronos_link);
             }
         }
         vkxml::HandleType::NoDispatch => {
-            let name = &handle.name[2..];
-            let ty = Ident::from(name.to_shouty_snake_case());
-            let name = Ident::from(name);
             quote! {
                 handle_nondispatchable!(#name, #ty, doc = #khronos_link);
             }
@@ -1952,7 +4070,90 @@ pub fn generate_handle(handle: &vkxml::Handle) -> Option<Tokens> {
     };
     Some(tokens)
 }
-fn generate_funcptr(fnptr: &vkxml::FunctionPointer) -> Tokens {
+/// Generates `object_type_of`, which looks up the `ObjectType` of a handle by its registry name.
+/// The typed handles expose the same relationship through `Handle::TYPE`.
+pub fn generate_object_type_table(handles: &[&vkxml::Handle]) -> Tokens {
+    let arms = handles
+        .iter()
+        .filter(|handle| !handle.name.is_empty())
+        .map(|handle| {
+            let name = handle.name.as_str();
+            let ty = object_type_variant(name);
+            quote! {
+                #name => Some(ObjectType::#ty),
+            }
+        });
+    quote! {
+        /// Returns the object type of a handle by its registry name, like `"VkBuffer"`.
+        pub fn object_type_of(handle_name: &str) -> Option<ObjectType> {
+            match handle_name {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    }
+}
+/// Maps each function pointer to the type that it `requires`, like the callback data struct of a
+/// messenger callback. vkxml drops this attribute.
+pub fn funcpointer_requires(registry: &vk_parse::Registry) -> HashMap<&str, &str> {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref types) => Some(types.children.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|child| match child {
+            vk_parse::TypesChild::Type(ty) if ty.category.as_deref() == Some("funcpointer") => {
+                let name = match ty.spec {
+                    vk_parse::TypeSpec::Code(ref code) => {
+                        code.markup.iter().find_map(|markup| match markup {
+                            vk_parse::TypeCodeMarkup::Name(name) => Some(name.as_str()),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                };
+                Some((name.or(ty.name.as_deref())?, ty.requires.as_deref()?))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Maps the function pointers of `requires` that are only usable on one platform to the platform
+/// define that guards them. That is the case if the function pointer, or the type it requires, is
+/// added by a platform specific extension.
+pub fn funcpointer_protects<'a>(
+    requires: &HashMap<&'a str, &'a str>,
+    extensions: &'a [vk_parse::Extension],
+    platforms: &'a [vk_parse::Platform],
+) -> HashMap<&'a str, &'a str> {
+    let type_protects: HashMap<&str, &str> = extensions
+        .iter()
+        .filter(|extension| extension.is_supported())
+        .filter_map(|extension| {
+            let protect = extension.platform_protect(platforms)?;
+            let types = extension.provided_items().types;
+            Some(types.into_iter().map(move |ty| (ty, protect)))
+        })
+        .flatten()
+        .collect();
+    requires
+        .iter()
+        .filter_map(|(&name, &required)| {
+            let protect = type_protects
+                .get(name)
+                .or_else(|| type_protects.get(required))?;
+            Some((name, *protect))
+        })
+        .collect()
+}
+
+/// Generates the `Option` of a function pointer type. Function pointers that are guarded by a
+/// platform `protect` are only available if that platform's feature is enabled.
+pub fn generate_funcptr(fnptr: &vkxml::FunctionPointer, protect: Option<&str>) -> Tokens {
     let name = Ident::from(fnptr.name.as_str());
     let ret_ty_tokens = fnptr.return_type.type_tokens(true);
     let params = fnptr.param.iter().map(|field| {
@@ -1963,15 +4164,18 @@ fn generate_funcptr(fnptr: &vkxml::FunctionPointer) -> Tokens {
         }
     });
     let khronos_link = khronos_link(&fnptr.name);
+    let cfg = protect.map(platform_cfg);
     quote! {
+        #cfg
         #[allow(non_camel_case_types)]
         #[doc = #khronos_link]
         pub type #name = Option<unsafe extern "system" fn(#(#params),*) -> #ret_ty_tokens>;
     }
 }
 
-fn generate_union(union: &vkxml::Union) -> Tokens {
+fn generate_union(union: &vkxml::Union, aliases: &[&str]) -> Tokens {
     let name = to_type_tokens(&union.name, None);
+    let alias_docs = alias_docs(aliases);
     let fields = union.elements.iter().map(|field| {
         let name = field.param_ident();
         let ty = field.type_tokens(false);
@@ -1984,6 +4188,7 @@ fn generate_union(union: &vkxml::Union) -> Tokens {
         #[repr(C)]
         #[derive(Copy, Clone)]
         #[doc = #khronos_link]
+        #alias_docs
         pub union #name {
             #(#fields),*
         }
@@ -1994,14 +4199,15 @@ fn generate_union(union: &vkxml::Union) -> Tokens {
         }
     }
 }
-pub fn root_struct_names(definitions: &[&vkxml::DefinitionsElement]) -> HashSet<String> {
+/// Returns the structs that start a `p_next` chain, along with the direction of that chain.
+pub fn root_structs(definitions: &[&vkxml::DefinitionsElement]) -> HashMap<String, ChainDirection> {
     definitions
         .iter()
         .filter_map(|definition| match *definition {
             vkxml::DefinitionsElement::Struct(ref _struct) => {
                 let is_root_struct = _struct.extends.is_none();
                 if is_root_struct {
-                    Some(_struct.name.clone())
+                    Some((_struct.name.clone(), next_direction(_struct)))
                 } else {
                     None
                 }
@@ -2010,258 +4216,1753 @@ pub fn root_struct_names(definitions: &[&vkxml::DefinitionsElement]) -> HashSet<
         })
         .collect()
 }
-pub fn generate_definition(
-    definition: &vkxml::DefinitionsElement,
-    union_types: &HashSet<&str, impl BuildHasher>,
-    root_structs: &HashSet<String, impl BuildHasher>,
-    bitflags_cache: &mut HashSet<Ident, impl BuildHasher>,
-    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
-) -> Option<Tokens> {
-    match *definition {
-        vkxml::DefinitionsElement::Typedef(ref typedef) => Some(generate_typedef(typedef)),
-        vkxml::DefinitionsElement::Struct(ref _struct) => {
-            Some(generate_struct(_struct, root_structs, union_types))
+/// A `const void* pNext` is only read by the implementation, a `void* pNext` is written to.
+fn next_direction(_struct: &vkxml::Struct) -> ChainDirection {
+    let p_next = _struct.elements.iter().find_map(|elem| match *elem {
+        vkxml::StructElement::Member(ref field) if field.name.as_deref() == Some("pNext") => {
+            Some(field)
         }
-        vkxml::DefinitionsElement::Bitmask(ref mask) => {
-            generate_bitmask(mask, bitflags_cache, const_values)
-        }
-        vkxml::DefinitionsElement::Handle(ref handle) => generate_handle(handle),
-        vkxml::DefinitionsElement::FuncPtr(ref fp) => Some(generate_funcptr(fp)),
-        vkxml::DefinitionsElement::Union(ref union) => Some(generate_union(union)),
         _ => None,
+    });
+    match p_next {
+        Some(field) if !field.is_const => ChainDirection::Out,
+        _ => ChainDirection::In,
     }
 }
-pub fn generate_feature<'a>(
-    feature: &vkxml::Feature,
-    commands: &CommandMap<'a>,
-    fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
-) -> quote::Tokens {
-    let (static_commands, entry_commands, device_commands, instance_commands) = feature
+/// Returns true if `p_next` is `*mut c_void` rather than `*const c_void`. The implementation
+/// writes through it for `returnedonly` structs, even if the registry declares it `const`.
+fn next_is_mut(_struct: &vkxml::Struct) -> bool {
+    _struct.is_return || next_direction(_struct) == ChainDirection::Out
+}
+/// The direction of the `p_next` chain that a struct can be pushed into.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChainDirection {
+    /// Passed to the implementation, like `VkBaseInStructure`.
+    In,
+    /// Written by the implementation, like `VkBaseOutStructure`.
+    Out,
+}
+/// Returns the chain direction of a struct that extends another struct, `returnedonly` structs
+/// are only ever filled in by the implementation.
+pub fn chain_direction(_struct: &vkxml::Struct) -> Option<ChainDirection> {
+    _struct.extends.as_ref()?;
+    if _struct.is_return {
+        Some(ChainDirection::Out)
+    } else {
+        Some(ChainDirection::In)
+    }
+}
+/// The chains that an extending struct may be part of, see [`chain_validity`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChainValidity {
+    Input,
+    Output,
+    Both,
+}
+/// Returns whether `_struct` may extend `root` in chains that are passed to the implementation,
+/// written by it, or both. Roots like `VkPhysicalDeviceFeatures2` have a mutable `p_next`, but
+/// are not `returnedonly` and can be passed to the implementation as well.
+pub fn chain_validity(_struct: &vkxml::Struct, root: &vkxml::Struct) -> Option<ChainValidity> {
+    let extends_root = _struct
+        .extends
+        .as_ref()?
+        .split(',')
+        .any(|extends| extends == root.name);
+    if !extends_root {
+        return None;
+    }
+    let is_output = next_direction(root) == ChainDirection::Out;
+    let is_input = !is_output || !root.is_return;
+    match (is_input && !_struct.is_return, is_output) {
+        (true, true) => Some(ChainValidity::Both),
+        (true, false) => Some(ChainValidity::Input),
+        (false, true) => Some(ChainValidity::Output),
+        (false, false) => None,
+    }
+}
+/// Returns the members of `_struct` whose `len` refers to a member that doesn't exist, together
+/// with that `len`. Literal lengths, `null-terminated` and lengths given as a `latexmath` formula
+/// are not checked, see [`MemberLen`]. A reported member points to a bug in the registry or its parser.
+pub fn unknown_member_lens(_struct: &vkxml::Struct) -> Vec<(&str, &str)> {
+    let members: Vec<_> = _struct
         .elements
         .iter()
-        .flat_map(|feature| {
-            if let vkxml::FeatureElement::Require(ref spec) = feature {
-                spec.elements
-                    .iter()
-                    .filter_map(|feature_spec| {
-                        if let vkxml::FeatureReference::CommandReference(ref cmd_ref) = feature_spec
-                        {
-                            Some(cmd_ref)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(field) => Some(field),
+            _ => None,
+        })
+        .collect();
+    let is_member = |name: &str| {
+        members
+            .iter()
+            .any(|field| field.name.as_deref() == Some(name))
+    };
+    members
+        .iter()
+        .filter_map(|field| match field.member_len()? {
+            MemberLen::Member(len) => Some((field.name.as_deref()?, len)),
+            _ => None,
+        })
+        .filter(|(_, len)| {
+            len.split(',').any(|part| {
+                // `pInfo->count` refers to a member of the member `pInfo`
+                let name = part.split("->").next().unwrap_or_default().trim();
+                name != "null-terminated" && name.parse::<u64>().is_err() && !is_member(name)
+            })
+        })
+        .collect()
+}
+/// Describes how a struct is used throughout the API.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StructUsage<'a> {
+    /// Commands that take the struct as an input parameter.
+    pub consumed_by: Vec<&'a str>,
+    /// Commands that write the struct through a mutable pointer parameter.
+    pub produced_by: Vec<&'a str>,
+    /// Structs that contain the struct as a member or accept it in their `p_next` chain.
+    pub embedded_in: Vec<&'a str>,
+}
+
+pub fn struct_usage<'a>(
+    name: &str,
+    commands: &CommandMap<'a>,
+    definitions: &[&'a vkxml::DefinitionsElement],
+) -> StructUsage<'a> {
+    let mut usage = StructUsage::default();
+    for cmd in commands.values() {
+        let params = cmd.param.iter().filter(|param| param.basetype == name);
+        for param in params {
+            let is_output = param.reference.is_some() && !param.is_const;
+            if is_output {
+                usage.produced_by.push(cmd.name.as_str());
             } else {
-                vec![]
+                usage.consumed_by.push(cmd.name.as_str());
             }
-        })
-        .filter_map(|cmd_ref| commands.get(&cmd_ref.name))
-        .fold(
-            (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
-            |mut acc, &cmd_ref| {
-                match cmd_ref.function_type() {
-                    FunctionType::Static => {
-                        acc.0.push(cmd_ref);
-                    }
-                    FunctionType::Entry => {
-                        acc.1.push(cmd_ref);
-                    }
-                    FunctionType::Device => {
-                        acc.2.push(cmd_ref);
-                    }
-                    FunctionType::Instance => {
-                        acc.3.push(cmd_ref);
-                    }
+        }
+    }
+    for &definition in definitions {
+        if let vkxml::DefinitionsElement::Struct(ref _struct) = definition {
+            let contains_member = _struct.elements.iter().any(|elem| match elem {
+                vkxml::StructElement::Member(ref field) => field.basetype == name,
+                _ => false,
+            });
+            if contains_member {
+                usage.embedded_in.push(_struct.name.as_str());
+            }
+            if _struct.name == name {
+                if let Some(ref extends) = _struct.extends {
+                    usage.embedded_in.extend(extends.split(','));
                 }
-                acc
-            },
-        );
-    let version = feature.version_string();
-    let static_fn = if feature.is_version(1, 0) {
-        generate_function_pointers(
-            Ident::from("StaticFn"),
-            &static_commands,
-            &HashMap::new(),
-            fn_cache,
-        )
-    } else {
-        quote! {}
-    };
-    let entry = generate_function_pointers(
-        Ident::from(format!("EntryFnV{}", version).as_str()),
-        &entry_commands,
-        &HashMap::new(),
-        fn_cache,
-    );
-    let instance = generate_function_pointers(
-        Ident::from(format!("InstanceFnV{}", version).as_str()),
-        &instance_commands,
-        &HashMap::new(),
-        fn_cache,
-    );
-    let device = generate_function_pointers(
-        Ident::from(format!("DeviceFnV{}", version).as_str()),
-        &device_commands,
-        &HashMap::new(),
-        fn_cache,
-    );
-    quote! {
-        #static_fn
-        #entry
-        #instance
-        #device
+            }
+        }
     }
+    usage.consumed_by.sort();
+    usage.consumed_by.dedup();
+    usage.produced_by.sort();
+    usage.produced_by.dedup();
+    usage.embedded_in.sort();
+    usage.embedded_in.dedup();
+    usage
 }
-pub fn constant_name(name: &str) -> String {
-    name.replace("VK_", "")
+/// How a type refers to one of its dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dependency {
+    /// The dependency is stored by value, as a member or as the base type of a typedef.
+    Embeds,
+    /// The dependency is only referenced through a pointer.
+    PointsTo,
 }
 
-pub fn generate_constant<'a>(
-    constant: &'a vkxml::Constant,
-    cache: &mut HashSet<&'a str, impl BuildHasher>,
-) -> Tokens {
-    cache.insert(constant.name.as_str());
-    let c = Constant::from_constant(constant);
-    let name = constant_name(&constant.name);
-    let ident = Ident::from(name.as_str());
-    let value = c.to_tokens(None);
-    let ty = if name == "TRUE" || name == "FALSE" {
-        CType::Bool32
-    } else {
-        c.ty()
-    };
-    let ty = ty.to_tokens();
-    quote! {
-        pub const #ident: #ty = #value;
+/// A directed graph of the definitions in the registry, with an edge from each type to the other
+/// definitions that it uses.
+#[derive(Debug, Default)]
+pub struct TypeDependencyGraph<'a> {
+    /// Every definition, in registry order.
+    nodes: Vec<&'a str>,
+    edges: HashMap<&'a str, Vec<(&'a str, Dependency)>>,
+}
+impl<'a> TypeDependencyGraph<'a> {
+    pub fn new(definitions: &[&'a vkxml::DefinitionsElement]) -> Self {
+        let mut graph = TypeDependencyGraph::default();
+        let mut uses = Vec::new();
+        for &definition in definitions {
+            let (name, deps): (&str, Vec<_>) = match definition {
+                vkxml::DefinitionsElement::Struct(_struct) => (
+                    &_struct.name,
+                    _struct
+                        .elements
+                        .iter()
+                        .filter_map(|elem| match elem {
+                            vkxml::StructElement::Member(field) => Some(field_dependency(field)),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                vkxml::DefinitionsElement::Union(union) => (
+                    &union.name,
+                    union.elements.iter().map(field_dependency).collect(),
+                ),
+                vkxml::DefinitionsElement::Typedef(typedef) => (
+                    &typedef.name,
+                    vec![(typedef.basetype.as_str(), Dependency::Embeds)],
+                ),
+                vkxml::DefinitionsElement::Bitmask(bitmask) => (
+                    &bitmask.name,
+                    vec![(bitmask.basetype.as_str(), Dependency::Embeds)],
+                ),
+                vkxml::DefinitionsElement::Handle(handle) if !handle.name.is_empty() => {
+                    (&handle.name, Vec::new())
+                }
+                vkxml::DefinitionsElement::FuncPtr(fnptr) => (
+                    &fnptr.name,
+                    fnptr
+                        .param
+                        .iter()
+                        .chain(Some(&fnptr.return_type))
+                        .map(field_dependency)
+                        .collect(),
+                ),
+                _ => continue,
+            };
+            graph.nodes.push(name);
+            uses.push((name, deps));
+        }
+        // Only keep edges between definitions, C types like `uint32_t` don't need ordering.
+        let nodes: HashSet<_> = graph.nodes.iter().cloned().collect();
+        for (name, mut deps) in uses {
+            deps.retain(|(dep, _)| nodes.contains(dep) && *dep != name);
+            deps.dedup();
+            graph.edges.insert(name, deps);
+        }
+        graph
+    }
+
+    /// Returns the definitions that `name` uses directly.
+    pub fn dependencies(&self, name: &str) -> &[(&'a str, Dependency)] {
+        self.edges.get(name).map_or(&[], |deps| &deps[..])
+    }
+
+    /// Returns every definition after the definitions that it embeds, and in registry order
+    /// otherwise. Pointers don't constrain the order, so types may point to each other.
+    pub fn topological_order(&self) -> Vec<&'a str> {
+        fn visit<'a>(
+            graph: &TypeDependencyGraph<'a>,
+            name: &'a str,
+            visited: &mut HashSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(name) {
+                return;
+            }
+            for &(dep, kind) in graph.dependencies(name) {
+                if kind == Dependency::Embeds {
+                    visit(graph, dep, visited, order);
+                }
+            }
+            order.push(name);
+        }
+        let mut visited = HashSet::new();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        for &name in &self.nodes {
+            visit(self, name, &mut visited, &mut order);
+        }
+        order
+    }
+}
+/// The size and alignment of a type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub size: usize,
+    pub align: usize,
+}
+impl Layout {
+    pub fn new(size: usize, align: usize) -> Layout {
+        Layout { size, align }
     }
 }
 
-pub fn generate_feature_extension<'a>(
-    registry: &'a vk_parse::Registry,
-    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
-    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
-) -> Tokens {
-    let constants = registry.0.iter().filter_map(|item| match item {
-        vk_parse::RegistryChild::Feature(feature) => Some(generate_extension_constants(
-            &feature.name,
-            0,
-            &feature.children,
-            const_cache,
-            const_values,
+/// A C ABI to compute layouts for. The targets Vulkan supports differ in the width of pointers, and
+/// in the alignment of 8 byte scalars like `uint64_t` and `double` inside of structs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Bits64,
+    /// 32 bit targets that align 8 byte scalars to 8 bytes, like ARM and x86 Windows.
+    Bits32,
+    /// 32 bit x86 outside of Windows, whose System V ABI aligns 8 byte scalars to 4 bytes.
+    X86SysV,
+}
+impl Target {
+    /// Returns the target for a pointer width in bits. 32 bit pointers give `Bits32`, the layouts
+    /// of `X86SysV` only differ in the alignment of 8 byte scalars.
+    pub fn from_pointer_width(pointer_width: u32) -> Option<Target> {
+        match pointer_width {
+            64 => Some(Target::Bits64),
+            32 => Some(Target::Bits32),
+            _ => None,
+        }
+    }
+
+    /// The width of pointers in bits.
+    pub fn pointer_width(self) -> u32 {
+        match self {
+            Target::Bits64 => 64,
+            Target::Bits32 | Target::X86SysV => 32,
+        }
+    }
+
+    fn max_scalar_align(self) -> usize {
+        match self {
+            Target::Bits64 | Target::Bits32 => 8,
+            Target::X86SysV => 4,
+        }
+    }
+
+    /// The `cfg` predicate that matches the target.
+    pub fn cfg_predicate(self) -> Tokens {
+        match self {
+            Target::Bits64 => quote!(target_pointer_width = "64"),
+            Target::Bits32 => quote!(all(
+                target_pointer_width = "32",
+                not(all(target_arch = "x86", not(windows)))
+            )),
+            Target::X86SysV => quote!(all(target_arch = "x86", not(windows))),
+        }
+    }
+}
+
+/// Computes the C layout of registry types for a `Target`.
+pub struct TypeLayouts<'a> {
+    definitions: HashMap<&'a str, &'a vkxml::DefinitionsElement>,
+    array_sizes: HashMap<&'a str, u64>,
+    target: Target,
+}
+impl<'a> TypeLayouts<'a> {
+    pub fn new(
+        definitions: &[&'a vkxml::DefinitionsElement],
+        array_sizes: HashMap<&'a str, u64>,
+        target: Target,
+    ) -> Self {
+        let definitions = definitions
+            .iter()
+            .filter_map(|&def| {
+                let name = match def {
+                    vkxml::DefinitionsElement::Enumeration(_enum) => &_enum.name,
+                    _ => definition_name(def)?,
+                };
+                Some((name, def))
+            })
+            .collect();
+        TypeLayouts {
+            definitions,
+            array_sizes,
+            target,
+        }
+    }
+
+    /// Returns the layout of a registry or C type, or `None` if it is opaque, like a platform
+    /// type, or refers to one.
+    pub fn layout(&self, type_name: &str) -> Option<Layout> {
+        let width = self.target.pointer_width() as usize / 8;
+        let pointer = Layout::new(width, width);
+        let primitive =
+            |size: usize| Some(Layout::new(size, size.min(self.target.max_scalar_align())));
+        if let Some(c_primitive) = CPrimitive::parse(type_name) {
+            return primitive(c_primitive.size());
+        }
+        match type_name {
+            "char" => return primitive(1),
+            "int" => return primitive(4),
+            "size_t" => return Some(pointer),
+            _ => (),
+        }
+        match self.definitions.get(type_name)? {
+            vkxml::DefinitionsElement::Typedef(typedef) => self.layout(&typedef.basetype),
+            vkxml::DefinitionsElement::Bitmask(bitmask) => self.layout(&bitmask.basetype),
+            vkxml::DefinitionsElement::Enumeration(_) => primitive(4),
+            vkxml::DefinitionsElement::Handle(handle) => match handle.ty {
+                vkxml::HandleType::Dispatch => Some(pointer),
+                // Non-dispatchable handles are 64 bit on every target.
+                vkxml::HandleType::NoDispatch => primitive(8),
+            },
+            vkxml::DefinitionsElement::FuncPtr(_) => Some(pointer),
+            vkxml::DefinitionsElement::Struct(_struct) => self.struct_offsets(_struct).1,
+            vkxml::DefinitionsElement::Union(union) => {
+                let mut layout = Layout::new(0, 1);
+                for field in &union.elements {
+                    let member = self.field_layout(field)?;
+                    layout.size = layout.size.max(member.size);
+                    layout.align = layout.align.max(member.align);
+                }
+                layout.size = align_to(layout.size, layout.align);
+                Some(layout)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the offset in bytes of a member of the struct `struct_name`.
+    pub fn member_offset(&self, struct_name: &str, member: &str) -> Option<usize> {
+        match self.definitions.get(struct_name)? {
+            vkxml::DefinitionsElement::Struct(_struct) => _struct.member_offset(member, self),
+            _ => None,
+        }
+    }
+
+    /// Lays out the members of a struct in order. Stops at the first member without a known
+    /// layout, the offsets up to it are still returned.
+    fn struct_offsets<'s>(
+        &self,
+        _struct: &'s vkxml::Struct,
+    ) -> (Vec<(&'s str, usize)>, Option<Layout>) {
+        let mut offsets = Vec::new();
+        let mut layout = Layout::new(0, 1);
+        let members = _struct.elements.iter().filter_map(|elem| match elem {
+            vkxml::StructElement::Member(field) => Some(field),
+            _ => None,
+        });
+        for field in members {
+            let member = match self.field_layout(field) {
+                Some(member) => member,
+                None => return (offsets, None),
+            };
+            let offset = align_to(layout.size, member.align);
+            offsets.push((field.name.as_deref().unwrap_or_default(), offset));
+            layout.size = offset + member.size;
+            layout.align = layout.align.max(member.align);
+        }
+        layout.size = align_to(layout.size, layout.align);
+        (offsets, Some(layout))
+    }
+
+    pub fn target(&self) -> Target {
+        self.target
+    }
+
+    fn field_layout(&self, field: &vkxml::Field) -> Option<Layout> {
+        if field.reference.is_some() {
+            let width = self.target.pointer_width() as usize / 8;
+            return Some(Layout::new(width, width));
+        }
+        let element = self.layout(&field.basetype)?;
+        match field.array_extent() {
+            Some(extent) => {
+                let len = extent.resolve(&self.array_sizes)? as usize;
+                Some(Layout::new(element.size * len, element.align))
+            }
+            None => Some(element),
+        }
+    }
+}
+/// Generates a test module that asserts the size and alignment of every struct and union, and
+/// the offsets of struct members, match `layouts`. The module only runs on the target of
+/// `layouts`.
+pub fn generate_layout_tests(
+    definitions: &[&vkxml::DefinitionsElement],
+    layouts: &TypeLayouts,
+    versions: &HashMap<&str, Version, impl BuildHasher>,
+) -> Tokens {
+    let tests =
+        definitions.iter().filter_map(|def| {
+            let (name, members) = match def {
+                // Written by hand, their bitfields aren't part of the registry layout
+                vkxml::DefinitionsElement::Struct(_struct)
+                    if _struct.name == "VkTransformMatrixKHR"
+                        || _struct.name == "VkAccelerationStructureInstanceKHR" =>
+                {
+                    return None
+                }
+                vkxml::DefinitionsElement::Struct(_struct) => (
+                    &_struct.name,
+                    _struct
+                        .elements
+                        .iter()
+                        .filter_map(|elem| match elem {
+                            vkxml::StructElement::Member(field) => Some(field),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                vkxml::DefinitionsElement::Union(union) => (&union.name, Vec::new()),
+                _ => return None,
+            };
+            let layout = layouts.layout(name)?;
+            let ty = name_to_tokens(name);
+            let test_name = Ident::from(format!("layout_of_{}", ty.as_ref().to_snake_case()));
+            let size = layout.size;
+            let align = layout.align;
+            let offsets: Vec<_> = members.iter().filter_map(|field| {
+            let offset = layouts.member_offset(name, field.name.as_deref()?)?;
+            let member = field.param_ident();
+            Some(quote! {
+                assert_eq!(
+                    unsafe { ::std::ptr::addr_of!((*base).#member) as usize - base as usize },
+                    #offset
+                );
+            })
+        }).collect();
+            let uninit = if offsets.is_empty() {
+                None
+            } else {
+                Some(quote! {
+                    let uninit = ::std::mem::MaybeUninit::<#ty>::uninit();
+                    let base = uninit.as_ptr();
+                })
+            };
+            let cfg = versions.get(name.as_str()).and_then(|&v| feature_cfg(v));
+            Some(quote! {
+                #cfg
+                #[test]
+                fn #test_name() {
+                    assert_eq!(::std::mem::size_of::<#ty>(), #size);
+                    assert_eq!(::std::mem::align_of::<#ty>(), #align);
+                    #uninit
+                    #(#offsets)*
+                }
+            })
+        });
+    let target = layouts.target().cfg_predicate();
+    quote! {
+        #[cfg(all(test, #target))]
+        mod layout_tests {
+            use super::*;
+            #(#tests)*
+        }
+    }
+}
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+/// Returns the types whose Rust type isn't `Copy`: the types that structs hold by value but that
+/// the registry doesn't define, and every struct that holds one of those by value, directly or
+/// through another struct. C types and every other kind of definition are `Copy`.
+pub fn non_copy_types<'a>(definitions: &[&'a vkxml::DefinitionsElement]) -> HashSet<&'a str> {
+    let defined: HashSet<&str> = definitions
+        .iter()
+        .filter_map(|&def| match def {
+            vkxml::DefinitionsElement::Reference(reference) => Some(reference.name.as_str()),
+            vkxml::DefinitionsElement::Enumeration(_enum) => Some(_enum.name.as_str()),
+            _ => definition_name(def),
+        })
+        .collect();
+    let structs: Vec<(&str, Vec<&vkxml::Field>)> = definitions
+        .iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Struct(_struct) => Some((
+                _struct.name.as_str(),
+                _struct
+                    .elements
+                    .iter()
+                    .filter_map(|elem| match elem {
+                        vkxml::StructElement::Member(field) => Some(field),
+                        _ => None,
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .collect();
+    let mut non_copy: HashSet<&str> = structs
+        .iter()
+        .flat_map(|(_, fields)| fields.iter())
+        .filter(|field| field.reference.is_none())
+        .map(|field| field.basetype.as_str())
+        .filter(|name| !defined.contains(name) && !is_c_type(name))
+        .collect();
+    loop {
+        let known = non_copy.len();
+        for (name, fields) in &structs {
+            if !fields.iter().all(|field| field.is_copy(&non_copy)) {
+                non_copy.insert(name);
+            }
+        }
+        if non_copy.len() == known {
+            return non_copy;
+        }
+    }
+}
+/// Returns true for the C types that `name_to_tokens` maps to a Rust primitive or `std::os::raw`.
+fn is_c_type(name: &str) -> bool {
+    CPrimitive::parse(name).is_some() || matches!(name, "size_t" | "int" | "void" | "char" | "long")
+}
+fn field_dependency(field: &vkxml::Field) -> (&str, Dependency) {
+    let kind = if field.reference.is_some() {
+        Dependency::PointsTo
+    } else {
+        Dependency::Embeds
+    };
+    (field.basetype.as_str(), kind)
+}
+#[allow(clippy::too_many_arguments)]
+pub fn generate_definition(
+    definition: &vkxml::DefinitionsElement,
+    union_types: &HashSet<&str, impl BuildHasher>,
+    root_structs: &HashMap<String, ChainDirection, impl BuildHasher>,
+    array_sizes: &HashMap<&str, u64, impl BuildHasher>,
+    non_copy_types: &HashSet<&str, impl BuildHasher>,
+    base_types: &HashMap<String, BaseType, impl BuildHasher>,
+    funcpointer_protects: &HashMap<&str, &str, impl BuildHasher>,
+    bitflags_cache: &mut HashSet<Ident, impl BuildHasher>,
+    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    aliases: &[&str],
+) -> Option<Tokens> {
+    match *definition {
+        vkxml::DefinitionsElement::Typedef(ref typedef) => {
+            Some(generate_typedef(typedef, base_types.get(&typedef.name)))
+        }
+        vkxml::DefinitionsElement::Struct(ref _struct) => Some(generate_struct(
+            _struct,
+            root_structs,
+            union_types,
+            array_sizes,
+            non_copy_types,
+            aliases,
+        )),
+        vkxml::DefinitionsElement::Bitmask(ref mask) => {
+            generate_bitmask(mask, bitflags_cache, const_values)
+        }
+        vkxml::DefinitionsElement::Handle(ref handle) => generate_handle(handle),
+        vkxml::DefinitionsElement::FuncPtr(ref fp) => Some(generate_funcptr(
+            fp,
+            funcpointer_protects.get(fp.name.as_str()).cloned(),
         )),
+        vkxml::DefinitionsElement::Union(ref union) => Some(generate_union(union, aliases)),
         _ => None,
-    });
+    }
+}
+/// Generates all definitions of the registry. Types that are embedded by value come before the
+/// types that contain them, see [`TypeDependencyGraph::topological_order`]. Structs and unions
+/// that were introduced by a core version are gated by its feature, and list their `aliases`.
+/// Function pointers are gated by their platform in `funcpointer_protects`.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_definitions(
+    definitions: &[&vkxml::DefinitionsElement],
+    array_sizes: &HashMap<&str, u64, impl BuildHasher>,
+    versions: &HashMap<&str, Version, impl BuildHasher>,
+    aliases: &HashMap<&str, &str, impl BuildHasher>,
+    base_types: &HashMap<String, BaseType, impl BuildHasher>,
+    funcpointer_protects: &HashMap<&str, &str, impl BuildHasher>,
+    bitflags_cache: &mut HashSet<Ident, impl BuildHasher>,
+    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+) -> Vec<Tokens> {
+    let union_types: HashSet<&str> = definitions
+        .iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Union(ref union) => Some(union.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    let root_structs = root_structs(definitions);
+    let non_copy_types = non_copy_types(definitions);
+    let by_name: HashMap<&str, &vkxml::DefinitionsElement> = definitions
+        .iter()
+        .filter_map(|&def| Some((definition_name(def)?, def)))
+        .collect();
+    TypeDependencyGraph::new(definitions)
+        .topological_order()
+        .into_iter()
+        .filter_map(|name| {
+            let def = by_name[name];
+            let tokens = generate_definition(
+                def,
+                &union_types,
+                &root_structs,
+                array_sizes,
+                &non_copy_types,
+                base_types,
+                funcpointer_protects,
+                bitflags_cache,
+                const_values,
+                &aliases_of(aliases, name),
+            )?;
+            // Enums and handles stay available, extensions extend and alias them freely.
+            match def {
+                vkxml::DefinitionsElement::Struct(_) | vkxml::DefinitionsElement::Union(_) => (),
+                _ => return Some(tokens),
+            };
+            match versions.get(name).and_then(|&v| feature_cfg(v)) {
+                Some(cfg) => Some(gate_items(&cfg, tokens)),
+                None => Some(tokens),
+            }
+        })
+        .collect()
+}
+fn alias_docs(aliases: &[&str]) -> Option<Tokens> {
+    if aliases.is_empty() {
+        return None;
+    }
+    let aliases = format!("Also known as: {}", aliases.join(", "));
+    Some(quote! {
+        #[doc = ""]
+        #[doc = #aliases]
+    })
+}
+fn definition_name(definition: &vkxml::DefinitionsElement) -> Option<&str> {
+    match definition {
+        vkxml::DefinitionsElement::Typedef(typedef) => Some(&typedef.name),
+        vkxml::DefinitionsElement::Bitmask(bitmask) => Some(&bitmask.name),
+        vkxml::DefinitionsElement::Struct(_struct) => Some(&_struct.name),
+        vkxml::DefinitionsElement::Union(union) => Some(&union.name),
+        vkxml::DefinitionsElement::Handle(handle) if !handle.name.is_empty() => Some(&handle.name),
+        vkxml::DefinitionsElement::FuncPtr(fnptr) => Some(&fnptr.name),
+        _ => None,
+    }
+}
+/// Returns the earliest core version that requires each definition.
+pub fn definition_versions<'a>(features: &[&'a vkxml::Feature]) -> HashMap<&'a str, Version> {
+    let mut versions = HashMap::new();
+    for feature in features {
+        let version = feature.version();
+        let references = feature
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                vkxml::FeatureElement::Require(ref spec) => Some(&spec.elements),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|reference| match reference {
+                vkxml::FeatureReference::DefinitionReference(ref def_ref) => {
+                    Some(def_ref.name.as_str())
+                }
+                _ => None,
+            });
+        for name in references {
+            let entry = versions.entry(name).or_insert(version);
+            *entry = std::cmp::min(*entry, version);
+        }
+    }
+    versions
+}
+/// Narrows `versions` down to the structs and unions that are gated by their version. Everything
+/// that stays available without the version features keeps the types that it refers to available
+/// as well: the definitions that aren't gated, the core types that an extension requires, under
+/// their own name or an alias, and the types in the signatures of the commands of extensions.
+pub fn gated_versions<'a>(
+    versions: &HashMap<&'a str, Version, impl BuildHasher>,
+    definitions: &[&vkxml::DefinitionsElement],
+    commands: &CommandMap<'_>,
+    extensions: &[vk_parse::Extension],
+    type_aliases: &HashMap<&str, &str, impl BuildHasher>,
+    cmd_aliases: &HashMap<String, String, impl BuildHasher>,
+) -> HashMap<&'a str, Version> {
+    let resolve = |name: &str| -> String {
+        let mut name = name;
+        // Bounded, in case the registry contains a cycle
+        for _ in 0..type_aliases.len() {
+            match type_aliases.get(name) {
+                Some(&target) => name = target,
+                None => break,
+            }
+        }
+        name.to_string()
+    };
+    let field_types = |fields: &mut dyn Iterator<Item = &vkxml::Field>| -> Vec<String> {
+        fields.map(|field| resolve(&field.basetype)).collect()
+    };
+
+    // The types that each struct, union and function pointer refers to
+    let mut gateable = HashSet::new();
+    let mut references: HashMap<&str, Vec<String>> = HashMap::new();
+    for def in definitions {
+        let (name, types) = match def {
+            vkxml::DefinitionsElement::Struct(_struct) => {
+                let mut members = _struct.elements.iter().filter_map(|elem| match elem {
+                    vkxml::StructElement::Member(field) => Some(field),
+                    _ => None,
+                });
+                let mut types = field_types(&mut members);
+                if let Some(extends) = &_struct.extends {
+                    types.extend(extends.split(',').map(&resolve));
+                }
+                gateable.insert(_struct.name.as_str());
+                (_struct.name.as_str(), types)
+            }
+            vkxml::DefinitionsElement::Union(union) => {
+                gateable.insert(union.name.as_str());
+                (union.name.as_str(), field_types(&mut union.elements.iter()))
+            }
+            vkxml::DefinitionsElement::FuncPtr(fnptr) => {
+                let mut fields = fnptr
+                    .param
+                    .iter()
+                    .chain(std::iter::once(&fnptr.return_type));
+                (fnptr.name.as_str(), field_types(&mut fields))
+            }
+            _ => continue,
+        };
+        references.insert(name, types);
+    }
+
+    let is_gated = |name: &str| {
+        gateable.contains(name) && versions.get(name).and_then(|&v| feature_cfg(v)).is_some()
+    };
+    let mut pending: Vec<String> = references
+        .keys()
+        .filter(|name| !is_gated(name))
+        .map(|name| name.to_string())
+        .collect();
+    for extension in extensions {
+        let provided = extension.provided_items();
+        pending.extend(provided.types.iter().map(|name| resolve(name)));
+        for name in provided.commands {
+            let name = cmd_aliases.get(name).map_or(name, String::as_str);
+            if let Some(cmd) = commands.get(name) {
+                let mut fields = cmd.param.iter().chain(std::iter::once(&cmd.return_type));
+                pending.extend(field_types(&mut fields));
+            }
+        }
+    }
+    let mut reached = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if let Some(types) = references.get(name.as_str()) {
+            if !reached.contains(&name) {
+                pending.extend(types.iter().cloned());
+            }
+        }
+        reached.insert(name);
+    }
+
+    versions
+        .iter()
+        .filter(|(name, _)| is_gated(name) && !reached.contains(**name))
+        .map(|(&name, &version)| (name, version))
+        .collect()
+}
+/// Items introduced after Vulkan 1.0 are gated behind a cargo feature like `V1_1`.
+pub fn feature_cfg(version: Version) -> Option<Tokens> {
+    if version <= Version::new(1, 0) {
+        return None;
+    }
+    let feature = format!("V{}_{}", version.major, version.minor);
+    Some(quote! {
+        #[cfg(feature = #feature)]
+    })
+}
+/// Puts `cfg` in front of every item in `tokens`.
+pub fn gate_items(cfg: &Tokens, tokens: Tokens) -> Tokens {
+    let file = syn::parse_file(&tokens.to_string()).expect("generated items");
+    let items = file.items.iter().map(|item| quote! { #cfg #item });
     quote! {
-        #(#constants)*
+        #(#items)*
+    }
+}
+pub fn generate_feature<'a>(
+    feature: &vkxml::Feature,
+    commands: &CommandMap<'a>,
+    fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
+) -> quote::Tokens {
+    let (static_commands, entry_commands, device_commands, instance_commands) = feature
+        .elements
+        .iter()
+        .flat_map(|feature| {
+            if let vkxml::FeatureElement::Require(ref spec) = feature {
+                spec.elements
+                    .iter()
+                    .filter_map(|feature_spec| {
+                        if let vkxml::FeatureReference::CommandReference(ref cmd_ref) = feature_spec
+                        {
+                            Some(cmd_ref)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            } else {
+                vec![]
+            }
+        })
+        .filter_map(|cmd_ref| commands.get(&cmd_ref.name))
+        .fold(
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+            |mut acc, &cmd_ref| {
+                match cmd_ref.dispatch_level(None) {
+                    // `vkGetInstanceProcAddr` loads all other commands, it gets a table of its own
+                    DispatchLevel::Entry
+                        if matches!(cmd_ref.function_type(), FunctionType::Static) =>
+                    {
+                        acc.0.push(cmd_ref);
+                    }
+                    DispatchLevel::Entry => {
+                        acc.1.push(cmd_ref);
+                    }
+                    DispatchLevel::Device => {
+                        acc.2.push(cmd_ref);
+                    }
+                    DispatchLevel::Instance => {
+                        acc.3.push(cmd_ref);
+                    }
+                }
+                acc
+            },
+        );
+    let version = feature.version_string();
+    let static_fn = if feature.is_version(1, 0) {
+        generate_function_pointers(
+            Ident::from("StaticFn"),
+            quote! {},
+            &static_commands,
+            &HashMap::new(),
+            &HashMap::new(),
+            fn_cache,
+        )
+    } else {
+        quote! {}
+    };
+    let entry = generate_function_pointers(
+        Ident::from(format!("EntryFnV{}", version).as_str()),
+        quote! {},
+        &entry_commands,
+        &HashMap::new(),
+        &HashMap::new(),
+        fn_cache,
+    );
+    let instance = generate_function_pointers(
+        Ident::from(format!("InstanceFnV{}", version).as_str()),
+        quote! {},
+        &instance_commands,
+        &HashMap::new(),
+        &HashMap::new(),
+        fn_cache,
+    );
+    let device = generate_function_pointers(
+        Ident::from(format!("DeviceFnV{}", version).as_str()),
+        quote! {},
+        &device_commands,
+        &HashMap::new(),
+        &HashMap::new(),
+        fn_cache,
+    );
+    let tokens = quote! {
+        #static_fn
+        #entry
+        #instance
+        #device
+    };
+    match feature_cfg(feature.version()) {
+        Some(cfg) => gate_items(&cfg, tokens),
+        None => tokens,
+    }
+}
+pub fn constant_name(name: &str) -> String {
+    name.replace("VK_", "")
+}
+
+/// Generates a constant. A value that refers to other constants, like
+/// `(VK_MAX_EXTENSION_NAME_SIZE - 1)`, has the type of the first constant it refers to, which has
+/// to be in `types` already, and is an error otherwise. The type of `constant` is added to `types`.
+pub fn generate_constant<'a>(
+    constant: &'a vkxml::Constant,
+    cache: &mut HashSet<&'a str, impl BuildHasher>,
+    types: &mut HashMap<&'a str, CType>,
+) -> Result<Tokens, String> {
+    cache.insert(constant.name.as_str());
+    let c = Constant::from_constant(constant);
+    let name = constant_name(&constant.name);
+    let ident = Ident::from(name.as_str());
+    let (ty, value) = match (&c, c.referenced_constants().first()) {
+        (Constant::CExpr(expr), Some(reference)) => {
+            let ty = types.get(reference).cloned().ok_or_else(|| {
+                format!("{} refers to unknown constant {}", constant.name, reference)
+            })?;
+            let term = Term::intern(&constant_name(expr));
+            (ty, quote! { #term })
+        }
+        _ if name == "TRUE" || name == "FALSE" => (CType::Bool32, c.to_tokens(None)),
+        _ => (c.ty(), c.to_tokens(None)),
+    };
+    let ty_tokens = ty.to_tokens();
+    types.insert(&constant.name, ty);
+    Ok(quote! {
+        pub const #ident: #ty_tokens = #value;
+    })
+}
+
+/// Orders `constants` so that every constant comes after the constants that its value refers to,
+/// and in registry order otherwise.
+pub fn constant_order<'a>(constants: &[&'a vkxml::Constant]) -> Vec<&'a vkxml::Constant> {
+    fn visit<'a>(
+        constant: &'a vkxml::Constant,
+        by_name: &HashMap<&str, &'a vkxml::Constant>,
+        visited: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a vkxml::Constant>,
+    ) {
+        if !visited.insert(&constant.name) {
+            return;
+        }
+        for reference in Constant::from_constant(constant).referenced_constants() {
+            if let Some(dep) = by_name.get(reference) {
+                visit(dep, by_name, visited, order);
+            }
+        }
+        order.push(constant);
+    }
+    let by_name: HashMap<&str, &vkxml::Constant> = constants
+        .iter()
+        .map(|constant| (constant.name.as_str(), *constant))
+        .collect();
+    let mut visited = HashSet::new();
+    let mut order = Vec::with_capacity(constants.len());
+    for constant in constants {
+        visit(constant, &by_name, &mut visited, &mut order);
+    }
+    order
+}
+
+/// Generates the API constants, each after the constants that it refers to. Fails if a constant
+/// refers to one that isn't part of `constants`.
+pub fn generate_constants<'a>(
+    constants: &[&'a vkxml::Constant],
+    cache: &mut HashSet<&'a str, impl BuildHasher>,
+) -> Result<Vec<Tokens>, String> {
+    let mut types = HashMap::new();
+    constant_order(constants)
+        .into_iter()
+        .map(|constant| generate_constant(constant, cache, &mut types))
+        .collect()
+}
+
+pub fn generate_feature_extension<'a>(
+    registry: &'a vk_parse::Registry,
+    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+) -> Tokens {
+    let constants = registry.0.iter().filter_map(|item| match item {
+        vk_parse::RegistryChild::Feature(feature) => Some(generate_extension_constants(
+            &feature.name,
+            0,
+            &feature.children,
+            None,
+            None,
+            const_cache,
+            const_values,
+        )),
+        _ => None,
+    });
+    quote! {
+        #(#constants)*
+    }
+}
+
+pub struct ConstantMatchInfo {
+    pub ident: Ident,
+    pub is_alias: bool,
+    pub protect: Option<String>,
+}
+
+pub fn generate_const_debugs(const_values: &BTreeMap<Ident, Vec<ConstantMatchInfo>>) -> Tokens {
+    let impls = const_values.iter().map(|(ty, values)| {
+        if ty.to_string().contains("Flags") {
+            let cases = values.iter().filter_map(|value| {
+                if value.is_alias {
+                    None
+                } else {
+                    let name = value.ident.to_string();
+                    let ident = value.ident;
+                    let cfg = value.protect.as_ref().map(|protect| platform_cfg(protect));
+                    Some(quote! { #cfg (#ty::#ident.0, #name) })
+                }
+            });
+            quote! {
+                impl fmt::Debug for #ty {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        const KNOWN: &[(Flags, &str)] = &[#(#cases),*];
+                        debug_flags(f, KNOWN, self.0)
+                    }
+                }
+            }
+        } else {
+            let cases = values.iter().filter_map(|value| {
+                if value.is_alias {
+                    None
+                } else {
+                    let name = value.ident.to_string();
+                    let ident = value.ident;
+                    let cfg = value.protect.as_ref().map(|protect| platform_cfg(protect));
+                    Some(quote! { #cfg Self::#ident => Some(#name), })
+                }
+            });
+            quote! {
+                impl fmt::Debug for #ty {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        let name = match *self {
+                            #(#cases)*
+                            _ => None,
+                        };
+                        if let Some(x) = name {
+                            f.write_str(x)
+                        } else {
+                            self.0.fmt(f)
+                        }
+                    }
+                }
+            }
+        }
+    });
+    quote! {
+        pub(crate) fn debug_flags(f: &mut fmt::Formatter, known: &[(Flags, &'static str)], value: Flags) -> fmt::Result {
+            let mut first = true;
+            let mut accum = value;
+            for (bit, name) in known {
+                if *bit != 0 && accum & *bit == *bit {
+                    if !first { f.write_str(" | ")?; }
+                    f.write_str(name)?;
+                    first = false;
+                    accum &= !bit;
+                }
+            }
+            if accum != 0 {
+                if !first { f.write_str(" | ")?; }
+                write!(f, "{:b}", accum)?;
+            }
+            Ok(())
+        }
+
+        #(#impls)*
+    }
+}
+/// Maps every type alias in the registry, like `VkPhysicalDeviceFeatures2KHR`, to the type it
+/// names.
+pub fn type_aliases(registry: &vk_parse::Registry) -> HashMap<&str, &str> {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref types) => Some(&types.children),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|child| match child {
+            vk_parse::TypesChild::Type(ty) => Some((ty.name.as_deref()?, ty.alias.as_deref()?)),
+            _ => None,
+        })
+        .collect()
+}
+/// Returns the sorted names of all aliases that resolve to `name`, directly or through other
+/// aliases.
+pub fn aliases_of<'a>(
+    aliases: &HashMap<&'a str, &'a str, impl BuildHasher>,
+    name: &str,
+) -> Vec<&'a str> {
+    let resolves_to_name = |alias: &str| {
+        let mut target = alias;
+        // Bounded, in case the registry contains a cycle
+        for _ in 0..aliases.len() {
+            match aliases.get(target) {
+                Some(&next) if next == name => return true,
+                Some(&next) => target = next,
+                None => return false,
+            }
+        }
+        false
+    };
+    let mut names: Vec<_> = aliases
+        .keys()
+        .copied()
+        .filter(|alias| resolves_to_name(alias))
+        .collect();
+    names.sort_unstable();
+    names
+}
+/// Generates a type alias for every alias in `types`, gated like the type it names, see
+/// [`gated_versions`].
+pub fn generate_aliases_of_types<'a>(
+    types: &'a vk_parse::Types,
+    versions: &HashMap<&str, Version, impl BuildHasher>,
+    ty_cache: &mut HashSet<Ident, impl BuildHasher>,
+) -> Tokens {
+    let aliases = types
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            vk_parse::TypesChild::Type(ty) => Some((ty.name.as_ref()?, ty.alias.as_ref()?)),
+            _ => None,
+        })
+        .filter_map(|(name, alias)| {
+            let name_ident = name_to_tokens(name);
+            if ty_cache.contains(&name_ident) {
+                return None;
+            };
+            ty_cache.insert(name_ident);
+            let alias_ident = name_to_tokens(alias);
+            let cfg = versions.get(alias.as_str()).and_then(|&v| feature_cfg(v));
+            let tokens = quote! {
+                #cfg
+                pub type #name_ident = #alias_ident;
+            };
+            Some(tokens)
+        });
+    quote! {
+        #(#aliases)*
+    }
+}
+fn video_enums(video: &vkxml::Registry) -> impl Iterator<Item = &vkxml::Enumeration> {
+    video
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Enums(ref enums) => Some(enums),
+            _ => None,
+        })
+        .flat_map(|enums| {
+            enums.elements.iter().filter_map(|_enum| match *_enum {
+                vkxml::EnumsElement::Enumeration(ref e) => Some(e),
+                _ => None,
+            })
+        })
+}
+/// A `StdVideo*ProfileIdc` enum of `video.xml`, which lists the profiles of a codec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoProfileEnum<'a> {
+    pub name: &'a str,
+    /// The codec, like `H264` for `StdVideoH264ProfileIdc`.
+    pub codec: &'a str,
+    /// The profiles as `(name, value)`, in registry order.
+    pub profiles: Vec<(&'a str, u64)>,
+}
+/// Returns the profile enums of every codec in `video.xml`.
+pub fn video_profile_enums(video: &vkxml::Registry) -> Vec<VideoProfileEnum<'_>> {
+    video_enums(video)
+        .filter_map(|_enum| {
+            let codec = _enum
+                .name
+                .strip_prefix("StdVideo")?
+                .strip_suffix("ProfileIdc")?;
+            let profiles = _enum
+                .elements
+                .iter()
+                .filter_map(|elem| match *elem {
+                    vkxml::EnumerationElement::Enum(ref constant) => {
+                        let value = Constant::from_constant(constant).value()?;
+                        Some((constant.name.as_str(), value.bits()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            Some(VideoProfileEnum {
+                name: &_enum.name,
+                codec,
+                profiles,
+            })
+        })
+        .collect()
+}
+/// Returns the width of a bitfield member, like the `1` of `uint32_t flag : 1`.
+pub fn bitfield_width(member: &vk_parse::TypeMemberDefinition) -> Option<u32> {
+    let (_, width) = member.code.rsplit_once(':')?;
+    width.trim().parse().ok()
+}
+/// Returns the members of every struct that declares a bitfield. vkxml loses the names of
+/// bitfield members, so these structs are generated from the members of `vk_parse` instead.
+pub fn bitfield_structs(
+    registry: &vk_parse::Registry,
+) -> HashMap<&str, Vec<&vk_parse::TypeMemberDefinition>> {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref types) => Some(types.children.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|child| match child {
+            vk_parse::TypesChild::Type(ty) => match ty.spec {
+                vk_parse::TypeSpec::Members(ref members) => Some((ty.name.as_deref()?, members)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter_map(|(name, members)| {
+            let members = members
+                .iter()
+                .filter_map(|member| match member {
+                    vk_parse::TypeMember::Definition(ref definition) => Some(definition),
+                    _ => None,
+                })
+                .collect_vec();
+            if members
+                .iter()
+                .any(|member| bitfield_width(member).is_some())
+            {
+                Some((name, members))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+/// Generates a `#[repr(C)]` struct with bitfields. Consecutive bitfields of the same type share
+/// a storage unit, like C compilers lay them out, and are read and written through accessors.
+/// Fails if a member has no name or type, or if a bitfield isn't an integer.
+fn generate_bitfield_struct(
+    name: &str,
+    members: &[&vk_parse::TypeMemberDefinition],
+) -> Result<Tokens, String> {
+    let ident = Ident::from(name);
+    let mut fields = Vec::new();
+    let mut accessors = Vec::new();
+    // The storage unit that is currently filled, as `(type, bits used, field)`
+    let mut unit: Option<(&str, u32, Ident)> = None;
+    let mut unit_count = 0;
+    for member in members {
+        let (mut member_name, mut member_ty) = (None, None);
+        for markup in &member.markup {
+            match markup {
+                vk_parse::TypeMemberMarkup::Name(name) => member_name = Some(name.as_str()),
+                vk_parse::TypeMemberMarkup::Type(ty) => member_ty = Some(ty.as_str()),
+                _ => (),
+            }
+        }
+        let member_name =
+            member_name.ok_or_else(|| format!("{} has a member without a name", name))?;
+        let member_ty = member_ty.ok_or_else(|| format!("{}.{} has no type", name, member_name))?;
+        let param_ident = Ident::from(escape_keyword(&member_name.to_snake_case()));
+        let ty_tokens = name_to_tokens(member_ty);
+        let width = match bitfield_width(member) {
+            Some(width) => width,
+            None => {
+                unit = None;
+                fields.push(quote!(pub #param_ident: #ty_tokens));
+                continue;
+            }
+        };
+        let unit_bits = CPrimitive::parse(member_ty)
+            .filter(|primitive| !matches!(primitive, CPrimitive::F32 | CPrimitive::F64))
+            .ok_or_else(|| {
+                format!(
+                    "{}.{} is a bitfield of {}, which isn't an integer",
+                    name, member_name, member_ty
+                )
+            })?
+            .size() as u32
+            * 8;
+        let (offset, storage) = match unit {
+            Some((ty, used, storage)) if ty == member_ty && used + width <= unit_bits => {
+                (used, storage)
+            }
+            _ => {
+                let storage = Ident::from(format!("_bitfield_{}", unit_count).as_str());
+                unit_count += 1;
+                fields.push(quote!(#storage: #ty_tokens));
+                (0, storage)
+            }
+        };
+        unit = Some((member_ty, offset + width, storage));
+        let mask = Term::intern(&format!("{:#x}", (1u64 << width) - 1));
+        let offset = Term::intern(&offset.to_string());
+        let setter_ident = Ident::from(format!("set_{}", param_ident).as_str());
+        accessors.push(quote! {
+            pub fn #param_ident(&self) -> #ty_tokens {
+                (self.#storage >> #offset) & #mask
+            }
+            pub fn #setter_ident(&mut self, #param_ident: #ty_tokens) {
+                self.#storage = (self.#storage & !(#mask << #offset))
+                    | ((#param_ident & #mask) << #offset);
+            }
+        });
+    }
+    Ok(quote! {
+        #[repr(C)]
+        #[derive(Copy, Clone, Default)]
+        pub struct #ident {
+            #(#fields),*
+        }
+        impl #ident {
+            #(#accessors)*
+        }
+    })
+}
+/// Generates the contents of `vk/video.rs` from `video.xml`, the registry of the video std
+/// headers. Those types keep their `StdVideo` names, and are plain C structs and enums. Structs
+/// with bitfields are taken from `video_spec`, the same registry parsed by `vk_parse`. Fails if
+/// one of those structs can't be generated.
+pub fn generate_video(
+    video: &vkxml::Registry,
+    video_spec: &vk_parse::Registry,
+) -> Result<Tokens, String> {
+    let bitfield_structs = bitfield_structs(video_spec);
+    let enums = video_enums(video).map(|_enum| {
+        let ident = Ident::from(_enum.name.as_str());
+        let constants: Vec<_> = _enum
+            .elements
+            .iter()
+            .filter_map(|elem| match *elem {
+                vkxml::EnumerationElement::Enum(ref constant) => Some(constant),
+                _ => None,
+            })
+            .collect();
+        let impl_block = bitflags_impl_block(ident, &_enum.name, &constants);
+        quote! {
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+            #[repr(transparent)]
+            pub struct #ident(pub(crate) i32);
+            impl #ident {
+                pub const fn from_raw(x: i32) -> Self { #ident(x) }
+                pub const fn as_raw(self) -> i32 { self.0 }
+            }
+            #impl_block
+        }
+    });
+    let structs = video
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Definitions(ref definitions) => Some(definitions),
+            _ => None,
+        })
+        .flat_map(|definitions| definitions.elements.iter())
+        .filter_map(|definition| match definition {
+            vkxml::DefinitionsElement::Struct(ref _struct) => Some(_struct),
+            _ => None,
+        })
+        .map(|_struct| {
+            if let Some(members) = bitfield_structs.get(_struct.name.as_str()) {
+                return generate_bitfield_struct(&_struct.name, members);
+            }
+            let ident = Ident::from(_struct.name.as_str());
+            let fields = _struct
+                .elements
+                .iter()
+                .filter_map(|elem| match *elem {
+                    vkxml::StructElement::Member(ref field) => Some(field),
+                    _ => None,
+                })
+                .map(|field| {
+                    let name = field.param_ident();
+                    let ty = field.type_tokens(false);
+                    quote! {
+                        pub #name: #ty
+                    }
+                });
+            Ok(quote! {
+                #[repr(C)]
+                #[derive(Copy, Clone)]
+                pub struct #ident {
+                    #(#fields),*
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(quote! {
+        use crate::vk::platform_types::*;
+        #(#enums)*
+        #(#structs)*
+    })
+}
+/// The declaration of the `video` module in `vk.rs`. The video std types are only needed by the
+/// video extensions, so they are gated behind the `video` feature.
+pub fn video_module() -> Tokens {
+    quote! {
+        #[cfg(feature = "video")]
+        pub mod video;
+    }
+}
+/// The content of `vk/macros.rs`. Like [`generate_vk_rs`], it doesn't depend on the registry.
+pub fn vk_macros() -> Tokens {
+    let version_macros = vk_version_macros();
+    let bitflags_macro = vk_bitflags_wrapped_macro();
+    let handle_nondispatchable_macro = handle_nondispatchable_macro();
+    let define_handle_macro = define_handle_macro();
+    quote! {
+        #version_macros
+        #bitflags_macro
+        #handle_nondispatchable_macro
+        #define_handle_macro
+    }
+}
+/// The content of `vk.rs`, which declares the generated modules next to the traits and helpers
+/// that they share. `video_module` is only declared if the video std types were generated.
+pub fn generate_vk_rs(video_module: Option<Tokens>) -> String {
+    // These are defined outside of `quote!` because rustfmt doesn't seem
+    // to format them correctly when they contain extra spaces.
+    let vk_rs_clippy_lints = r#"
+#![allow(clippy::too_many_arguments, clippy::cognitive_complexity, clippy::wrong_self_convention)]
+"#;
+
+    let ptr_chain_code = quote! {
+        /// Iterates through the pointer chain. Includes the item that is passed into the function.
+        /// Stops at the last `BaseOutStructure` that has a null `p_next` field.
+        pub(crate) unsafe fn ptr_chain_iter<T>(
+            ptr: &mut T,
+        ) -> impl Iterator<Item = *mut BaseOutStructure> {
+            let ptr: *mut BaseOutStructure = ptr as *mut T as _;
+            (0..).scan(ptr, |p_ptr, _| {
+                if p_ptr.is_null() {
+                    return None;
+                }
+                let n_ptr = (**p_ptr).p_next as *mut BaseOutStructure;
+                let old = *p_ptr;
+                *p_ptr = n_ptr;
+                Some(old)
+            })
+        }
+    };
+
+    let vk_rs_code = quote! {
+        #[macro_use]
+        mod macros;
+        pub use macros::*;
+        mod aliases;
+        pub use aliases::*;
+        mod bitflags;
+        pub use bitflags::*;
+        // The debug impls and default values still refer to constants of deprecated extensions.
+        #[allow(deprecated)]
+        mod const_debugs;
+        pub(crate) use const_debugs::*;
+        mod constants;
+        pub use constants::*;
+        #[allow(deprecated)]
+        mod definitions;
+        pub use definitions::*;
+        mod enums;
+        pub use enums::*;
+        // Deprecated extensions are still generated, only their users should be warned.
+        #[allow(deprecated)]
+        mod extensions;
+        pub use extensions::*;
+        mod feature_extensions;
+        pub use feature_extensions::*;
+        mod features;
+        pub use features::*;
+        mod platform_types;
+        pub use platform_types::*;
+        #video_module
+
+        #ptr_chain_code
+
+        pub trait Handle {
+            const TYPE: ObjectType;
+            fn as_raw(self) -> u64;
+            /// # Safety
+            ///
+            /// `x` must be null or a valid handle of this type.
+            unsafe fn from_raw(x: u64) -> Self;
+        }
+
+        /// Structs that are identified by their `s_type`, which always equals `STRUCTURE_TYPE`.
+        pub trait TaggedStructure {
+            const STRUCTURE_TYPE: StructureType;
+        }
+    };
+    format!("{} {}", vk_rs_clippy_lints, vk_rs_code)
+}
+/// Writes `code` to `path`, unless the file already has that content. Keeping the file untouched
+/// preserves its modification time, so crates that depend on it aren't rebuilt needlessly.
+pub fn write_if_changed(path: &Path, code: &str) -> bool {
+    if std::fs::read(path).is_ok_and(|existing| existing == code.as_bytes()) {
+        return false;
     }
+    std::fs::write(path, code).unwrap_or_else(|err| panic!("Unable to write {:?}: {}", path, err));
+    true
 }
 
-pub struct ConstantMatchInfo {
-    pub ident: Ident,
-    pub is_alias: bool,
+/// The kind of a registry item. Items of different kinds may share a name, like a struct and the
+/// command that fills it, so the kind is part of the `ItemId`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ItemKind {
+    Typedef,
+    Bitmask,
+    Struct,
+    Union,
+    Handle,
+    FuncPtr,
+    Enum,
+    Constant,
+    Command,
 }
 
-pub fn generate_const_debugs(const_values: &BTreeMap<Ident, Vec<ConstantMatchInfo>>) -> Tokens {
-    let impls = const_values.iter().map(|(ty, values)| {
-        if ty.to_string().contains("Flags") {
-            let cases = values.iter().filter_map(|value| {
-                if value.is_alias {
-                    None
-                } else {
-                    let name = value.ident.to_string();
-                    let ident = value.ident;
-                    Some(quote! { (#ty::#ident.0, #name) })
-                }
-            });
-            quote! {
-                impl fmt::Debug for #ty {
-                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                        const KNOWN: &[(Flags, &str)] = &[#(#cases),*];
-                        debug_flags(f, KNOWN, self.0)
+/// A stable id of a registry item, derived from its kind and name only. It doesn't change between
+/// runs or generator builds, so generated output can be cached by it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemId(pub u32);
+impl ItemId {
+    pub fn new(kind: ItemKind, name: &str) -> ItemId {
+        ItemId(fnv1a(&format!("{:?}:{}", kind, name)))
+    }
+}
+/// FNV-1a, as the hashers of std are not guaranteed to be stable across releases.
+fn fnv1a(data: &str) -> u32 {
+    data.bytes().fold(0x811c_9dc5u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item<'a> {
+    pub id: ItemId,
+    pub kind: ItemKind,
+    pub name: &'a str,
+}
+impl<'a> Item<'a> {
+    pub fn new(kind: ItemKind, name: &'a str) -> Item<'a> {
+        Item {
+            id: ItemId::new(kind, name),
+            kind,
+            name,
+        }
+    }
+}
+
+/// Checks that no two different items share an id. FNV-1a is not free of collisions, and a cache
+/// that is keyed by `ItemId` would mix up the output of colliding items.
+pub fn check_item_ids(items: &[Item<'_>]) -> Result<(), String> {
+    let mut seen: HashMap<ItemId, &Item<'_>> = HashMap::new();
+    for item in items {
+        if let Some(other) = seen.insert(item.id, item) {
+            if (other.kind, other.name) != (item.kind, item.name) {
+                return Err(format!(
+                    "{:?} {} and {:?} {} share the id {}",
+                    other.kind, other.name, item.kind, item.name, item.id.0
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Collects every named type, enum, constant and command of the registry, in registry order.
+pub fn collect_items(registry: &vkxml::Registry) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+    for elem in &registry.elements {
+        match elem {
+            vkxml::RegistryElement::Definitions(definitions) => {
+                items.extend(definitions.elements.iter().filter_map(|def| match def {
+                    vkxml::DefinitionsElement::Typedef(typedef) => {
+                        Some(Item::new(ItemKind::Typedef, &typedef.name))
                     }
-                }
+                    vkxml::DefinitionsElement::Bitmask(bitmask) => {
+                        Some(Item::new(ItemKind::Bitmask, &bitmask.name))
+                    }
+                    vkxml::DefinitionsElement::Struct(_struct) => {
+                        Some(Item::new(ItemKind::Struct, &_struct.name))
+                    }
+                    vkxml::DefinitionsElement::Union(union) => {
+                        Some(Item::new(ItemKind::Union, &union.name))
+                    }
+                    vkxml::DefinitionsElement::Handle(handle) if !handle.name.is_empty() => {
+                        Some(Item::new(ItemKind::Handle, &handle.name))
+                    }
+                    vkxml::DefinitionsElement::FuncPtr(fnptr) => {
+                        Some(Item::new(ItemKind::FuncPtr, &fnptr.name))
+                    }
+                    _ => None,
+                }))
             }
-        } else {
-            let cases = values.iter().filter_map(|value| {
-                if value.is_alias {
-                    None
-                } else {
-                    let name = value.ident.to_string();
-                    let ident = value.ident;
-                    Some(quote! { Self::#ident => Some(#name), })
-                }
-            });
-            quote! {
-                impl fmt::Debug for #ty {
-                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                        let name = match *self {
-                            #(#cases)*
-                            _ => None,
-                        };
-                        if let Some(x) = name {
-                            f.write_str(x)
-                        } else {
-                            self.0.fmt(f)
-                        }
+            vkxml::RegistryElement::Enums(enums) => {
+                items.extend(enums.elements.iter().filter_map(|elem| match elem {
+                    vkxml::EnumsElement::Enumeration(_enum) => {
+                        Some(Item::new(ItemKind::Enum, &_enum.name))
                     }
-                }
+                    _ => None,
+                }))
             }
+            vkxml::RegistryElement::Constants(constants) => items.extend(
+                constants
+                    .elements
+                    .iter()
+                    .map(|constant| Item::new(ItemKind::Constant, &constant.name)),
+            ),
+            vkxml::RegistryElement::Commands(commands) => items.extend(
+                commands
+                    .elements
+                    .iter()
+                    .map(|cmd| Item::new(ItemKind::Command, &cmd.name)),
+            ),
+            _ => (),
         }
-    });
-    quote! {
-        pub(crate) fn debug_flags(f: &mut fmt::Formatter, known: &[(Flags, &'static str)], value: Flags) -> fmt::Result {
-            let mut first = true;
-            let mut accum = value;
-            for (bit, name) in known {
-                if *bit != 0 && accum & *bit == *bit {
-                    if !first { f.write_str(" | ")?; }
-                    f.write_str(name)?;
-                    first = false;
-                    accum &= !bit;
-                }
+    }
+    items
+}
+
+/// The number of structs, enums, commands and extensions of a registry, and the version of the
+/// headers it belongs to. Its `Display` form is meant for logging by the generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RegistrySummary {
+    pub header_version: Option<u32>,
+    pub structs: usize,
+    pub enums: usize,
+    pub commands: usize,
+    pub extensions: usize,
+}
+impl Display for RegistrySummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.header_version {
+            Some(version) => write!(f, "Vulkan headers version {}", version)?,
+            None => write!(f, "Vulkan headers of unknown version")?,
+        }
+        write!(
+            f,
+            ": {} structs, {} enums, {} commands, {} extensions",
+            self.structs, self.enums, self.commands, self.extensions
+        )
+    }
+}
+
+/// Summarizes `registry`. The header version is the value of the `VK_HEADER_VERSION` define.
+pub fn registry_summary(registry: &vkxml::Registry) -> RegistrySummary {
+    let mut summary = RegistrySummary::default();
+    for item in collect_items(registry) {
+        match item.kind {
+            ItemKind::Struct => summary.structs += 1,
+            ItemKind::Enum => summary.enums += 1,
+            ItemKind::Command => summary.commands += 1,
+            _ => (),
+        }
+    }
+    for elem in &registry.elements {
+        match elem {
+            vkxml::RegistryElement::Definitions(definitions) => {
+                summary.header_version = summary.header_version.or_else(|| {
+                    definitions.elements.iter().find_map(|def| match def {
+                        vkxml::DefinitionsElement::Define(define)
+                            if define.name == "VK_HEADER_VERSION" =>
+                        {
+                            define
+                                .value
+                                .as_ref()
+                                .or(define.c_expression.as_ref())
+                                .and_then(|value| value.trim().parse().ok())
+                        }
+                        _ => None,
+                    })
+                })
             }
-            if accum != 0 {
-                if !first { f.write_str(" | ")?; }
-                write!(f, "{:b}", accum)?;
+            vkxml::RegistryElement::Extensions(extensions) => {
+                summary.extensions += extensions.elements.len()
             }
-            Ok(())
+            _ => (),
         }
-
-        #(#impls)*
     }
+    summary
 }
-pub fn generate_aliases_of_types<'a>(
-    types: &'a vk_parse::Types,
-    ty_cache: &mut HashSet<Ident, impl BuildHasher>,
-) -> Tokens {
-    let aliases = types
-        .children
-        .iter()
-        .filter_map(|child| match child {
-            vk_parse::TypesChild::Type(ty) => Some((ty.name.as_ref()?, ty.alias.as_ref()?)),
-            _ => None,
-        })
-        .filter_map(|(name, alias)| {
-            let name_ident = name_to_tokens(name);
-            if ty_cache.contains(&name_ident) {
-                return None;
-            };
-            ty_cache.insert(name_ident);
-            let alias_ident = name_to_tokens(alias);
-            let tokens = quote! {
-                pub type #name_ident = #alias_ident;
-            };
-            Some(tokens)
-        });
-    quote! {
-        #(#aliases)*
+
+/// What `write_source_code` generated. Its `Display` form is meant for logging by the generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedSource {
+    pub summary: RegistrySummary,
+    /// The number of files that were generated, whether they changed or not.
+    pub modules: usize,
+    /// The paths of the files that were written.
+    pub written: Vec<PathBuf>,
+}
+impl Display for GeneratedSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}; generated {} modules, {} of them changed",
+            self.summary,
+            self.modules,
+            self.written.len()
+        )
     }
 }
-pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
-    use std::fs::File;
-    use std::io::Write;
-    let spec2 = vk_parse::parse_file(vk_xml);
+
+/// Generates the `vk` module from `vk_xml` into `src_dir`. Files whose content didn't change are
+/// left untouched, the others are listed in the returned `written`.
+pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) -> GeneratedSource {
+    let xml = std::fs::read_to_string(vk_xml).expect("vk.xml");
+    let xml = normalize_east_const(&xml);
+    let spec2 = vk_parse::parse_stream(xml.as_bytes());
     let extensions: &Vec<vk_parse::Extension> = spec2
         .0
         .iter()
@@ -2271,36 +5972,21 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
         })
         .next()
         .expect("extension");
-    let mut ty_cache = HashSet::new();
-    let aliases: Vec<_> = spec2
-        .0
-        .iter()
-        .filter_map(|item| match item {
-            vk_parse::RegistryChild::Types(ref ty) => {
-                Some(generate_aliases_of_types(ty, &mut ty_cache))
-            }
-            _ => None,
-        })
-        .collect();
-
-    let spec = vk_parse::parse_file_as_vkxml(vk_xml);
-    let cmd_aliases: HashMap<String, String> = spec2
+    let platforms: &[vk_parse::Platform] = spec2
         .0
         .iter()
         .filter_map(|item| match item {
-            vk_parse::RegistryChild::Commands(cmds) => {
-                let cmd_tuple_iter = cmds.children.iter().filter_map(|cmd| match cmd {
-                    vk_parse::Command::Alias { name, alias } => {
-                        Some((name.to_string(), alias.to_string()))
-                    }
-                    _ => None,
-                });
-                Some(cmd_tuple_iter)
-            }
+            vk_parse::RegistryChild::Platforms(ref platforms) => Some(&platforms.children[..]),
             _ => None,
         })
-        .flatten()
+        .next()
+        .unwrap_or_default();
+    let spec = vk_parse::parse_stream_as_vkxml(xml.as_bytes());
+    let cmd_aliases: HashMap<String, String> = command_items(&spec2)
+        .into_iter()
+        .filter_map(|cmd| Some((cmd.name.to_string(), cmd.alias_of?.to_string())))
         .collect();
+    let pfn_cfgs = pfn_cfgs(&spec2, &cmd_aliases);
 
     let commands: HashMap<vkxml::Identifier, &vkxml::Command> = spec
         .elements
@@ -2361,6 +6047,12 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
     let mut bitflags_cache = HashSet::new();
     let mut const_cache = HashSet::new();
 
+    check_reserved_ranges(&reserved_ranges(&spec2), all_enumerants(&enums, extensions))
+        .unwrap_or_else(|err| panic!("{}", err));
+    // Tools cache the generated output by `ItemId`, colliding ids would make them reuse the output
+    // of another item instead of regenerating it.
+    check_item_ids(&collect_items(&spec)).unwrap_or_else(|err| panic!("{}", err));
+
     let mut const_values: BTreeMap<Ident, Vec<ConstantMatchInfo>> = BTreeMap::new();
 
     let (enum_code, bitflags_code) = enums
@@ -2374,46 +6066,73 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
             acc
         });
 
-    let mut constants_code: Vec<_> = constants
-        .iter()
-        .map(|constant| generate_constant(constant, &mut const_cache))
-        .collect();
+    let mut constants_code =
+        generate_constants(&constants, &mut const_cache).unwrap_or_else(|err| panic!("{}", err));
 
     constants_code.push(quote! { pub const SHADER_UNUSED_NV : u32 = SHADER_UNUSED_KHR;});
 
-    let extension_code = extensions
-        .iter()
+    let extension_code = sorted_extensions(extensions)
+        .into_iter()
         .filter_map(|ext| {
             generate_extension(
                 ext,
+                platforms,
                 &commands,
                 &mut const_cache,
                 &mut const_values,
                 &cmd_aliases,
+                &pfn_cfgs,
                 &mut fn_cache,
             )
         })
         .collect_vec();
 
-    let union_types = definitions
+    let extension_table = generate_extension_table(extensions);
+
+    let handles: Vec<_> = definitions
         .iter()
         .filter_map(|def| match def {
-            vkxml::DefinitionsElement::Union(ref union) => Some(union.name.as_str()),
+            vkxml::DefinitionsElement::Handle(ref handle) => Some(handle),
             _ => None,
         })
-        .collect::<HashSet<&str>>();
-
-    let root_names = root_struct_names(&definitions);
-    let definition_code: Vec<_> = definitions
-        .into_iter()
-        .filter_map(|def| {
-            generate_definition(
-                def,
-                &union_types,
-                &root_names,
-                &mut bitflags_cache,
-                &mut const_values,
-            )
+        .collect();
+    let object_type_code = generate_object_type_table(&handles);
+
+    let type_aliases = type_aliases(&spec2);
+    let versions = gated_versions(
+        &definition_versions(&features),
+        &definitions,
+        &commands,
+        extensions,
+        &type_aliases,
+        &cmd_aliases,
+    );
+    let mut ty_cache = HashSet::new();
+    let aliases: Vec<_> = spec2
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref ty) => {
+                Some(generate_aliases_of_types(ty, &versions, &mut ty_cache))
+            }
+            _ => None,
+        })
+        .collect();
+    let definition_code = generate_definitions(
+        &definitions,
+        &array_sizes(&constants),
+        &versions,
+        &type_aliases,
+        &base_types(&spec2),
+        &funcpointer_protects(&funcpointer_requires(&spec2), extensions, platforms),
+        &mut bitflags_cache,
+        &mut const_values,
+    );
+    let layout_tests: Vec<_> = [Target::Bits64, Target::Bits32, Target::X86SysV]
+        .iter()
+        .map(|&target| {
+            let layouts = TypeLayouts::new(&definitions, array_sizes(&constants), target);
+            generate_layout_tests(&definitions, &layouts, &versions)
         })
         .collect();
 
@@ -2425,63 +6144,17 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
         generate_feature_extension(&spec2, &mut const_cache, &mut const_values);
 
     let const_debugs = generate_const_debugs(&const_values);
+    let result_error = const_values
+        .get(&Ident::from("Result"))
+        .map(|values| generate_result_error(values));
 
-    let bitflags_macro = vk_bitflags_wrapped_macro();
-    let handle_nondispatchable_macro = handle_nondispatchable_macro();
-    let define_handle_macro = define_handle_macro();
-    let version_macros = vk_version_macros();
     let platform_specific_types = platform_specific_types();
 
-    let ptr_chain_code = quote! {
-        /// Iterates through the pointer chain. Includes the item that is passed into the function.
-        /// Stops at the last `BaseOutStructure` that has a null `p_next` field.
-        pub(crate) unsafe fn ptr_chain_iter<T>(
-            ptr: &mut T,
-        ) -> impl Iterator<Item = *mut BaseOutStructure> {
-            let ptr: *mut BaseOutStructure = ptr as *mut T as _;
-            (0..).scan(ptr, |p_ptr, _| {
-                if p_ptr.is_null() {
-                    return None;
-                }
-                let n_ptr = (**p_ptr).p_next as *mut BaseOutStructure;
-                let old = *p_ptr;
-                *p_ptr = n_ptr;
-                Some(old)
-            })
-        }
-    };
-
-    let macros_code = quote! {
-        #version_macros
-        #bitflags_macro
-        #handle_nondispatchable_macro
-        #define_handle_macro
-    };
-
     let src_dir = src_dir.as_ref();
 
     let vk_dir = src_dir.join("vk");
     std::fs::create_dir_all(&vk_dir).expect("failed to create vk dir");
 
-    let mut vk_rs_file = File::create(src_dir.join("vk.rs")).expect("vk.rs");
-
-    let mut vk_macros_file = File::create(vk_dir.join("macros.rs")).expect("vk/macros.rs");
-    let mut vk_features_file = File::create(vk_dir.join("features.rs")).expect("vk/features.rs");
-    let mut vk_definitions_file =
-        File::create(vk_dir.join("definitions.rs")).expect("vk/definitions.rs");
-    let mut vk_platform_types_file =
-        File::create(vk_dir.join("platform_types.rs")).expect("vk/platform_types.rs");
-    let mut vk_enums_file = File::create(vk_dir.join("enums.rs")).expect("vk/enums.rs");
-    let mut vk_bitflags_file = File::create(vk_dir.join("bitflags.rs")).expect("vk/bitflags.rs");
-    let mut vk_constants_file = File::create(vk_dir.join("constants.rs")).expect("vk/constants.rs");
-    let mut vk_extensions_file =
-        File::create(vk_dir.join("extensions.rs")).expect("vk/extensions.rs");
-    let mut vk_feature_extensions_file =
-        File::create(vk_dir.join("feature_extensions.rs")).expect("vk/feature_extensions.rs");
-    let mut vk_const_debugs_file =
-        File::create(vk_dir.join("const_debugs.rs")).expect("vk/const_debugs.rs");
-    let mut vk_aliases_file = File::create(vk_dir.join("aliases.rs")).expect("vk/aliases.rs");
-
     let feature_code = quote! {
         use std::os::raw::*;
         use crate::vk::bitflags::*;
@@ -2493,18 +6166,21 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
     let definition_code = quote! {
         use std::fmt;
         use std::os::raw::*;
-        use crate::vk::{Handle, ptr_chain_iter};
+        use crate::vk::{Handle, TaggedStructure, ptr_chain_iter};
         use crate::vk::platform_types::*;
         use crate::vk::aliases::*;
         use crate::vk::bitflags::*;
         use crate::vk::constants::*;
         use crate::vk::enums::*;
         #(#definition_code)*
+        #object_type_code
+        #(#layout_tests)*
     };
 
     let enum_code = quote! {
         use std::fmt;
         #(#enum_code)*
+        #result_error
     };
 
     let bitflags_code = quote! {
@@ -2525,6 +6201,7 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
         use crate::vk::definitions::*;
         use crate::vk::enums::*;
         #(#extension_code)*
+        #extension_table
     };
 
     let feature_extensions_code = quote! {
@@ -2553,66 +6230,53 @@ pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
         #platform_specific_types
     };
 
-    // These are defined outside of `quote!` because rustfmt doesn't seem
-    // to format them correctly when they contain extra spaces.
-    let vk_rs_clippy_lints = r#"
-#![allow(clippy::too_many_arguments, clippy::cognitive_complexity, clippy::wrong_self_convention)]
-"#;
-
-    let vk_rs_code = quote! {
-        #[macro_use]
-        mod macros;
-        pub use macros::*;
-        mod aliases;
-        pub use aliases::*;
-        mod bitflags;
-        pub use bitflags::*;
-        mod const_debugs;
-        pub(crate) use const_debugs::*;
-        mod constants;
-        pub use constants::*;
-        mod definitions;
-        pub use definitions::*;
-        mod enums;
-        pub use enums::*;
-        mod extensions;
-        pub use extensions::*;
-        mod feature_extensions;
-        pub use feature_extensions::*;
-        mod features;
-        pub use features::*;
-        mod platform_types;
-        pub use platform_types::*;
-
-        #ptr_chain_code
-
-        pub trait Handle {
-            const TYPE: ObjectType;
-            fn as_raw(self) -> u64;
-            fn from_raw(_: u64) -> Self;
-        }
+    let video_xml = vk_xml.with_file_name("video.xml");
+    let video_code = if video_xml.exists() {
+        let xml = std::fs::read_to_string(&video_xml).expect("video.xml");
+        let xml = normalize_east_const(&xml);
+        Some(
+            generate_video(
+                &vk_parse::parse_stream_as_vkxml(xml.as_bytes()),
+                &vk_parse::parse_stream(xml.as_bytes()),
+            )
+            .unwrap_or_else(|err| panic!("{}", err)),
+        )
+    } else {
+        None
     };
+    let video_module = video_code.as_ref().map(|_| video_module());
 
-    write!(&mut vk_macros_file, "{}", macros_code).expect("Unable to write vk/macros.rs");
-    write!(&mut vk_platform_types_file, "{}", platform_types_code)
-        .expect("Unable to write to vk/platform_types.rs");
-    write!(&mut vk_features_file, "{}", feature_code).expect("Unable to write vk/features.rs");
-    write!(&mut vk_definitions_file, "{}", definition_code)
-        .expect("Unable to write vk/definitions.rs");
-    write!(&mut vk_enums_file, "{}", enum_code).expect("Unable to write vk/enums.rs");
-    write!(&mut vk_bitflags_file, "{}", bitflags_code).expect("Unable to write vk/bitflags.rs");
-    write!(&mut vk_constants_file, "{}", constants_code).expect("Unable to write vk/constants.rs");
-    write!(&mut vk_extensions_file, "{}", extension_code)
-        .expect("Unable to write vk/extensions.rs");
-    write!(
-        &mut vk_feature_extensions_file,
-        "{}",
-        feature_extensions_code
-    )
-    .expect("Unable to write vk/feature_extensions.rs");
-    write!(&mut vk_const_debugs_file, "{}", const_debugs)
-        .expect("Unable to write vk/const_debugs.rs");
-    write!(&mut vk_aliases_file, "{}", aliases).expect("Unable to write vk/aliases.rs");
-    write!(&mut vk_rs_file, "{} {}", vk_rs_clippy_lints, vk_rs_code)
-        .expect("Unable to write vk.rs");
+    let mut files = vec![
+        (vk_dir.join("macros.rs"), vk_macros().to_string()),
+        (
+            vk_dir.join("platform_types.rs"),
+            platform_types_code.to_string(),
+        ),
+        (vk_dir.join("features.rs"), feature_code.to_string()),
+        (vk_dir.join("definitions.rs"), definition_code.to_string()),
+        (vk_dir.join("enums.rs"), enum_code.to_string()),
+        (vk_dir.join("bitflags.rs"), bitflags_code.to_string()),
+        (vk_dir.join("constants.rs"), constants_code.to_string()),
+        (vk_dir.join("extensions.rs"), extension_code.to_string()),
+        (
+            vk_dir.join("feature_extensions.rs"),
+            feature_extensions_code.to_string(),
+        ),
+        (vk_dir.join("const_debugs.rs"), const_debugs.to_string()),
+        (vk_dir.join("aliases.rs"), aliases.to_string()),
+    ];
+    if let Some(video_code) = video_code {
+        files.push((vk_dir.join("video.rs"), video_code.to_string()));
+    }
+    files.push((src_dir.join("vk.rs"), generate_vk_rs(video_module)));
+
+    GeneratedSource {
+        summary: registry_summary(&spec),
+        modules: files.len(),
+        written: files
+            .into_iter()
+            .filter(|(path, code)| write_if_changed(path, code))
+            .map(|(path, _)| path)
+            .collect(),
+    }
 }
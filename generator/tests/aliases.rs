@@ -0,0 +1,84 @@
+mod common;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use generator::{aliases_of, generate_definitions, type_aliases};
+
+const TYPES: &str = r#"
+<types>
+    <type category="struct" name="VkPhysicalDeviceFeatures2">
+        <member><type>VkStructureType</type> <name>sType</name></member>
+        <member><type>void</type>* <name>pNext</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceFeatures2KHR" alias="VkPhysicalDeviceFeatures2"/>
+    <type category="struct" name="VkPhysicalDeviceGlobalPriorityQueryFeatures">
+        <member><type>VkStructureType</type> <name>sType</name></member>
+        <member><type>void</type>* <name>pNext</name></member>
+        <member><type>VkBool32</type> <name>globalPriorityQuery</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR" alias="VkPhysicalDeviceGlobalPriorityQueryFeatures"/>
+    <type category="struct" name="VkPhysicalDeviceGlobalPriorityQueryFeaturesEXT" alias="VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR"/>
+    <type category="bitmask" name="VkFormatFeatureFlags2KHR" alias="VkFormatFeatureFlags2"/>
+</types>
+"#;
+
+#[test]
+fn core_types_list_their_aliases() {
+    let registry = common::registry(TYPES);
+    let aliases = type_aliases(&registry);
+    assert_eq!(
+        aliases.get("VkPhysicalDeviceFeatures2KHR"),
+        Some(&"VkPhysicalDeviceFeatures2")
+    );
+
+    assert_eq!(
+        aliases_of(&aliases, "VkPhysicalDeviceFeatures2"),
+        vec!["VkPhysicalDeviceFeatures2KHR"]
+    );
+    assert_eq!(
+        aliases_of(&aliases, "VkFormatFeatureFlags2"),
+        vec!["VkFormatFeatureFlags2KHR"]
+    );
+    assert_eq!(
+        aliases_of(&aliases, "VkPhysicalDeviceGlobalPriorityQueryFeatures"),
+        vec![
+            "VkPhysicalDeviceGlobalPriorityQueryFeaturesEXT",
+            "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR",
+        ]
+    );
+    // An alias that is aliased itself lists its own aliases too
+    assert_eq!(
+        aliases_of(&aliases, "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR"),
+        vec!["VkPhysicalDeviceGlobalPriorityQueryFeaturesEXT"]
+    );
+    assert!(aliases_of(&aliases, "VkPhysicalDeviceFeatures2KHR").is_empty());
+}
+
+#[test]
+fn promoted_types_document_their_former_names() {
+    let registry = common::registry(TYPES);
+    let aliases = type_aliases(&registry);
+    let vkxml_registry = common::vkxml_registry(TYPES);
+    let definitions = common::definitions(&vkxml_registry);
+    let code = generate_definitions(
+        &definitions,
+        &HashMap::new(),
+        &HashMap::new(),
+        &aliases,
+        &HashMap::new(),
+        &HashMap::new(),
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+    );
+    let source: String = code.iter().map(|tokens| tokens.to_string()).collect();
+    assert!(source.contains(
+        "PhysicalDeviceFeatures2.html>\" ] # [ doc = \"\" ] \
+         # [ doc = \"Also known as: VkPhysicalDeviceFeatures2KHR\" ] \
+         pub struct PhysicalDeviceFeatures2 {"
+    ));
+    assert!(source.contains(
+        "# [ doc = \"Also known as: VkPhysicalDeviceGlobalPriorityQueryFeaturesEXT, \
+         VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR\" ] \
+         pub struct PhysicalDeviceGlobalPriorityQueryFeatures {"
+    ));
+}
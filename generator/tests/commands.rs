@@ -0,0 +1,510 @@
+mod common;
+
+use generator::{
+    command_items, commands_returning, generate_feature, vkxml, CommandExt, CommandItem,
+    DispatchLevel, LenSource,
+};
+use std::collections::HashSet;
+
+const REGISTRY: &str = r#"
+<commands>
+    <command>
+        <proto><type>PFN_vkVoidFunction</type> <name>vkGetDeviceProcAddr</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param len="null-terminated">const <type>char</type>* <name>pName</name></param>
+    </command>
+    <command successcodes="VK_SUCCESS,VK_INCOMPLETE" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+        <proto><type>VkResult</type> <name>vkEnumerateDeviceExtensionProperties</name></proto>
+        <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+        <param optional="true" len="null-terminated">const <type>char</type>* <name>pLayerName</name></param>
+        <param optional="false,true"><type>uint32_t</type>* <name>pPropertyCount</name></param>
+        <param optional="true" len="pPropertyCount"><type>VkExtensionProperties</type>* <name>pProperties</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0" comment="Vulkan core API interface definitions">
+    <require comment="Device commands">
+        <command name="vkGetDeviceProcAddr"/>
+        <command name="vkEnumerateDeviceExtensionProperties"/>
+    </require>
+</feature>
+"#;
+
+fn generate_v1_0() -> String {
+    let registry = common::vkxml_registry(REGISTRY);
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    generate_feature(features[0], &commands, &mut HashSet::new()).to_string()
+}
+
+#[test]
+fn c_string_params_stay_raw_on_tables() {
+    let tokens = generate_v1_0();
+    // Loaders pass `name.as_ptr()` to the function pointers of the tables
+    assert!(tokens.contains(
+        "pub get_device_proc_addr : extern \"system\" fn ( device : Device , p_name : *const c_char , )"
+    ));
+    // The method takes a `&CStr` instead
+    assert!(tokens.contains(
+        "pub unsafe fn get_device_proc_addr ( & self , device : Device , p_name : & :: std :: ffi :: CStr , )"
+    ));
+    assert!(tokens.contains("( self . get_device_proc_addr ) ( device , p_name . as_ptr ( ) , )"));
+    assert_eq!(tokens.matches("pub unsafe fn get_device_proc_addr (").count(), 1);
+    // Optional strings can be null, so they stay raw pointers
+    assert!(tokens.contains("p_layer_name : *const c_char , p_property_count"));
+}
+
+#[test]
+fn enumerate_helper_returns_vec() {
+    let tokens = generate_v1_0();
+    assert!(tokens.contains(
+        "pub unsafe fn enumerate_device_extension_properties ( & self , physical_device : PhysicalDevice , p_layer_name : *const c_char , ) -> VkResult < Vec < ExtensionProperties >>"
+    ));
+    // The helper replaces the method with the C signature
+    assert!(!tokens.contains(
+        "p_property_count : *mut u32 , p_properties : *mut ExtensionProperties , ) -> Result {"
+    ));
+
+    let code = format!(
+        "use std::os::raw::{{c_char, c_void}};
+        use std::sync::atomic::{{AtomicU32, Ordering}};
+        #[derive(Clone, Copy)] pub struct Device(usize);
+        #[derive(Clone, Copy)] pub struct PhysicalDevice(usize);
+        #[derive(Clone, Default, Debug, PartialEq)] pub struct ExtensionProperties(u32);
+        #[derive(Clone, Copy, Debug, PartialEq)] pub struct Result(i32);
+        impl Result {{
+            pub const SUCCESS: Result = Result(0);
+            pub const INCOMPLETE: Result = Result(5);
+            pub fn result(self) -> VkResult<()> {{ self.result_with_success(()) }}
+            pub fn result_with_success<T>(self, value: T) -> VkResult<T> {{
+                if self.0 < 0 {{ Err(self) }} else {{ Ok(value) }}
+            }}
+        }}
+        pub type VkResult<T> = std::result::Result<T, Result>;
+        pub type PFN_vkVoidFunction = Option<unsafe extern \"system\" fn()>;
+        {}
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        // Another extension shows up between the first count and the first fill
+        extern \"system\" fn enumerate(
+            _: PhysicalDevice,
+            _: *const c_char,
+            count: *mut u32,
+            properties: *mut ExtensionProperties,
+        ) -> Result {{
+            let available = if CALLS.fetch_add(1, Ordering::SeqCst) == 0 {{ 1 }} else {{ 2 }};
+            if properties.is_null() {{
+                unsafe {{ *count = available }};
+                return Result::SUCCESS;
+            }}
+            let written = unsafe {{ (*count).min(available) }};
+            for i in 0..written {{
+                unsafe {{ *properties.add(i as usize) = ExtensionProperties(i) }};
+            }}
+            unsafe {{ *count = written }};
+            if written < available {{ Result::INCOMPLETE }} else {{ Result::SUCCESS }}
+        }}
+        #[test]
+        fn enumerate_again_while_incomplete() {{
+            let table = InstanceFnV1_0::load(|name| {{
+                if name.to_bytes() == b\"vkEnumerateDeviceExtensionProperties\" {{
+                    enumerate as *const c_void
+                }} else {{
+                    std::ptr::null()
+                }}
+            }});
+            let properties = unsafe {{
+                table.enumerate_device_extension_properties(PhysicalDevice(0), std::ptr::null())
+            }};
+            assert_eq!(
+                properties,
+                Ok(vec![ExtensionProperties(0), ExtensionProperties(1)])
+            );
+            assert_eq!(CALLS.load(Ordering::SeqCst), 4);
+        }}",
+        tokens
+    );
+    assert!(common::run_tests("enumerate_helper", &code));
+}
+
+const DESCRIPTOR_COMMANDS: &str = r#"
+<commands>
+    <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY,VK_ERROR_OUT_OF_DEVICE_MEMORY">
+        <proto><type>VkResult</type> <name>vkAllocateCommandBuffers</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param>const <type>VkCommandBufferAllocateInfo</type>* <name>pAllocateInfo</name></param>
+        <param len="pAllocateInfo-&gt;commandBufferCount"><type>VkCommandBuffer</type>* <name>pCommandBuffers</name></param>
+    </command>
+    <command>
+        <proto><type>void</type> <name>vkUpdateDescriptorSets</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param optional="true"><type>uint32_t</type> <name>descriptorWriteCount</name></param>
+        <param len="descriptorWriteCount">const <type>VkWriteDescriptorSet</type>* <name>pDescriptorWrites</name></param>
+        <param optional="true"><type>uint32_t</type> <name>descriptorCopyCount</name></param>
+        <param len="descriptorCopyCount">const <type>VkCopyDescriptorSet</type>* <name>pDescriptorCopies</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0" comment="Vulkan core API interface definitions">
+    <require comment="Device commands">
+        <command name="vkAllocateCommandBuffers"/>
+        <command name="vkUpdateDescriptorSets"/>
+    </require>
+</feature>
+"#;
+
+#[test]
+fn allocate_command_buffers_length_pairs() {
+    let registry = common::vkxml_registry(DESCRIPTOR_COMMANDS);
+    let commands = common::commands(&registry);
+    assert_eq!(
+        commands["vkAllocateCommandBuffers"].length_pairs(),
+        vec![(2, LenSource::Member(1, "commandBufferCount".to_string()))]
+    );
+    assert_eq!(
+        commands["vkUpdateDescriptorSets"].length_pairs(),
+        vec![(2, LenSource::Param(1)), (4, LenSource::Param(3))]
+    );
+}
+
+#[test]
+fn count_and_data_params_are_slices() {
+    let registry = common::vkxml_registry(DESCRIPTOR_COMMANDS);
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    let tokens = generate_feature(features[0], &commands, &mut HashSet::new()).to_string();
+    assert!(tokens.contains(
+        "pub unsafe fn update_descriptor_sets ( & self , device : Device , p_descriptor_writes : & [ WriteDescriptorSet ] , p_descriptor_copies : & [ CopyDescriptorSet ] , ) -> c_void"
+    ));
+    assert!(tokens.contains(
+        "( self . update_descriptor_sets ) ( device , p_descriptor_writes . len ( ) as _ , p_descriptor_writes . as_ptr ( ) , p_descriptor_copies . len ( ) as _ , p_descriptor_copies . as_ptr ( ) , )"
+    ));
+    // Only the function pointer keeps the C signature
+    assert!(tokens.contains("pub update_descriptor_sets : extern \"system\" fn ( device : Device , descriptor_write_count : u32 ,"));
+    assert_eq!(tokens.matches("pub unsafe fn update_descriptor_sets (").count(), 1);
+
+    assert!(tokens.contains(
+        "pub unsafe fn allocate_command_buffers ( & self , device : Device , p_allocate_info : *const CommandBufferAllocateInfo , ) -> VkResult < Vec < CommandBuffer >>"
+    ));
+    assert!(tokens.contains(
+        "let mut p_command_buffers = vec ! [ CommandBuffer :: default ( ) ; ( * p_allocate_info ) . command_buffer_count as usize ] ;"
+    ));
+}
+
+#[test]
+fn slices_sharing_a_count_are_length_checked() {
+    let registry = common::vkxml_registry(
+        r#"
+        <commands>
+            <command>
+                <proto><type>void</type> <name>vkCmdBindVertexBuffers</name></proto>
+                <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                <param><type>uint32_t</type> <name>firstBinding</name></param>
+                <param><type>uint32_t</type> <name>bindingCount</name></param>
+                <param len="bindingCount">const <type>VkBuffer</type>* <name>pBuffers</name></param>
+                <param len="bindingCount">const <type>VkDeviceSize</type>* <name>pOffsets</name></param>
+            </command>
+        </commands>
+        <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+            <require>
+                <command name="vkCmdBindVertexBuffers"/>
+            </require>
+        </feature>
+        "#,
+    );
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    let tokens = generate_feature(features[0], &commands, &mut HashSet::new()).to_string();
+    assert!(tokens.contains(
+        "# [ doc = \"\" ] # [ doc = \"# Panics\" ] # [ doc = \"\" ] \
+         # [ doc = \"Panics if the lengths of `p_buffers`, `p_offsets` differ.\" ] \
+         pub unsafe fn cmd_bind_vertex_buffers ( & self , command_buffer : CommandBuffer , first_binding : u32 , p_buffers : & [ Buffer ] , p_offsets : & [ DeviceSize ] , ) -> c_void { \
+         assert_eq ! ( p_buffers . len ( ) , p_offsets . len ( ) ) ; \
+         ( self . cmd_bind_vertex_buffers ) ( command_buffer , first_binding , p_buffers . len ( ) as _ , p_buffers . as_ptr ( ) , p_offsets . as_ptr ( ) , ) }"
+    ));
+}
+
+const DRAW_COMMANDS: &str = r#"
+<commands>
+    <command queues="graphics" renderpass="inside" cmdbufferlevel="primary,secondary">
+        <proto><type>void</type> <name>vkCmdDraw</name></proto>
+        <param externsync="true"><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+        <param><type>uint32_t</type> <name>vertexCount</name></param>
+        <param><type>uint32_t</type> <name>instanceCount</name></param>
+        <param><type>uint32_t</type> <name>firstVertex</name></param>
+        <param><type>uint32_t</type> <name>firstInstance</name></param>
+    </command>
+    <command queues="graphics,compute,transfer" renderpass="outside" cmdbufferlevel="primary,secondary">
+        <proto><type>void</type> <name>vkCmdCopyBuffer</name></proto>
+        <param externsync="true"><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+        <param><type>VkBuffer</type> <name>srcBuffer</name></param>
+        <param><type>VkBuffer</type> <name>dstBuffer</name></param>
+        <param><type>uint32_t</type> <name>regionCount</name></param>
+        <param len="regionCount">const <type>VkBufferCopy</type>* <name>pRegions</name></param>
+    </command>
+    <command>
+        <proto><type>void</type> <name>vkDestroyBuffer</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param optional="true" externsync="true"><type>VkBuffer</type> <name>buffer</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+    <require>
+        <command name="vkCmdDraw"/>
+        <command name="vkCmdCopyBuffer"/>
+        <command name="vkDestroyBuffer"/>
+    </require>
+</feature>
+"#;
+
+#[test]
+fn command_capabilities() {
+    let registry = common::vkxml_registry(DRAW_COMMANDS);
+    let commands = common::commands(&registry);
+
+    let draw = commands["vkCmdDraw"].capabilities();
+    assert_eq!(draw.queues, vec!["graphics"]);
+    assert!(matches!(draw.render_pass, Some(vkxml::Renderpass::Inside)));
+    assert_eq!(draw.command_buffer_levels, vec!["primary", "secondary"]);
+
+    let copy = commands["vkCmdCopyBuffer"].capabilities();
+    assert_eq!(copy.queues, vec!["graphics", "compute", "transfer"]);
+    assert!(matches!(copy.render_pass, Some(vkxml::Renderpass::Outside)));
+
+    let destroy = commands["vkDestroyBuffer"].capabilities();
+    assert!(destroy.queues.is_empty());
+    assert!(destroy.render_pass.is_none());
+    assert!(destroy.command_buffer_levels.is_empty());
+}
+
+#[test]
+fn command_capability_docs() {
+    let registry = common::vkxml_registry(DRAW_COMMANDS);
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    let tokens = generate_feature(features[0], &commands, &mut HashSet::new()).to_string();
+    assert!(tokens.contains(
+        "# [ doc = \"Supported queue types: graphics\" ] # [ doc = \"Must be called inside a render pass\" ] # [ doc = \"Supported command buffer levels: primary, secondary\" ] pub unsafe fn cmd_draw"
+    ));
+    assert!(tokens.contains(
+        "# [ doc = \"Supported queue types: graphics, compute, transfer\" ] # [ doc = \"Must be called outside of a render pass\" ]"
+    ));
+    assert!(tokens.contains("vkDestroyBuffer.html>\" ] pub unsafe fn destroy_buffer"));
+    // Nothing to wrap, the method keeps the C signature
+    assert_eq!(tokens.matches("pub unsafe fn cmd_draw (").count(), 1);
+}
+
+#[test]
+fn commands_returning_device_lost() {
+    let registry = common::vkxml_registry(
+        r#"
+        <commands>
+            <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY,VK_ERROR_OUT_OF_DEVICE_MEMORY,VK_ERROR_DEVICE_LOST">
+                <proto><type>VkResult</type> <name>vkQueueSubmit</name></proto>
+                <param externsync="true"><type>VkQueue</type> <name>queue</name></param>
+                <param optional="true"><type>uint32_t</type> <name>submitCount</name></param>
+                <param len="submitCount">const <type>VkSubmitInfo</type>* <name>pSubmits</name></param>
+                <param optional="true" externsync="true"><type>VkFence</type> <name>fence</name></param>
+            </command>
+            <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY,VK_ERROR_OUT_OF_DEVICE_MEMORY,VK_ERROR_DEVICE_LOST">
+                <proto><type>VkResult</type> <name>vkDeviceWaitIdle</name></proto>
+                <param><type>VkDevice</type> <name>device</name></param>
+            </command>
+            <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY,VK_ERROR_OUT_OF_DEVICE_MEMORY">
+                <proto><type>VkResult</type> <name>vkCreateFence</name></proto>
+                <param><type>VkDevice</type> <name>device</name></param>
+                <param>const <type>VkFenceCreateInfo</type>* <name>pCreateInfo</name></param>
+                <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                <param><type>VkFence</type>* <name>pFence</name></param>
+            </command>
+            <command>
+                <proto><type>void</type> <name>vkDestroyFence</name></proto>
+                <param><type>VkDevice</type> <name>device</name></param>
+                <param optional="true" externsync="true"><type>VkFence</type> <name>fence</name></param>
+                <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+            </command>
+        </commands>
+        "#,
+    );
+    let commands = common::commands(&registry);
+    assert_eq!(
+        commands_returning(&commands, "VK_ERROR_DEVICE_LOST"),
+        vec!["vkDeviceWaitIdle", "vkQueueSubmit"]
+    );
+    assert_eq!(
+        commands_returning(&commands, "VK_ERROR_OUT_OF_HOST_MEMORY"),
+        vec!["vkCreateFence", "vkDeviceWaitIdle", "vkQueueSubmit"]
+    );
+    assert!(commands_returning(&commands, "VK_ERROR_SURFACE_LOST_KHR").is_empty());
+}
+
+const DISPATCH_COMMANDS: &str = r#"
+<commands>
+    <command>
+        <proto><type>PFN_vkVoidFunction</type> <name>vkGetInstanceProcAddr</name></proto>
+        <param optional="true"><type>VkInstance</type> <name>instance</name></param>
+        <param len="null-terminated">const <type>char</type>* <name>pName</name></param>
+    </command>
+    <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+        <proto><type>VkResult</type> <name>vkCreateInstance</name></proto>
+        <param>const <type>VkInstanceCreateInfo</type>* <name>pCreateInfo</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+        <param><type>VkInstance</type>* <name>pInstance</name></param>
+    </command>
+    <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+        <proto><type>VkResult</type> <name>vkCreateDevice</name></proto>
+        <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+        <param>const <type>VkDeviceCreateInfo</type>* <name>pCreateInfo</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+        <param><type>VkDevice</type>* <name>pDevice</name></param>
+    </command>
+    <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+        <proto><type>VkResult</type> <name>vkCreateBuffer</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param>const <type>VkBufferCreateInfo</type>* <name>pCreateInfo</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+        <param><type>VkBuffer</type>* <name>pBuffer</name></param>
+    </command>
+    <command queues="graphics,compute">
+        <proto><type>void</type> <name>vkCmdBeginDebugUtilsLabelEXT</name></proto>
+        <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+        <param>const <type>VkDebugUtilsLabelEXT</type>* <name>pLabelInfo</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+    <require>
+        <command name="vkGetInstanceProcAddr"/>
+        <command name="vkCreateInstance"/>
+        <command name="vkCreateDevice"/>
+        <command name="vkCreateBuffer"/>
+    </require>
+</feature>
+<extensions>
+    <extension name="VK_EXT_debug_utils" number="129" type="instance" supported="vulkan">
+        <require>
+            <command name="vkCmdBeginDebugUtilsLabelEXT"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+
+#[test]
+fn command_dispatch_levels() {
+    let vkxml_registry = common::vkxml_registry(DISPATCH_COMMANDS);
+    let registry = common::registry(DISPATCH_COMMANDS);
+    let commands = common::commands(&vkxml_registry);
+    let level = |name: &str| commands[name].dispatch_level(None);
+    assert_eq!(level("vkGetInstanceProcAddr"), DispatchLevel::Entry);
+    assert_eq!(level("vkCreateInstance"), DispatchLevel::Entry);
+    assert_eq!(level("vkCreateDevice"), DispatchLevel::Instance);
+    assert_eq!(level("vkCreateBuffer"), DispatchLevel::Device);
+
+    let debug_utils = common::find_extension(&registry, "VK_EXT_debug_utils");
+    assert_eq!(
+        commands["vkCmdBeginDebugUtilsLabelEXT"].dispatch_level(Some(debug_utils)),
+        DispatchLevel::Instance
+    );
+}
+
+#[test]
+fn commands_are_routed_by_dispatch_level() {
+    let registry = common::vkxml_registry(DISPATCH_COMMANDS);
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    let tokens = generate_feature(features[0], &commands, &mut HashSet::new()).to_string();
+    let table = |name: &str| {
+        let start = tokens
+            .find(&format!("pub struct {} ", name))
+            .unwrap_or_else(|| panic!("{} is missing", name));
+        let end = tokens[start + 1..]
+            .find("pub struct ")
+            .map_or(tokens.len(), |end| start + 1 + end);
+        &tokens[start..end]
+    };
+    let has_create_buffer = |name: &str| table(name).contains("pub unsafe fn create_buffer (");
+    assert!(has_create_buffer("DeviceFnV1_0"));
+    assert!(!has_create_buffer("InstanceFnV1_0"));
+    assert!(!has_create_buffer("EntryFnV1_0"));
+    assert!(table("InstanceFnV1_0").contains("pub unsafe fn create_device ("));
+    assert!(table("EntryFnV1_0").contains("pub unsafe fn create_instance ("));
+    assert!(table("StaticFn").contains("pub unsafe fn get_instance_proc_addr ("));
+}
+
+#[test]
+fn loaders_holding_tables_are_clone() {
+    let registry = common::vkxml_registry(DISPATCH_COMMANDS);
+    let commands = common::commands(&registry);
+    let features = common::features(&registry);
+    let tokens = generate_feature(features[0], &commands, &mut HashSet::new()).to_string();
+    assert!(tokens.contains("# [ derive ( Clone , Copy ) ] pub struct DeviceFnV1_0 {"));
+
+    let code = format!(
+        "#![allow(non_camel_case_types)]
+        use std::os::raw::{{c_char, c_void}};
+        #[derive(Clone, Copy)] pub struct Instance(usize);
+        #[derive(Clone, Copy)] pub struct PhysicalDevice(usize);
+        #[derive(Clone, Copy)] pub struct Device(usize);
+        #[derive(Clone, Copy)] pub struct Buffer(u64);
+        pub struct Result(i32);
+        pub struct AllocationCallbacks;
+        pub struct InstanceCreateInfo;
+        pub struct DeviceCreateInfo;
+        pub struct BufferCreateInfo;
+        pub type PFN_vkVoidFunction = Option<unsafe extern \"system\" fn()>;
+        {}
+        #[derive(Clone)]
+        pub struct DeviceLoader {{
+            handle: Device,
+            fp: DeviceFnV1_0,
+        }}
+        #[test]
+        fn clone() {{
+            let loader = DeviceLoader {{
+                handle: Device(1),
+                fp: DeviceFnV1_0::load(|_| std::ptr::null()),
+            }};
+            let copy = loader.clone();
+            assert_eq!(copy.handle.0, 1);
+            assert_eq!(copy.fp.create_buffer as usize, loader.fp.create_buffer as usize);
+        }}",
+        tokens
+    );
+    assert!(common::run_tests("loader_clone", &code));
+}
+
+#[test]
+fn command_aliases_resolve_to_their_core_command() {
+    let registry = common::registry(
+        r#"
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkGetPhysicalDeviceFeatures2</name></proto>
+        <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+        <param><type>VkPhysicalDeviceFeatures2</type>* <name>pFeatures</name></param>
+    </command>
+    <command name="vkGetPhysicalDeviceFeatures2KHR" alias="vkGetPhysicalDeviceFeatures2"/>
+    <command name="vkGetPhysicalDeviceFeatures2EXT" alias="vkGetPhysicalDeviceFeatures2KHR"/>
+</commands>
+"#,
+    );
+    let items = command_items(&registry);
+    assert_eq!(
+        items,
+        vec![
+            CommandItem {
+                name: "vkGetPhysicalDeviceFeatures2",
+                alias_of: None,
+            },
+            CommandItem {
+                name: "vkGetPhysicalDeviceFeatures2KHR",
+                alias_of: Some("vkGetPhysicalDeviceFeatures2"),
+            },
+            CommandItem {
+                name: "vkGetPhysicalDeviceFeatures2EXT",
+                alias_of: Some("vkGetPhysicalDeviceFeatures2"),
+            },
+        ]
+    );
+    assert!(!items[0].is_alias());
+    assert!(items[1].is_alias());
+    assert_eq!(items[0].canonical_name(), "vkGetPhysicalDeviceFeatures2");
+    assert_eq!(items[1].canonical_name(), "vkGetPhysicalDeviceFeatures2");
+}
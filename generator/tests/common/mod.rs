@@ -0,0 +1,210 @@
+//! Helpers to run the generator against small, hand written registries instead of the full
+//! `vk.xml`.
+#![allow(dead_code)]
+
+use generator::{vk_parse, vkxml};
+use std::collections::{HashMap, HashSet};
+use std::process::Command;
+
+fn wrap(body: &str) -> String {
+    format!("<registry>{}</registry>", body)
+}
+
+pub fn registry(body: &str) -> vk_parse::Registry {
+    vk_parse::parse_stream(wrap(body).as_bytes())
+}
+
+pub fn vkxml_registry(body: &str) -> vkxml::Registry {
+    vk_parse::parse_stream_as_vkxml(wrap(body).as_bytes())
+}
+
+pub fn definitions(registry: &vkxml::Registry) -> Vec<&vkxml::DefinitionsElement> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Definitions(ref definitions) => Some(definitions),
+            _ => None,
+        })
+        .flat_map(|definitions| definitions.elements.iter())
+        .collect()
+}
+
+pub fn find_struct<'a>(registry: &'a vkxml::Registry, name: &str) -> &'a vkxml::Struct {
+    definitions(registry)
+        .into_iter()
+        .filter_map(|definition| match definition {
+            vkxml::DefinitionsElement::Struct(ref _struct) => Some(_struct),
+            _ => None,
+        })
+        .find(|_struct| _struct.name == name)
+        .expect("struct not found")
+}
+
+/// Generates `_struct` on its own, without any of the other definitions of the registry.
+pub fn generate_struct(_struct: &vkxml::Struct) -> String {
+    generator::generate_struct(
+        _struct,
+        &HashMap::new(),
+        &HashSet::new(),
+        &HashMap::new(),
+        &HashSet::new(),
+        &[],
+    )
+    .to_string()
+}
+
+pub fn extensions(registry: &vk_parse::Registry) -> &[vk_parse::Extension] {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Extensions(ref ext) => Some(&ext.children[..]),
+            _ => None,
+        })
+        .next()
+        .expect("extensions")
+}
+
+pub fn find_extension<'a>(registry: &'a vk_parse::Registry, name: &str) -> &'a vk_parse::Extension {
+    extensions(registry)
+        .iter()
+        .find(|ext| ext.name == name)
+        .expect("extension not found")
+}
+
+pub fn commands(registry: &vkxml::Registry) -> generator::CommandMap<'_> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Commands(ref cmds) => Some(cmds),
+            _ => None,
+        })
+        .flat_map(|cmds| cmds.elements.iter().map(|cmd| (cmd.name.clone(), cmd)))
+        .collect()
+}
+
+pub fn features(registry: &vkxml::Registry) -> Vec<&vkxml::Feature> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Features(ref features) => Some(features),
+            _ => None,
+        })
+        .flat_map(|features| features.elements.iter())
+        .collect()
+}
+
+pub fn platforms(registry: &vk_parse::Registry) -> &[vk_parse::Platform] {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Platforms(ref platforms) => Some(&platforms.children[..]),
+            _ => None,
+        })
+        .next()
+        .unwrap_or_default()
+}
+
+pub fn enums(registry: &vkxml::Registry) -> Vec<&vkxml::Enumeration> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Enums(ref enums) => Some(enums),
+            _ => None,
+        })
+        .flat_map(|enums| {
+            enums.elements.iter().filter_map(|_enum| match *_enum {
+                vkxml::EnumsElement::Enumeration(ref e) => Some(e),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+pub fn constants(registry: &vkxml::Registry) -> Vec<&vkxml::Constant> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Constants(ref constants) => Some(constants),
+            _ => None,
+        })
+        .flat_map(|constants| constants.elements.iter())
+        .collect()
+}
+
+pub fn find_enums<'a>(registry: &'a vk_parse::Registry, name: &str) -> &'a vk_parse::Enums {
+    registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Enums(ref enums) => Some(enums),
+            _ => None,
+        })
+        .find(|enums| enums.name.as_deref() == Some(name))
+        .expect("enums not found")
+}
+
+pub fn types(registry: &vk_parse::Registry) -> &vk_parse::Types {
+    registry
+        .0
+        .iter()
+        .find_map(|item| match item {
+            vk_parse::RegistryChild::Types(ref types) => Some(types),
+            _ => None,
+        })
+        .expect("types not found")
+}
+
+/// Compiles generated `code` as a test crate with `rustc` and runs it. Returns true if it compiled
+/// and all of its tests passed.
+pub fn run_tests(name: &str, code: &str) -> bool {
+    run_tests_with_features(name, code, &[])
+}
+
+/// Like [`run_tests`], with the cargo `features` enabled.
+pub fn run_tests_with_features(name: &str, code: &str, features: &[&str]) -> bool {
+    let dir = std::env::temp_dir().join(format!("ash-generator-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let source = dir.join(format!("{}.rs", name));
+    let binary = dir.join(name);
+    std::fs::write(&source, code).unwrap();
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let compiled = Command::new(rustc)
+        .args(["--edition", "2018", "--test", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .args(
+            features
+                .iter()
+                .flat_map(|feature| vec!["--cfg".to_string(), format!("feature=\"{}\"", feature)]),
+        )
+        .arg(&source)
+        .status()
+        .unwrap();
+    let passed = compiled.success() && Command::new(&binary).status().unwrap().success();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    passed
+}
+
+/// Returns the features that `ash/Cargo.toml` declares, the generated code can only be gated on
+/// those.
+pub fn ash_features() -> Vec<String> {
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/../ash/Cargo.toml");
+    let manifest = std::fs::read_to_string(manifest).unwrap();
+    manifest
+        .lines()
+        .skip_while(|line| *line != "[features]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter(|line| line.contains(" = "))
+        .filter_map(|line| line.split(" = ").next())
+        .map(|feature| feature.to_string())
+        .collect()
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use generator::{constant_order, generate_constants, vkxml, Constant};
+use std::collections::HashSet;
+
+const REGISTRY: &str = r#"
+<enums name="API Constants" comment="Vulkan hardcoded constants - not an enumerated type, part of the header boilerplate">
+    <enum value="256" name="VK_MAX_EXTENSION_NAME_SIZE"/>
+    <enum value="(~0U)" name="VK_REMAINING_MIP_LEVELS"/>
+    <enum value="1000.0f" name="VK_LOD_CLAMP_NONE"/>
+    <enum value="(VK_MAX_EXTENSION_NAME_SIZE - 1)" name="VK_MAX_EXTENSION_NAME_LENGTH"/>
+</enums>
+"#;
+
+fn constants(registry: &vkxml::Registry) -> Vec<&vkxml::Constant> {
+    registry
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::RegistryElement::Constants(ref constants) => Some(constants),
+            _ => None,
+        })
+        .flat_map(|constants| constants.elements.iter())
+        .collect()
+}
+
+#[test]
+fn constants_list_the_constants_they_reference() {
+    let registry = common::vkxml_registry(REGISTRY);
+    let references: Vec<_> = constants(&registry)
+        .into_iter()
+        .map(|constant| {
+            let value = Constant::from_constant(constant);
+            let references: Vec<String> = value
+                .referenced_constants()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            (constant.name.as_str(), references)
+        })
+        .collect();
+    assert_eq!(
+        references,
+        vec![
+            ("VK_MAX_EXTENSION_NAME_SIZE", vec![]),
+            ("VK_REMAINING_MIP_LEVELS", vec![]),
+            ("VK_LOD_CLAMP_NONE", vec![]),
+            (
+                "VK_MAX_EXTENSION_NAME_LENGTH",
+                vec!["VK_MAX_EXTENSION_NAME_SIZE".to_string()]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn constants_follow_the_constants_they_reference() {
+    let registry = common::vkxml_registry(
+        r#"
+<enums name="API Constants" comment="Vulkan hardcoded constants - not an enumerated type, part of the header boilerplate">
+    <enum value="(VK_MAX_EXTENSION_NAME_SIZE - 1)" name="VK_MAX_EXTENSION_NAME_LENGTH"/>
+    <enum value="(~0U)" name="VK_REMAINING_MIP_LEVELS"/>
+    <enum value="256" name="VK_MAX_EXTENSION_NAME_SIZE"/>
+</enums>
+"#,
+    );
+    let constants = constants(&registry);
+    let order: Vec<_> = constant_order(&constants)
+        .into_iter()
+        .map(|constant| constant.name.as_str())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            "VK_MAX_EXTENSION_NAME_SIZE",
+            "VK_MAX_EXTENSION_NAME_LENGTH",
+            "VK_REMAINING_MIP_LEVELS",
+        ]
+    );
+
+    let code = generate_constants(&constants, &mut HashSet::new()).unwrap();
+    let source: String = code.iter().map(|tokens| tokens.to_string()).collect();
+    let file = syn::parse_file(&source).expect("constants are valid Rust");
+    assert_eq!(file.items.len(), 3);
+    assert!(code[1].to_string().ends_with(
+        "pub const MAX_EXTENSION_NAME_LENGTH : usize = (MAX_EXTENSION_NAME_SIZE - 1) ;"
+    ));
+}
+
+#[test]
+fn unknown_constant_references_are_errors() {
+    let registry = common::vkxml_registry(
+        r#"
+<enums name="API Constants" comment="Vulkan hardcoded constants - not an enumerated type, part of the header boilerplate">
+    <enum value="(VK_MAX_DEVICE_GROUP_SIZE - 1)" name="VK_MAX_DEVICE_GROUP_INDEX"/>
+</enums>
+"#,
+    );
+    let constants = constants(&registry);
+    assert_eq!(
+        generate_constants(&constants, &mut HashSet::new()),
+        Err(
+            "VK_MAX_DEVICE_GROUP_INDEX refers to unknown constant VK_MAX_DEVICE_GROUP_SIZE"
+                .to_string()
+        )
+    );
+}
@@ -0,0 +1,367 @@
+mod common;
+
+use generator::{
+    all_enumerants, check_reserved_ranges, enum_backing_type, generate_const_debugs, generate_enum,
+    generate_extension_constants, generate_result_error, name_to_tokens, reserved_ranges, vk_parse,
+    EnumLiteral, EnumType, EnumsExt,
+};
+use std::collections::{BTreeMap, HashSet};
+
+const ENUMS: &str = r#"
+<enums name="VkImageLayout" type="enum">
+    <enum value="0" name="VK_IMAGE_LAYOUT_UNDEFINED"/>
+    <enum value="1" name="VK_IMAGE_LAYOUT_GENERAL"/>
+    <enum value="2" name="VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL"/>
+</enums>
+<enums name="VkImageUsageFlagBits" type="bitmask">
+    <enum bitpos="4" name="VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT"/>
+</enums>
+<extensions>
+    <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+        <require>
+            <enum offset="2" extends="VkImageLayout" name="VK_IMAGE_LAYOUT_PRESENT_SRC_KHR"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+
+#[test]
+fn enumerants_are_found_under_their_enum() {
+    let vkxml_registry = common::vkxml_registry(ENUMS);
+    let registry = common::registry(ENUMS);
+    let enums = common::enums(&vkxml_registry);
+    let enumerants: Vec<_> = all_enumerants(&enums, common::extensions(&registry)).collect();
+
+    let find = |name: &str| {
+        enumerants
+            .iter()
+            .find(|&&(_, enumerant, _)| enumerant == name)
+            .copied()
+    };
+    assert_eq!(
+        find("VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL"),
+        Some((
+            "VkImageLayout",
+            "VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL",
+            2
+        ))
+    );
+    assert_eq!(
+        find("VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT"),
+        Some((
+            "VkImageUsageFlagBits",
+            "VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT",
+            16
+        ))
+    );
+    assert_eq!(
+        find("VK_IMAGE_LAYOUT_PRESENT_SRC_KHR"),
+        Some((
+            "VkImageLayout",
+            "VK_IMAGE_LAYOUT_PRESENT_SRC_KHR",
+            1_000_001_002
+        ))
+    );
+}
+
+#[test]
+fn only_defined_flag_combinations() {
+    let registry = common::vkxml_registry(
+        r#"
+        <enums name="VkCullModeFlagBits" type="bitmask">
+            <enum value="0" name="VK_CULL_MODE_NONE"/>
+            <enum bitpos="0" name="VK_CULL_MODE_FRONT_BIT"/>
+            <enum bitpos="1" name="VK_CULL_MODE_BACK_BIT"/>
+            <enum value="0x00000003" name="VK_CULL_MODE_FRONT_AND_BACK"/>
+        </enums>
+        <enums name="VkColorComponentFlagBits" type="bitmask">
+            <enum bitpos="0" name="VK_COLOR_COMPONENT_R_BIT"/>
+            <enum bitpos="1" name="VK_COLOR_COMPONENT_G_BIT"/>
+            <enum bitpos="2" name="VK_COLOR_COMPONENT_B_BIT"/>
+            <enum bitpos="3" name="VK_COLOR_COMPONENT_A_BIT"/>
+        </enums>
+        <extensions></extensions>
+        "#,
+    );
+    let generate = |name: &str| {
+        let _enum = common::enums(&registry)
+            .into_iter()
+            .find(|_enum| _enum.name == name)
+            .expect("enum not found");
+        match generate_enum(
+            _enum,
+            &mut HashSet::new(),
+            &mut BTreeMap::new(),
+            &mut HashSet::new(),
+        ) {
+            EnumType::Bitflags(tokens) => tokens.to_string(),
+            EnumType::Enum(_) => panic!("{} is not a bitmask", name),
+        }
+    };
+    let constants = |tokens: &str| tokens.matches("pub const ").count();
+
+    let cull_mode = generate("VkCullModeFlagBits");
+    assert!(cull_mode.contains("pub const FRONT_AND_BACK : Self = CullModeFlags ( 0x0000_0003 )"));
+    assert_eq!(constants(&cull_mode), 4);
+
+    let color_component = generate("VkColorComponentFlagBits");
+    for bit in &["R", "G", "B", "A"] {
+        assert!(color_component.contains(&format!("pub const {} : Self", bit)));
+    }
+    assert_eq!(constants(&color_component), 4);
+}
+
+#[test]
+fn enums_reserved_range() {
+    let registry = common::registry(
+        r#"
+        <enums name="VkPerformanceCounterScopeKHR" type="enum" start="1000116000" end="1000116999">
+            <enum value="0" name="VK_PERFORMANCE_COUNTER_SCOPE_COMMAND_BUFFER_KHR"/>
+        </enums>
+        "#,
+    );
+    let scope = common::find_enums(&registry, "VkPerformanceCounterScopeKHR");
+    assert_eq!(scope.reserved_range(), Some((1_000_116_000, 1_000_116_999)));
+
+    let registry = common::registry(ENUMS);
+    let layout = common::find_enums(&registry, "VkImageLayout");
+    assert_eq!(layout.reserved_range(), None);
+}
+
+#[test]
+fn enumerant_outside_of_reserved_range() {
+    const RESERVED: &str = r#"
+    <enums name="VkBlendOverlapEXT" type="enum" start="0" end="2">
+        <enum value="0" name="VK_BLEND_OVERLAP_UNCORRELATED_EXT"/>
+        <enum value="1" name="VK_BLEND_OVERLAP_DISJOINT_EXT"/>
+    </enums>
+    <extensions>
+        <extension name="VK_EXT_blend_operation_advanced" number="149" type="device" supported="vulkan">
+            <require>
+                <enum offset="0" extends="VkBlendOverlapEXT" name="VK_BLEND_OVERLAP_SYNTHETIC_EXT"/>
+            </require>
+        </extension>
+    </extensions>
+    "#;
+    let vkxml_registry = common::vkxml_registry(RESERVED);
+    let registry = common::registry(RESERVED);
+    let enums = common::enums(&vkxml_registry);
+    let ranges = reserved_ranges(&registry);
+    assert_eq!(ranges["VkBlendOverlapEXT"], (0, 2));
+
+    let core_only = all_enumerants(&enums, &[]);
+    assert_eq!(check_reserved_ranges(&ranges, core_only), Ok(()));
+
+    let extended = all_enumerants(&enums, common::extensions(&registry));
+    assert_eq!(
+        check_reserved_ranges(&ranges, extended),
+        Err(
+            "VK_BLEND_OVERLAP_SYNTHETIC_EXT = 1000148000 is outside of the reserved range 0..=2 of VkBlendOverlapEXT"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn result_error_codes() {
+    const RESULTS: &str = r#"
+    <enums name="VkResult" type="enum">
+        <enum value="0" name="VK_SUCCESS"/>
+        <enum value="5" name="VK_INCOMPLETE"/>
+        <enum value="-1" name="VK_ERROR_OUT_OF_HOST_MEMORY"/>
+        <enum value="-4" name="VK_ERROR_DEVICE_LOST"/>
+    </enums>
+    <extensions>
+        <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan">
+            <require>
+                <enum offset="0" extends="VkResult" dir="-" name="VK_ERROR_SURFACE_LOST_KHR"/>
+            </require>
+        </extension>
+    </extensions>
+    "#;
+    let vkxml_registry = common::vkxml_registry(RESULTS);
+    let registry = common::registry(RESULTS);
+    let mut const_values = BTreeMap::new();
+    let result = match generate_enum(
+        common::enums(&vkxml_registry)[0],
+        &mut HashSet::new(),
+        &mut const_values,
+        &mut HashSet::new(),
+    ) {
+        EnumType::Enum(tokens) => tokens.to_string(),
+        EnumType::Bitflags(_) => panic!("VkResult is not an enum"),
+    };
+    let surface = &common::extensions(&registry)[0];
+    let surface_constants = generate_extension_constants(
+        &surface.name,
+        surface.number.unwrap(),
+        &surface.children,
+        None,
+        None,
+        &mut HashSet::new(),
+        &mut const_values,
+    )
+    .to_string();
+
+    let tokens = generate_result_error(&const_values[&name_to_tokens("VkResult")]).to_string();
+    assert!(
+        tokens.contains("pub enum Error { OUT_OF_HOST_MEMORY , DEVICE_LOST , SURFACE_LOST_KHR , ")
+    );
+    assert!(tokens.contains("pub type VkResult < T > = :: std :: result :: Result < T , Error > ;"));
+    assert!(tokens.contains("pub fn result ( self ) -> VkResult < ( ) >"));
+
+    let code = [
+        "use std::fmt;".to_string(),
+        "pub type Flags = u32;".to_string(),
+        result,
+        surface_constants,
+        generate_const_debugs(&const_values).to_string(),
+        tokens,
+        r#"
+        #[test]
+        fn result() {
+            assert_eq!(Result::SUCCESS.result(), Ok(()));
+            // Success codes are not errors
+            assert_eq!(Result::INCOMPLETE.result_with_success(5), Ok(5));
+            assert_eq!(Result::INCOMPLETE.error(), None);
+            assert_eq!(Result::ERROR_DEVICE_LOST.result(), Err(Error::DEVICE_LOST));
+            assert_eq!(
+                Result::ERROR_DEVICE_LOST.result_with_success(5),
+                Err(Error::DEVICE_LOST)
+            );
+            assert_eq!(Result::ERROR_DEVICE_LOST.error(), Some(Error::DEVICE_LOST));
+            assert_eq!(
+                Result::ERROR_SURFACE_LOST_KHR.error(),
+                Some(Error::SURFACE_LOST_KHR)
+            );
+            assert_eq!(Result::from(Error::SURFACE_LOST_KHR), Result::ERROR_SURFACE_LOST_KHR);
+            // Codes that are newer than the bindings
+            let unknown = Result::from_raw(-1_000_999_000);
+            assert_eq!(unknown.result(), Err(Error::Unknown(unknown)));
+            assert_eq!(unknown.error(), Some(Error::Unknown(unknown)));
+        }
+        "#
+        .to_string(),
+    ];
+    assert!(common::run_tests("result_error", &code.join("\n")));
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    let vkxml_registry = common::vkxml_registry(ENUMS);
+    let image_layout = common::enums(&vkxml_registry)
+        .into_iter()
+        .find(|_enum| _enum.name == "VkImageLayout")
+        .expect("enum not found");
+    let mut const_values = BTreeMap::new();
+    let tokens = match generate_enum(
+        image_layout,
+        &mut HashSet::new(),
+        &mut const_values,
+        &mut HashSet::new(),
+    ) {
+        EnumType::Enum(tokens) => tokens.to_string(),
+        EnumType::Bitflags(_) => panic!("VkImageLayout is not an enum"),
+    };
+    assert!(tokens.contains("pub struct ImageLayout ( pub ( crate ) i32 ) ;"));
+
+    // Drivers may return values that are newer than the bindings
+    let code = [
+        "use std::fmt;".to_string(),
+        "pub type Flags = u32;".to_string(),
+        tokens,
+        generate_const_debugs(&const_values).to_string(),
+        r#"
+        #[test]
+        fn unknown_value() {
+            let layout = ImageLayout::from_raw(1_000_999_000);
+            assert_eq!(layout.as_raw(), 1_000_999_000);
+            assert_ne!(layout, ImageLayout::GENERAL);
+            assert_eq!(format!("{:?}", layout), "1000999000");
+            assert_eq!(format!("{:?}", ImageLayout::from_raw(1)), "GENERAL");
+        }
+        "#
+        .to_string(),
+    ];
+    assert!(common::run_tests("unknown_enum_value", &code.join("\n")));
+}
+
+#[test]
+fn enums_are_sized_by_their_backing_type() {
+    let registry = common::vkxml_registry(ENUMS);
+    let enums = common::enums(&registry);
+    let find = |name: &str| *enums.iter().find(|e| e.name == name).unwrap();
+    let layout = find("VkImageLayout");
+    let usage = find("VkImageUsageFlagBits");
+    assert_eq!(enum_backing_type(layout).as_ref(), "i32");
+    assert_eq!(enum_backing_type(usage).as_ref(), "Flags");
+
+    let generate = |_enum| {
+        let mut const_cache = HashSet::new();
+        let mut const_values = BTreeMap::new();
+        let mut bitflags_cache = HashSet::new();
+        match generate_enum(
+            _enum,
+            &mut const_cache,
+            &mut const_values,
+            &mut bitflags_cache,
+        ) {
+            EnumType::Enum(tokens) | EnumType::Bitflags(tokens) => tokens.to_string(),
+        }
+    };
+    let code = generate(layout);
+    assert!(code.contains("# [ repr ( transparent ) ]"));
+    assert!(code.contains("pub struct ImageLayout ( pub ( crate ) i32 )"));
+    assert!(code.contains("pub const fn from_raw ( x : i32 ) -> Self"));
+    assert!(generate(usage).contains("pub struct ImageUsageFlags ( pub ( crate ) Flags )"));
+}
+
+#[test]
+fn enum_values_are_typed() {
+    let registry = common::registry(
+        r#"
+        <enums name="VkImageLayout" type="enum">
+            <enum value="0" name="VK_IMAGE_LAYOUT_UNDEFINED"/>
+        </enums>
+        <extensions>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+                <require>
+                    <enum value="70" name="VK_KHR_SWAPCHAIN_SPEC_VERSION"/>
+                    <enum value="&quot;VK_KHR_swapchain&quot;" name="VK_KHR_SWAPCHAIN_EXTENSION_NAME"/>
+                    <enum value="VK_IMAGE_LAYOUT_UNDEFINED" name="VK_IMAGE_LAYOUT_NONE_KHR"/>
+                </require>
+            </extension>
+        </extensions>
+        "#,
+    );
+    let literals: Vec<_> = common::extensions(&registry)[0]
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|item| match item {
+            vk_parse::InterfaceItem::Enum(_enum) => match _enum.spec {
+                vk_parse::EnumSpec::Value { ref value, .. } => {
+                    Some((_enum.name.as_str(), EnumLiteral::parse(value)))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        literals,
+        [
+            ("VK_KHR_SWAPCHAIN_SPEC_VERSION", Some(EnumLiteral::Int(70))),
+            (
+                "VK_KHR_SWAPCHAIN_EXTENSION_NAME",
+                Some(EnumLiteral::Str("VK_KHR_swapchain".to_string()))
+            ),
+            ("VK_IMAGE_LAYOUT_NONE_KHR", None),
+        ]
+    );
+    assert_eq!(EnumLiteral::parse("-1"), Some(EnumLiteral::Int(-1)));
+}
@@ -0,0 +1,755 @@
+mod common;
+
+use generator::{
+    command_extensions, command_items, extension_constants, funcpointer_protects,
+    funcpointer_requires, generate_const_debugs, generate_extension, generate_extension_constants,
+    generate_extension_strings, generate_extension_table, generate_funcptr, pfn_cfgs, platform_cfg,
+    platform_protects, promotions_into, sorted_extensions, vk_parse, vkxml, EnumLiteral,
+    ExtensionExt,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const EXTENSIONS: &str = r#"
+<platforms comment="Vulkan platform names, reserved for use with platform- and window-system-specific extensions">
+    <platform name="xlib" protect="VK_USE_PLATFORM_XLIB_KHR" comment="X Window System, Xlib client library"/>
+    <platform name="win32" protect="VK_USE_PLATFORM_WIN32_KHR" comment="Microsoft Win32 API (also refers to Win64 apps)"/>
+</platforms>
+<extensions>
+    <extension name="VK_KHR_swapchain" number="2" type="device" requires="VK_KHR_surface" supported="vulkan">
+        <require>
+            <enum value="70" name="VK_KHR_SWAPCHAIN_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_swapchain&quot;" name="VK_KHR_SWAPCHAIN_EXTENSION_NAME"/>
+        </require>
+    </extension>
+    <extension name="VK_KHR_win32_surface" number="10" type="instance" requires="VK_KHR_surface" platform="win32" supported="vulkan">
+        <require>
+            <enum value="6" name="VK_KHR_WIN32_SURFACE_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_win32_surface&quot;" name="VK_KHR_WIN32_SURFACE_EXTENSION_NAME"/>
+            <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR"/>
+        </require>
+    </extension>
+    <extension name="VK_EXT_debug_report" number="12" type="instance" supported="vulkan" deprecatedby="VK_EXT_debug_utils" specialuse="debugging">
+        <require>
+            <enum value="9" name="VK_EXT_DEBUG_REPORT_SPEC_VERSION"/>
+            <enum value="&quot;VK_EXT_debug_report&quot;" name="VK_EXT_DEBUG_REPORT_EXTENSION_NAME"/>
+        </require>
+    </extension>
+    <extension name="VK_KHR_xlib_surface" number="5" type="instance" platform="xlib" supported="disabled">
+        <require>
+            <enum value="6" name="VK_KHR_XLIB_SURFACE_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_xlib_surface&quot;" name="VK_KHR_XLIB_SURFACE_EXTENSION_NAME"/>
+        </require>
+    </extension>
+    <extension name="VK_KHR_maintenance1" number="70" type="device" supported="vulkan" promotedto="VK_VERSION_1_1">
+        <require>
+            <enum value="2" name="VK_KHR_MAINTENANCE1_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_maintenance1&quot;" name="VK_KHR_MAINTENANCE1_EXTENSION_NAME"/>
+        </require>
+    </extension>
+    <extension name="VK_EXT_debug_utils" number="129" type="instance" supported="vulkan" specialuse="debugging">
+        <require>
+            <enum value="2" name="VK_EXT_DEBUG_UTILS_SPEC_VERSION"/>
+            <enum value="&quot;VK_EXT_debug_utils&quot;" name="VK_EXT_DEBUG_UTILS_EXTENSION_NAME"/>
+        </require>
+    </extension>
+    <extension name="VK_KHR_portability_subset" number="164" type="device" requires="VK_KHR_get_physical_device_properties2" provisional="true" supported="vulkan">
+        <require>
+            <enum value="1" name="VK_KHR_PORTABILITY_SUBSET_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_portability_subset&quot;" name="VK_KHR_PORTABILITY_SUBSET_EXTENSION_NAME"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+
+#[test]
+fn debugging_extension_special_use() {
+    let registry = common::registry(EXTENSIONS);
+    let debug_utils = common::find_extension(&registry, "VK_EXT_debug_utils");
+    assert_eq!(debug_utils.special_use(), vec!["debugging"]);
+    let swapchain = common::find_extension(&registry, "VK_KHR_swapchain");
+    assert!(swapchain.special_use().is_empty());
+}
+
+fn generate(registry: &vk_parse::Registry, name: &str) -> String {
+    generate_extension(
+        common::find_extension(registry, name),
+        common::platforms(registry),
+        &HashMap::new(),
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+        &HashMap::new(),
+        &HashMap::new(),
+        &mut HashSet::new(),
+    )
+    .expect("extension is reserved")
+    .to_string()
+}
+
+#[test]
+fn special_use_and_deprecated_extension_attributes() {
+    let registry = common::registry(EXTENSIONS);
+    let debug_utils = generate(&registry, "VK_EXT_debug_utils");
+    assert!(debug_utils.contains("special use: debugging\" ] pub struct ExtDebugUtilsFn"));
+    assert!(!debug_utils.contains("deprecated"));
+
+    let debug_report = generate(&registry, "VK_EXT_debug_report");
+    assert!(debug_report.contains(
+        "# [ deprecated ( note = \"Deprecated by VK_EXT_debug_utils\" ) ] pub struct ExtDebugReportFn"
+    ));
+
+    let swapchain = generate(&registry, "VK_KHR_swapchain");
+    assert!(!swapchain.contains("special use"));
+    assert!(!swapchain.contains("deprecated"));
+}
+
+#[test]
+fn provisional_extension_is_feature_gated() {
+    let registry = common::registry(EXTENSIONS);
+    let portability_subset = common::find_extension(&registry, "VK_KHR_portability_subset");
+    assert!(portability_subset.is_provisional());
+    assert!(!common::find_extension(&registry, "VK_KHR_swapchain").is_provisional());
+
+    let tokens = generate(&registry, "VK_KHR_portability_subset");
+    assert!(tokens.contains(
+        "# [ cfg ( feature = \"provisional\" ) ] # [ derive ( Clone , Copy ) ] # [ doc = \"⚠️ provisional — API may change\" ] pub struct KhrPortabilitySubsetFn"
+    ));
+    assert!(tokens.contains("# [ cfg ( feature = \"provisional\" ) ] impl KhrPortabilitySubsetFn"));
+    assert!(common::ash_features().contains(&"provisional".to_string()));
+
+    let swapchain = generate(&registry, "VK_KHR_swapchain");
+    assert!(!swapchain.contains("provisional"));
+}
+
+#[test]
+fn provisional_extension_doc() {
+    let registry = common::registry(EXTENSIONS);
+    let tokens = generate(&registry, "VK_KHR_portability_subset");
+    assert!(tokens.contains(
+        "# [ doc = \"⚠️ provisional — API may change\" ] pub struct KhrPortabilitySubsetFn"
+    ));
+
+    let debug_utils = generate(&registry, "VK_EXT_debug_utils");
+    assert!(!debug_utils.contains("provisional"));
+}
+
+#[test]
+fn platform_specific_extension_constant() {
+    let registry = common::registry(EXTENSIONS);
+    let win32_surface = common::find_extension(&registry, "VK_KHR_win32_surface");
+    let protect = win32_surface.platform_protect(common::platforms(&registry));
+    assert_eq!(protect, Some("VK_USE_PLATFORM_WIN32_KHR"));
+    let constants = extension_constants(
+        win32_surface.number.unwrap(),
+        &win32_surface.children,
+        protect,
+        None,
+    );
+    assert_eq!(constants.len(), 1);
+    let constant = &constants[0];
+    assert_eq!(
+        constant.name,
+        "VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR"
+    );
+    assert_eq!(constant.extends, "VkStructureType");
+    assert_eq!(constant.protect, Some("VK_USE_PLATFORM_WIN32_KHR"));
+    assert_eq!(constant.constant.value().unwrap().bits(), 1_000_009_000);
+}
+
+#[test]
+fn platform_specific_variant_is_cfg_gated() {
+    let registry = common::registry(EXTENSIONS);
+    let win32_surface = common::find_extension(&registry, "VK_KHR_win32_surface");
+    let mut const_values = BTreeMap::new();
+    const_values.insert(generator::name_to_tokens("VkStructureType"), Vec::new());
+    let tokens = generate_extension_constants(
+        &win32_surface.name,
+        win32_surface.number.unwrap(),
+        &win32_surface.children,
+        win32_surface.platform_protect(common::platforms(&registry)),
+        None,
+        &mut HashSet::new(),
+        &mut const_values,
+    )
+    .to_string();
+    assert!(tokens.contains(
+        "# [ cfg ( feature = \"VK_USE_PLATFORM_WIN32_KHR\" ) ] pub const WIN32_SURFACE_CREATE_INFO_KHR"
+    ));
+    assert!(tokens.contains("] impl StructureType {"));
+    assert!(!tokens.contains("cfg ( feature = \"VK_USE_PLATFORM_WIN32_KHR\" ) ] impl"));
+
+    let debugs = generate_const_debugs(&const_values).to_string();
+    assert!(debugs.contains(
+        "# [ cfg ( feature = \"VK_USE_PLATFORM_WIN32_KHR\" ) ] Self :: WIN32_SURFACE_CREATE_INFO_KHR"
+    ));
+}
+
+#[test]
+fn constants_of_deprecated_extensions_are_deprecated() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_EXT_debug_report" number="12" type="instance" deprecatedby="VK_EXT_debug_utils" supported="vulkan">
+                <require>
+                    <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT"/>
+                    <enum extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_REPORT_CREATE_INFO_EXT" alias="VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT" comment="Backwards-compatible alias containing a typo"/>
+                </require>
+            </extension>
+            <extension name="VK_MVK_ios_surface" number="123" type="instance" obsoletedby="VK_EXT_metal_surface" supported="vulkan">
+                <require>
+                    <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_IOS_SURFACE_CREATE_INFO_MVK"/>
+                </require>
+            </extension>
+            <extension name="VK_EXT_debug_utils" number="129" type="instance" supported="vulkan">
+                <require>
+                    <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT"/>
+                </require>
+            </extension>
+        </extensions>
+        "#,
+    );
+    let debug_report = common::find_extension(&registry, "VK_EXT_debug_report");
+    let note = debug_report.deprecation_note();
+    assert_eq!(note.as_deref(), Some("Deprecated by VK_EXT_debug_utils"));
+    let constants = extension_constants(12, &debug_report.children, None, note.as_deref());
+    assert_eq!(constants.len(), 2);
+    for constant in &constants {
+        assert_eq!(
+            constant.deprecated.as_deref(),
+            Some("Deprecated by VK_EXT_debug_utils")
+        );
+    }
+
+    let ios_surface = common::find_extension(&registry, "VK_MVK_ios_surface");
+    assert_eq!(
+        ios_surface.deprecation_note().as_deref(),
+        Some("Obsoleted by VK_EXT_metal_surface")
+    );
+    // The comment of an alias does not deprecate it on its own
+    let debug_utils = common::find_extension(&registry, "VK_EXT_debug_utils");
+    assert_eq!(debug_utils.deprecation_note(), None);
+    let constants = extension_constants(129, &debug_utils.children, None, None);
+    assert_eq!(constants[0].deprecated, None);
+
+    let mut const_values = BTreeMap::new();
+    const_values.insert(generator::name_to_tokens("VkStructureType"), Vec::new());
+    let tokens = generate_extension_constants(
+        &debug_report.name,
+        12,
+        &debug_report.children,
+        None,
+        note.as_deref(),
+        &mut HashSet::new(),
+        &mut const_values,
+    )
+    .to_string();
+    assert!(tokens.contains(
+        "# [ deprecated = \"Deprecated by VK_EXT_debug_utils\" ] pub const DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT"
+    ));
+    assert!(tokens.contains(
+        "# [ deprecated = \"Deprecated by VK_EXT_debug_utils\" ] pub const DEBUG_REPORT_CREATE_INFO_EXT"
+    ));
+}
+
+#[test]
+fn platform_protects_of_supported_extensions() {
+    let registry = common::registry(EXTENSIONS);
+    let protects = platform_protects(common::extensions(&registry), common::platforms(&registry));
+    assert!(protects.contains("VK_USE_PLATFORM_WIN32_KHR"));
+    // VK_KHR_xlib_surface is disabled in this registry
+    assert!(!protects.contains("VK_USE_PLATFORM_XLIB_KHR"));
+    assert_eq!(protects.len(), 1);
+}
+
+#[test]
+fn platform_features_are_declared() {
+    let registry = common::registry(
+        r#"
+        <platforms>
+            <platform name="xlib" protect="VK_USE_PLATFORM_XLIB_KHR"/>
+            <platform name="xlib_xrandr" protect="VK_USE_PLATFORM_XLIB_XRANDR_EXT"/>
+            <platform name="xcb" protect="VK_USE_PLATFORM_XCB_KHR"/>
+            <platform name="wayland" protect="VK_USE_PLATFORM_WAYLAND_KHR"/>
+            <platform name="directfb" protect="VK_USE_PLATFORM_DIRECTFB_EXT"/>
+            <platform name="android" protect="VK_USE_PLATFORM_ANDROID_KHR"/>
+            <platform name="win32" protect="VK_USE_PLATFORM_WIN32_KHR"/>
+            <platform name="vi" protect="VK_USE_PLATFORM_VI_NN"/>
+            <platform name="ios" protect="VK_USE_PLATFORM_IOS_MVK"/>
+            <platform name="macos" protect="VK_USE_PLATFORM_MACOS_MVK"/>
+            <platform name="metal" protect="VK_USE_PLATFORM_METAL_EXT"/>
+            <platform name="fuchsia" protect="VK_USE_PLATFORM_FUCHSIA"/>
+            <platform name="ggp" protect="VK_USE_PLATFORM_GGP"/>
+            <platform name="screen" protect="VK_USE_PLATFORM_SCREEN_QNX"/>
+        </platforms>
+        "#,
+    );
+    let features = common::ash_features();
+    for platform in common::platforms(&registry) {
+        let cfg = platform_cfg(&platform.protect).to_string();
+        assert!(cfg.contains(&format!("\"{}\"", platform.protect)));
+        assert!(
+            features.contains(&platform.protect),
+            "{} is not declared",
+            platform.protect
+        );
+    }
+}
+
+#[test]
+fn extension_for_command() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+                <require>
+                    <command name="vkCreateSwapchainKHR"/>
+                    <command name="vkGetDeviceGroupPresentCapabilitiesKHR"/>
+                </require>
+            </extension>
+            <extension name="VK_KHR_device_group" number="61" type="device" supported="vulkan">
+                <require>
+                    <command name="vkGetDeviceGroupPresentCapabilitiesKHR"/>
+                </require>
+            </extension>
+            <extension name="VK_KHR_dynamic_rendering" number="45" type="device" supported="vulkan">
+                <require>
+                    <command name="vkCmdBeginRenderingKHR"/>
+                    <command name="vkCmdEndRenderingKHR"/>
+                </require>
+            </extension>
+            <extension name="VK_KHR_xlib_surface" number="5" type="instance" supported="disabled">
+                <require>
+                    <command name="vkCreateXlibSurfaceKHR"/>
+                </require>
+            </extension>
+        </extensions>
+        "#,
+    );
+    let table = command_extensions(common::extensions(&registry));
+    assert_eq!(
+        table.get("vkCmdBeginRenderingKHR"),
+        Some(&"VK_KHR_dynamic_rendering")
+    );
+    assert_eq!(
+        table.get("vkGetDeviceGroupPresentCapabilitiesKHR"),
+        Some(&"VK_KHR_swapchain")
+    );
+    assert_eq!(table.get("vkCreateXlibSurfaceKHR"), None);
+    assert_eq!(table.get("vkCreateDevice"), None);
+}
+
+#[test]
+fn extension_sort_order() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan"/>
+            <extension name="VK_EXT_texel_buffer_alignment" number="282" type="device" supported="vulkan" sortorder="1"/>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan"/>
+        </extensions>
+        "#,
+    );
+    let extensions = common::extensions(&registry);
+    let sort_orders: Vec<_> = extensions.iter().map(|ext| ext.sort_order()).collect();
+    assert_eq!(sort_orders, vec![None, Some(1), None]);
+
+    let names: Vec<_> = sorted_extensions(extensions)
+        .iter()
+        .map(|ext| ext.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "VK_KHR_surface",
+            "VK_KHR_swapchain",
+            "VK_EXT_texel_buffer_alignment"
+        ]
+    );
+}
+
+#[test]
+fn extensions_are_ordered_by_number() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan"/>
+            <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan"/>
+        </extensions>
+        "#,
+    );
+    let names: Vec<_> = sorted_extensions(common::extensions(&registry))
+        .iter()
+        .map(|ext| ext.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["VK_KHR_surface", "VK_KHR_swapchain"]);
+}
+
+#[test]
+fn extensions_promoted_into_vulkan_1_1() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_KHR_get_physical_device_properties2" number="60" type="instance" supported="vulkan" promotedto="VK_VERSION_1_1"/>
+            <extension name="VK_KHR_maintenance1" number="70" type="device" supported="vulkan" promotedto="VK_VERSION_1_1"/>
+            <extension name="VK_KHR_16bit_storage" number="84" type="device" supported="vulkan" promotedto="VK_VERSION_1_1"/>
+            <extension name="VK_KHR_draw_indirect_count" number="170" type="device" supported="vulkan" promotedto="VK_VERSION_1_2"/>
+            <extension name="VK_EXT_buffer_device_address" number="245" type="device" supported="vulkan" promotedto="VK_KHR_buffer_device_address"/>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan"/>
+        </extensions>
+        "#,
+    );
+    let extensions = common::extensions(&registry);
+    assert_eq!(
+        promotions_into(extensions, 1, 1),
+        vec![
+            "VK_KHR_get_physical_device_properties2",
+            "VK_KHR_maintenance1",
+            "VK_KHR_16bit_storage"
+        ]
+    );
+    assert_eq!(
+        promotions_into(extensions, 1, 2),
+        vec!["VK_KHR_draw_indirect_count"]
+    );
+    assert!(promotions_into(extensions, 1, 0).is_empty());
+    // Promotions into other extensions are not core promotions
+    assert_eq!(
+        common::find_extension(&registry, "VK_EXT_buffer_device_address").promoted_to(),
+        None
+    );
+}
+
+#[test]
+fn promoted_extension_doc() {
+    let registry = common::registry(EXTENSIONS);
+    let maintenance1 = generate(&registry, "VK_KHR_maintenance1");
+    assert!(maintenance1
+        .contains("# [ doc = \"Promoted to Vulkan 1.1 core\" ] pub struct KhrMaintenance1Fn"));
+
+    let swapchain = generate(&registry, "VK_KHR_swapchain");
+    assert!(!swapchain.contains("Promoted"));
+}
+
+#[test]
+fn feature_requirements() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_KHR_push_descriptor" number="81" type="device" supported="vulkan">
+                <require>
+                    <enum value="2" name="VK_KHR_PUSH_DESCRIPTOR_SPEC_VERSION"/>
+                    <command name="vkCmdPushDescriptorSetKHR"/>
+                </require>
+                <require feature="VK_VERSION_1_1">
+                    <command name="vkCmdPushDescriptorSetWithTemplateKHR"/>
+                </require>
+                <require feature="VK_KHR_descriptor_update_template">
+                    <type name="VkDescriptorUpdateTemplateType"/>
+                    <enum value="1" extends="VkDescriptorUpdateTemplateType" name="VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_PUSH_DESCRIPTORS_KHR"/>
+                    <command name="vkCmdPushDescriptorSetWithTemplateKHR"/>
+                </require>
+            </extension>
+        </extensions>
+        "#,
+    );
+    let push_descriptor = common::find_extension(&registry, "VK_KHR_push_descriptor");
+    let requirements = push_descriptor.feature_requirements();
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(
+        requirements["VK_VERSION_1_1"],
+        vec!["vkCmdPushDescriptorSetWithTemplateKHR"]
+    );
+    assert_eq!(
+        requirements["VK_KHR_descriptor_update_template"],
+        vec![
+            "VkDescriptorUpdateTemplateType",
+            "VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_PUSH_DESCRIPTORS_KHR",
+            "vkCmdPushDescriptorSetWithTemplateKHR"
+        ]
+    );
+
+    let registry = common::registry(EXTENSIONS);
+    let swapchain = common::find_extension(&registry, "VK_KHR_swapchain");
+    assert!(swapchain.feature_requirements().is_empty());
+}
+
+#[test]
+fn extension_names_are_cstr_constants() {
+    let registry = common::registry(EXTENSIONS);
+    let swapchain = common::find_extension(&registry, "VK_KHR_swapchain");
+    let strings = generate_extension_strings(&swapchain.children, &mut HashSet::new()).to_string();
+    assert!(strings.contains(
+        "pub const KHR_SWAPCHAIN_EXTENSION_NAME : & :: std :: ffi :: CStr = \
+         unsafe { :: std :: ffi :: CStr :: from_bytes_with_nul_unchecked ( b\"VK_KHR_swapchain\\0\" ) } ;"
+    ));
+    assert!(!strings.contains("SPEC_VERSION"));
+    assert!(generate(&registry, "VK_KHR_swapchain").contains(&strings));
+
+    let code = format!(
+        "{}\n#[test] fn name() {{ assert_eq!(KHR_SWAPCHAIN_EXTENSION_NAME.to_str(), Ok(\"VK_KHR_swapchain\")); }}",
+        strings
+    );
+    assert!(common::run_tests("extension_names", &code));
+}
+
+#[test]
+fn provided_items_of_an_extension() {
+    let registry = common::registry(
+        r#"
+        <extensions>
+            <extension name="VK_EXT_debug_utils" number="129" type="instance" supported="vulkan">
+                <require>
+                    <enum value="2" name="VK_EXT_DEBUG_UTILS_SPEC_VERSION"/>
+                    <enum value="&quot;VK_EXT_debug_utils&quot;" name="VK_EXT_DEBUG_UTILS_EXTENSION_NAME"/>
+                        <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT"/>
+                        <enum offset="3" extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT"/>
+                        <type name="VkDebugUtilsLabelEXT"/>
+                        <type name="VkDebugUtilsObjectNameInfoEXT"/>
+                        <command name="vkSetDebugUtilsObjectNameEXT"/>
+                        <command name="vkCmdBeginDebugUtilsLabelEXT"/>
+                        <command name="vkCmdEndDebugUtilsLabelEXT"/>
+                </require>
+            </extension>
+        </extensions>
+        "#,
+    );
+    let debug_utils = common::find_extension(&registry, "VK_EXT_debug_utils").provided_items();
+    assert_eq!(debug_utils.commands.len(), 3);
+    assert_eq!(debug_utils.commands[0], "vkSetDebugUtilsObjectNameEXT");
+    assert_eq!(
+        debug_utils.constants,
+        [
+            ("VK_EXT_DEBUG_UTILS_SPEC_VERSION", EnumLiteral::Int(2)),
+            (
+                "VK_EXT_DEBUG_UTILS_EXTENSION_NAME",
+                EnumLiteral::Str("VK_EXT_debug_utils".to_string())
+            ),
+        ]
+    );
+    assert_eq!(
+        debug_utils.types,
+        ["VkDebugUtilsLabelEXT", "VkDebugUtilsObjectNameInfoEXT"]
+    );
+    assert_eq!(
+        debug_utils.enum_extensions,
+        [
+            (
+                "VkStructureType",
+                "VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT"
+            ),
+            ("VkStructureType", "VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT"),
+        ]
+    );
+}
+
+#[test]
+fn extension_metadata_lookup() {
+    let registry = common::registry(EXTENSIONS);
+    let table = generate_extension_table(common::extensions(&registry)).to_string();
+    // Disabled extensions are left out.
+    assert!(!table.contains("VK_KHR_xlib_surface"));
+
+    let code = format!(
+        "{}\n#[test] fn lookup() {{
+            assert!(EXTENSIONS.windows(2).all(|pair| pair[0].name < pair[1].name));
+            let swapchain = extension_metadata(\"VK_KHR_swapchain\").unwrap();
+            assert_eq!(swapchain.kind, ExtensionKind::Device);
+            assert_eq!(swapchain.number, 2);
+            assert_eq!(swapchain.spec_version, 70);
+            let debug_utils = extension_metadata(\"VK_EXT_debug_utils\").unwrap();
+            assert_eq!(debug_utils.kind, ExtensionKind::Instance);
+            assert!(extension_metadata(\"VK_KHR_xlib_surface\").is_none());
+        }}",
+        table
+    );
+    assert!(common::run_tests("extension_table", &code));
+}
+
+const PLATFORM_FUNCPOINTERS: &str = r#"
+<platforms>
+    <platform name="win32" protect="VK_USE_PLATFORM_WIN32_KHR" comment="Microsoft Win32 API (also refers to Win64 apps)"/>
+</platforms>
+<types>
+    <type category="struct" name="VkWin32CallbackDataKHR">
+        <member><type>uint32_t</type> <name>value</name></member>
+    </type>
+    <type category="struct" name="VkDebugCallbackDataEXT">
+        <member><type>uint32_t</type> <name>value</name></member>
+    </type>
+    <type category="funcpointer" requires="VkWin32CallbackDataKHR">typedef void (VKAPI_PTR *<name>PFN_vkWin32CallbackKHR</name>)(
+        const <type>VkWin32CallbackDataKHR</type>* pCallbackData);</type>
+    <type category="funcpointer" requires="VkDebugCallbackDataEXT">typedef void (VKAPI_PTR *<name>PFN_vkDebugCallbackEXT</name>)(
+        const <type>VkDebugCallbackDataEXT</type>* pCallbackData);</type>
+    <type category="funcpointer">typedef void (VKAPI_PTR *<name>PFN_vkVoidFunction</name>)(void);</type>
+</types>
+<extensions>
+    <extension name="VK_KHR_win32_callback" number="10" type="instance" platform="win32" supported="vulkan">
+        <require>
+            <type name="VkWin32CallbackDataKHR"/>
+            <type name="PFN_vkWin32CallbackKHR"/>
+        </require>
+    </extension>
+    <extension name="VK_EXT_debug_callback" number="12" type="instance" supported="vulkan">
+        <require>
+            <type name="VkDebugCallbackDataEXT"/>
+            <type name="PFN_vkDebugCallbackEXT"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+
+#[test]
+fn platform_specific_funcpointers_carry_their_requires() {
+    let registry = common::registry(PLATFORM_FUNCPOINTERS);
+    let requires = funcpointer_requires(&registry);
+    let mut expected = HashMap::new();
+    expected.insert("PFN_vkWin32CallbackKHR", "VkWin32CallbackDataKHR");
+    expected.insert("PFN_vkDebugCallbackEXT", "VkDebugCallbackDataEXT");
+    assert_eq!(requires, expected);
+
+    let protects = funcpointer_protects(
+        &requires,
+        common::extensions(&registry),
+        common::platforms(&registry),
+    );
+    let mut expected = HashMap::new();
+    expected.insert("PFN_vkWin32CallbackKHR", "VK_USE_PLATFORM_WIN32_KHR");
+    assert_eq!(protects, expected);
+}
+
+#[test]
+fn win32_funcpointers_are_cfg_gated() {
+    let registry = common::registry(PLATFORM_FUNCPOINTERS);
+    let protects = funcpointer_protects(
+        &funcpointer_requires(&registry),
+        common::extensions(&registry),
+        common::platforms(&registry),
+    );
+    let vkxml_registry = common::vkxml_registry(PLATFORM_FUNCPOINTERS);
+    let funcptrs: Vec<_> = common::definitions(&vkxml_registry)
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::FuncPtr(ref fnptr) => Some(
+                generate_funcptr(fnptr, protects.get(fnptr.name.as_str()).cloned()).to_string(),
+            ),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(funcptrs.len(), 3);
+    assert!(funcptrs[0].starts_with(
+        "# [ cfg ( feature = \"VK_USE_PLATFORM_WIN32_KHR\" ) ] # [ allow ( non_camel_case_types ) ]"
+    ));
+    assert!(funcptrs[0].contains("pub type PFN_vkWin32CallbackKHR"));
+    assert!(!funcptrs[1].contains("cfg"));
+    assert!(!funcptrs[2].contains("cfg"));
+}
+
+#[test]
+fn aliased_commands_reexport_the_canonical_function_pointer() {
+    let xml = r#"
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkGetPhysicalDeviceFeatures2</name></proto>
+        <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+        <param><type>VkPhysicalDeviceFeatures2</type>* <name>pFeatures</name></param>
+    </command>
+    <command name="vkGetPhysicalDeviceFeatures2KHR" alias="vkGetPhysicalDeviceFeatures2"/>
+</commands>
+<extensions>
+    <extension name="VK_KHR_get_physical_device_properties2" number="60" type="instance" supported="vulkan">
+        <require>
+            <command name="vkGetPhysicalDeviceFeatures2KHR"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+    let registry = common::registry(xml);
+    let vkxml_registry = common::vkxml_registry(xml);
+    let cmd_aliases: HashMap<String, String> = command_items(&registry)
+        .into_iter()
+        .filter_map(|cmd| Some((cmd.name.to_string(), cmd.alias_of?.to_string())))
+        .collect();
+    // Another extension already declared the function pointer of the core command
+    let mut fn_cache = HashSet::new();
+    fn_cache.insert("vkGetPhysicalDeviceFeatures2");
+    let tokens = generate_extension(
+        common::find_extension(&registry, "VK_KHR_get_physical_device_properties2"),
+        common::platforms(&registry),
+        &common::commands(&vkxml_registry),
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+        &cmd_aliases,
+        &pfn_cfgs(&registry, &cmd_aliases),
+        &mut fn_cache,
+    )
+    .expect("extension is reserved")
+    .to_string();
+    assert!(tokens.contains(
+        "pub use crate :: vk :: PFN_vkGetPhysicalDeviceFeatures2 as PFN_vkGetPhysicalDeviceFeatures2KHR ;"
+    ));
+    assert!(!tokens.contains("pub type PFN_vkGetPhysicalDeviceFeatures2"));
+    assert!(tokens.contains("pub get_physical_device_features2_khr :"));
+}
+
+#[test]
+fn reexported_function_pointers_keep_the_cfg_of_the_canonical_command() {
+    let xml = r#"
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkCmdDispatchGraphAMDX</name></proto>
+        <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+    </command>
+    <command name="vkCmdDispatchGraphEXT" alias="vkCmdDispatchGraphAMDX"/>
+</commands>
+<extensions>
+    <extension name="VK_AMDX_shader_enqueue" number="135" type="device" supported="vulkan" provisional="true">
+        <require>
+            <command name="vkCmdDispatchGraphAMDX"/>
+        </require>
+    </extension>
+    <extension name="VK_EXT_shader_enqueue" number="136" type="device" supported="vulkan">
+        <require>
+            <command name="vkCmdDispatchGraphEXT"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+    let registry = common::registry(xml);
+    let vkxml_registry = common::vkxml_registry(xml);
+    let cmd_aliases: HashMap<String, String> = command_items(&registry)
+        .into_iter()
+        .filter_map(|cmd| Some((cmd.name.to_string(), cmd.alias_of?.to_string())))
+        .collect();
+    let pfn_cfgs = pfn_cfgs(&registry, &cmd_aliases);
+    assert_eq!(pfn_cfgs.len(), 1);
+    assert_eq!(
+        pfn_cfgs["vkCmdDispatchGraphAMDX"].to_string(),
+        "# [ cfg ( feature = \"provisional\" ) ]"
+    );
+
+    let commands = common::commands(&vkxml_registry);
+    let mut fn_cache = HashSet::new();
+    let mut generate = |name| {
+        generate_extension(
+            common::find_extension(&registry, name),
+            common::platforms(&registry),
+            &commands,
+            &mut HashSet::new(),
+            &mut BTreeMap::new(),
+            &cmd_aliases,
+            &pfn_cfgs,
+            &mut fn_cache,
+        )
+        .expect("extension is reserved")
+        .to_string()
+    };
+    let provisional = generate("VK_AMDX_shader_enqueue");
+    assert!(provisional.contains(
+        "# [ cfg ( feature = \"provisional\" ) ] # [ allow ( non_camel_case_types ) ] \
+         pub type PFN_vkCmdDispatchGraphAMDX"
+    ));
+    let alias = generate("VK_EXT_shader_enqueue");
+    assert!(alias.contains(
+        "# [ cfg ( feature = \"provisional\" ) ] \
+         pub use crate :: vk :: PFN_vkCmdDispatchGraphAMDX as PFN_vkCmdDispatchGraphEXT ;"
+    ));
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use generator::{
+    command_items, definition_versions, feature_cfg, gate_items, gated_versions,
+    generate_aliases_of_types, generate_definitions, generate_extension, generate_feature,
+    generate_struct, pfn_cfgs, type_aliases, FeatureExt, Version,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const FEATURES: &str = r#"
+<feature api="vulkan" name="VK_VERSION_1_2" number="1.2" comment="Vulkan 1.2 core API interface definitions."/>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0" comment="Vulkan core API interface definitions"/>
+<feature api="vulkan" name="VK_VERSION_1_1" number="1.1" comment="Vulkan 1.1 core API interface definitions."/>
+"#;
+
+#[test]
+fn versions_are_ordered() {
+    let registry = common::vkxml_registry(FEATURES);
+    let features = common::features(&registry);
+    let mut versions: Vec<Version> = features.iter().map(|feature| feature.version()).collect();
+    assert!(Version::new(1, 1) < Version::new(1, 2));
+    assert!(Version::new(1, 2) < Version::new(2, 0));
+    versions.sort();
+    assert_eq!(
+        versions,
+        vec![Version::new(1, 0), Version::new(1, 1), Version::new(1, 2)]
+    );
+    assert!(features[0].is_version(1, 2));
+}
+
+#[test]
+fn version_features_are_declared() {
+    let features = common::ash_features();
+    for minor in 1..=3 {
+        let cfg = feature_cfg(Version::new(1, minor)).unwrap().to_string();
+        let feature = format!("V1_{}", minor);
+        assert!(cfg.contains(&format!("\"{}\"", feature)));
+        assert!(features.contains(&feature), "{} is not declared", feature);
+    }
+}
+
+#[test]
+fn newer_struct_is_feature_gated() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkExtent2D">
+                <member><type>uint32_t</type> <name>width</name></member>
+                <member><type>uint32_t</type> <name>height</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceVulkan13Features">
+                <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_FEATURES"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true"><type>void</type>* <name>pNext</name></member>
+                <member><type>VkBool32</type> <name>robustImageAccess</name></member>
+            </type>
+        </types>
+        <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+            <require>
+                <type name="VkExtent2D"/>
+            </require>
+        </feature>
+        <feature api="vulkan" name="VK_VERSION_1_3" number="1.3">
+            <require>
+                <type name="VkExtent2D"/>
+                <type name="VkPhysicalDeviceVulkan13Features"/>
+            </require>
+        </feature>
+        "#,
+    );
+    let versions = definition_versions(&common::features(&registry));
+    assert_eq!(versions["VkExtent2D"], Version::new(1, 0));
+    assert!(feature_cfg(versions["VkExtent2D"]).is_none());
+
+    let version = versions["VkPhysicalDeviceVulkan13Features"];
+    assert_eq!(version, Version::new(1, 3));
+    let cfg = feature_cfg(version).unwrap();
+    assert_eq!(cfg.to_string(), "# [ cfg ( feature = \"V1_3\" ) ]");
+
+    let _struct = common::find_struct(&registry, "VkPhysicalDeviceVulkan13Features");
+    let tokens = generate_struct(
+        _struct,
+        &HashMap::new(),
+        &HashSet::new(),
+        &HashMap::new(),
+        &HashSet::new(),
+        &[],
+    );
+    let gated = gate_items(&cfg, tokens).to_string();
+    assert!(gated.starts_with("# [ cfg ( feature = \"V1_3\" ) ]"));
+    assert!(gated.contains(
+        "# [ cfg ( feature = \"V1_3\" ) ] # [ repr ( transparent ) ] pub struct PhysicalDeviceVulkan13FeaturesBuilder"
+    ));
+    assert!(gated.contains(
+        "# [ cfg ( feature = \"V1_3\" ) ] impl < 'a > PhysicalDeviceVulkan13FeaturesBuilder"
+    ));
+}
+
+#[test]
+fn generated_code_builds_without_version_features() {
+    let xml = r#"
+<types>
+    <type category="struct" name="VkExtent2D">
+        <member><type>uint32_t</type> <name>width</name></member>
+        <member><type>uint32_t</type> <name>height</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceSparseFeatures">
+        <member><type>uint32_t</type> <name>residency</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceFeatures2">
+        <member><type>VkPhysicalDeviceSparseFeatures</type> <name>features</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceFeatures2KHR" alias="VkPhysicalDeviceFeatures2"/>
+    <type category="struct" name="VkPhysicalDeviceProtectedMemoryFeatures">
+        <member><type>uint32_t</type> <name>protectedMemory</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceProtectedMemoryFeaturesKHR" alias="VkPhysicalDeviceProtectedMemoryFeatures"/>
+</types>
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkGetPhysicalDeviceFeatures2</name></proto>
+        <param><type>VkPhysicalDeviceFeatures2</type>* <name>pFeatures</name></param>
+    </command>
+    <command name="vkGetPhysicalDeviceFeatures2KHR" alias="vkGetPhysicalDeviceFeatures2"/>
+    <command>
+        <proto><type>void</type> <name>vkGetPhysicalDeviceProtectedMemory</name></proto>
+        <param><type>VkPhysicalDeviceProtectedMemoryFeatures</type>* <name>pFeatures</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+    <require>
+        <type name="VkExtent2D"/>
+    </require>
+</feature>
+<feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+    <require>
+        <type name="VkPhysicalDeviceSparseFeatures"/>
+        <type name="VkPhysicalDeviceFeatures2"/>
+        <type name="VkPhysicalDeviceProtectedMemoryFeatures"/>
+        <command name="vkGetPhysicalDeviceFeatures2"/>
+        <command name="vkGetPhysicalDeviceProtectedMemory"/>
+    </require>
+</feature>
+<extensions>
+    <extension name="VK_KHR_get_physical_device_properties2" number="60" type="instance" supported="vulkan">
+        <require>
+            <type name="VkPhysicalDeviceFeatures2KHR"/>
+            <command name="vkGetPhysicalDeviceFeatures2KHR"/>
+        </require>
+    </extension>
+</extensions>
+"#;
+    let registry = common::registry(xml);
+    let vkxml_registry = common::vkxml_registry(xml);
+    let definitions = common::definitions(&vkxml_registry);
+    let commands = common::commands(&vkxml_registry);
+    let features = common::features(&vkxml_registry);
+    let extensions = common::extensions(&registry);
+    let aliases = type_aliases(&registry);
+    let cmd_aliases: HashMap<String, String> = command_items(&registry)
+        .into_iter()
+        .filter_map(|cmd| Some((cmd.name.to_string(), cmd.alias_of?.to_string())))
+        .collect();
+
+    // The extension requires `VkPhysicalDeviceFeatures2` through its alias, which keeps its
+    // members available as well
+    let versions = gated_versions(
+        &definition_versions(&features),
+        &definitions,
+        &commands,
+        extensions,
+        &aliases,
+        &cmd_aliases,
+    );
+    assert_eq!(versions.len(), 1);
+    assert_eq!(
+        versions["VkPhysicalDeviceProtectedMemoryFeatures"],
+        Version::new(1, 1)
+    );
+
+    let mut fn_cache = HashSet::new();
+    let definitions_code = generate_definitions(
+        &definitions,
+        &HashMap::new(),
+        &versions,
+        &aliases,
+        &HashMap::new(),
+        &HashMap::new(),
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+    );
+    let aliases_code =
+        generate_aliases_of_types(common::types(&registry), &versions, &mut HashSet::new());
+    assert!(aliases_code.to_string().contains(
+        "# [ cfg ( feature = \"V1_1\" ) ] \
+         pub type PhysicalDeviceProtectedMemoryFeaturesKHR"
+    ));
+    let extension_code = generate_extension(
+        common::find_extension(&registry, "VK_KHR_get_physical_device_properties2"),
+        common::platforms(&registry),
+        &commands,
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+        &cmd_aliases,
+        &pfn_cfgs(&registry, &cmd_aliases),
+        &mut fn_cache,
+    )
+    .expect("extension is reserved");
+    let feature_code: Vec<_> = features
+        .iter()
+        .map(|feature| generate_feature(feature, &commands, &mut fn_cache))
+        .collect();
+    assert!(feature_code[1]
+        .to_string()
+        .starts_with("# [ cfg ( feature = \"V1_1\" ) ]"));
+
+    let code = format!(
+        "pub mod vk {{
+            use std::os::raw::*;
+            pub type PFN_vkVoidFunction = Option<unsafe extern \"system\" fn()>;
+            {}
+            {}
+            {}
+            {}
+        }}
+        #[test]
+        fn extension() {{
+            let features = vk::PhysicalDeviceFeatures2KHR::default();
+            assert_eq!(features.features.residency, 0);
+            let _: Option<vk::PFN_vkGetPhysicalDeviceFeatures2KHR> = None;
+            let _ = vk::KhrGetPhysicalDeviceProperties2Fn::load(|_| std::ptr::null());
+        }}
+        #[cfg(feature = \"V1_1\")]
+        #[test]
+        fn core() {{
+            let _ = vk::PhysicalDeviceProtectedMemoryFeaturesKHR::default();
+            let _ = vk::InstanceFnV1_1::load(|_| std::ptr::null());
+        }}",
+        definitions_code
+            .iter()
+            .map(|tokens| tokens.to_string())
+            .collect::<String>(),
+        aliases_code,
+        extension_code,
+        feature_code
+            .iter()
+            .map(|tokens| tokens.to_string())
+            .collect::<String>(),
+    );
+    assert!(common::run_tests("no_version_features", &code));
+    assert!(common::run_tests_with_features(
+        "version_features",
+        &code,
+        &["V1_1"]
+    ));
+}
@@ -0,0 +1,646 @@
+mod common;
+
+use generator::{
+    base_types, generate_funcptr, generate_typedef, normalize_east_const, parse_declaration, vkxml,
+    ArrayExtent, BaseType, CPrimitive, CType, FieldExt,
+};
+use quote::ToTokens;
+
+const COMMANDS: &str = r#"
+<commands>
+    <command>
+        <proto><type>PFN_vkVoidFunction</type> <name>vkGetDeviceProcAddr</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param len="null-terminated">const <type>char</type>* <name>pName</name></param>
+    </command>
+    <command>
+        <proto><type>void</type> <name>vkCmdUploadBytes</name></proto>
+        <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+        <param><type>uint32_t</type> <name>dataSize</name></param>
+        <param len="dataSize">const <type>uint8_t</type>* <name>pData</name></param>
+    </command>
+    <command>
+        <proto><type>VkResult</type> <name>vkCreateInstance</name></proto>
+        <param>const <type>VkInstanceCreateInfo</type>* <name>pCreateInfo</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+        <param><type>VkInstance</type>* <name>pInstance</name></param>
+    </command>
+</commands>
+"#;
+
+fn param<'a>(registry: &'a vkxml::Registry, command: &str, name: &str) -> &'a vkxml::Field {
+    common::commands(registry)[command]
+        .param
+        .iter()
+        .find(|param| param.name.as_deref() == Some(name))
+        .expect("param not found")
+}
+
+#[test]
+fn c_string_and_byte_params() {
+    let registry = common::vkxml_registry(COMMANDS);
+    assert!(param(&registry, "vkGetDeviceProcAddr", "pName").is_c_string());
+    assert!(!param(&registry, "vkCmdUploadBytes", "pData").is_c_string());
+    assert!(!param(&registry, "vkCreateInstance", "pCreateInfo").is_c_string());
+}
+
+#[test]
+fn declared_names_are_populated() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkClearColorValueHolder">
+                <member><type>float</type> <name>float32</name>[4]</member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="funcpointer">typedef void* (VKAPI_PTR *<name>PFN_vkAllocationFunction</name>)(
+    <type>void</type>*                                       pUserData,
+    <type>size_t</type>                                      size,
+    <type>size_t</type>                                      alignment,
+    <type>VkSystemAllocationScope</type>                     allocationScope);</type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkClearColorValueHolder");
+    let members: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(ref field) => Some(field.param_ident().to_string()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(members, vec!["float32", "p_next"]);
+
+    let registry_cmds = common::vkxml_registry(COMMANDS);
+    assert_eq!(
+        param(&registry_cmds, "vkCreateInstance", "pAllocator")
+            .param_ident()
+            .to_string(),
+        "p_allocator"
+    );
+
+    let fnptr = common::definitions(&registry)
+        .into_iter()
+        .find_map(|def| match def {
+            vkxml::DefinitionsElement::FuncPtr(ref fnptr) => Some(fnptr),
+            _ => None,
+        })
+        .expect("funcpointer");
+    assert_eq!(fnptr.name, "PFN_vkAllocationFunction");
+    let params: Vec<_> = fnptr
+        .param
+        .iter()
+        .map(|field| field.param_ident().to_string())
+        .collect();
+    assert_eq!(
+        params,
+        vec!["p_user_data", "size", "alignment", "allocation_scope"]
+    );
+}
+
+#[test]
+fn named_array_extent() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true">
+                <member><type>uint8_t</type> <name>deviceUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+                <member><type>float</type> <name>color</name>[4]</member>
+                <member><type>uint32_t</type> <name>deviceNodeMask</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkPhysicalDeviceIDProperties");
+    let extents: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(ref field) => Some(field.array_extent()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        extents,
+        vec![
+            Some(ArrayExtent::Constant("VK_UUID_SIZE".to_string())),
+            Some(ArrayExtent::Literal(4)),
+            None,
+        ]
+    );
+
+    let sum = ArrayExtent::parse("VK_MAX_DRIVER_NAME_SIZE + 1");
+    assert_eq!(
+        sum,
+        ArrayExtent::Sum(vec![
+            ArrayExtent::Constant("VK_MAX_DRIVER_NAME_SIZE".to_string()),
+            ArrayExtent::Literal(1),
+        ])
+    );
+    assert_eq!(sum.into_tokens().to_string(), "MAX_DRIVER_NAME_SIZE + 1");
+}
+
+#[test]
+fn east_const_declarations() {
+    let registry = common::vkxml_registry(&normalize_east_const(
+        r#"
+        <types>
+            <type category="struct" name="VkApplicationInfo">
+                <member>const <type>char</type>* <name>pApplicationName</name></member>
+                <member><type>char</type> const* <name>pEngineName</name></member>
+                <member>const <type>char</type>* const* <name>ppEnabledLayerNames</name></member>
+                <member><type>char</type> const * const* <name>ppEnabledExtensionNames</name></member>
+            </type>
+        </types>
+        "#,
+    ));
+    let _struct = common::find_struct(&registry, "VkApplicationInfo");
+    let members: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(ref field) => Some(field),
+            _ => None,
+        })
+        .collect();
+    let type_tokens = |field: &vkxml::Field| field.type_tokens(false).to_string();
+    assert_eq!(type_tokens(members[0]), type_tokens(members[1]));
+    assert!(members[1].is_c_string());
+    assert_eq!(type_tokens(members[2]), type_tokens(members[3]));
+    assert_eq!(type_tokens(members[3]), "*const *const c_char");
+}
+
+#[test]
+fn qualified_member_types() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkInstanceCreateInfo">
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member optional="true">const <type>VkApplicationInfo</type>* <name>pApplicationInfo</name></member>
+                <member><type>uint32_t</type> <name>enabledLayerCount</name></member>
+                <member>const <type>char</type>* const* <name>ppEnabledLayerNames</name></member>
+                <member><type>char</type> <name>layerName</name>[<enum>VK_MAX_EXTENSION_NAME_SIZE</enum>]</member>
+                <member><type>float</type> <name>blendConstants</name>[4]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkInstanceCreateInfo");
+    let types: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(ref field) => {
+                Some(field.qualified_type_tokens().to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            "*const :: std :: os :: raw :: c_void",
+            "*const vk :: ApplicationInfo",
+            "u32",
+            "*const *const :: std :: os :: raw :: c_char",
+            "[ :: std :: os :: raw :: c_char ; vk :: MAX_EXTENSION_NAME_SIZE ]",
+            "[ f32 ; 4 ]",
+        ]
+    );
+}
+
+#[test]
+fn bool32_members() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceFeatures">
+                <member><type>VkBool32</type> <name>robustBufferAccess</name></member>
+                <member><type>uint32_t</type> <name>maxBoundDescriptorSets</name></member>
+                <member><type>VkBool32</type>* <name>pSupported</name></member>
+                <member><type>VkBool32</type> <name>supported</name>[4]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkPhysicalDeviceFeatures");
+    let bools: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(ref field) => Some(field.is_bool32()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(bools, vec![true, false, false, false]);
+}
+
+#[test]
+fn void_funcpointer_params() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="funcpointer">typedef void (VKAPI_PTR *<name>PFN_vkVoidFunction</name>)(void);</type>
+        </types>
+        "#,
+    );
+    let fnptr = common::definitions(&registry)
+        .into_iter()
+        .find_map(|def| match def {
+            vkxml::DefinitionsElement::FuncPtr(ref fnptr) => Some(fnptr),
+            _ => None,
+        })
+        .expect("funcpointer");
+    assert_eq!(fnptr.name, "PFN_vkVoidFunction");
+    assert!(fnptr.param.is_empty());
+    assert_eq!(fnptr.return_type.basetype, "void");
+}
+
+#[test]
+fn funcpointers_are_nullable() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="funcpointer">typedef void* (VKAPI_PTR *<name>PFN_vkAllocationFunction</name>)(
+    <type>void</type>*                                       pUserData,
+    <type>size_t</type>                                      size,
+    <type>size_t</type>                                      alignment,
+    <type>VkSystemAllocationScope</type>                     allocationScope);</type>
+        </types>
+        "#,
+    );
+    let fnptr = common::definitions(&registry)
+        .into_iter()
+        .find_map(|def| match def {
+            vkxml::DefinitionsElement::FuncPtr(ref fnptr) => Some(fnptr),
+            _ => None,
+        })
+        .expect("funcpointer");
+    let tokens = generate_funcptr(fnptr, None).to_string();
+    assert!(tokens.contains(
+        "pub type PFN_vkAllocationFunction = Option < unsafe extern \"system\" fn ( p_user_data : *mut c_void , size : usize , alignment : usize , allocation_scope : SystemAllocationScope ) -> *mut c_void > ;"
+    ));
+}
+
+#[test]
+fn base_types_are_aliases() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="basetype">typedef <type>uint64_t</type> <name>VkDeviceSize</name>;</type>
+            <type category="basetype">typedef <type>uint64_t</type> <name>VkDeviceAddress</name>;</type>
+            <type category="basetype">typedef <type>uint32_t</type> <name>VkBool32</name>;</type>
+        </types>
+        "#,
+    );
+    let typedefs: Vec<_> = common::definitions(&registry)
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Typedef(ref typedef) => {
+                Some(generate_typedef(typedef, None).to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(typedefs.len(), 3);
+    assert!(typedefs[0].ends_with("pub type DeviceSize = u64 ;"));
+    assert!(typedefs[1].ends_with("pub type DeviceAddress = u64 ;"));
+    assert!(typedefs[2].ends_with("pub type Bool32 = u32 ;"));
+}
+
+#[test]
+fn const_array_params() {
+    let registry = common::vkxml_registry(
+        r#"
+        <commands>
+            <command queues="graphics" renderpass="both" cmdbufferlevel="primary,secondary">
+                <proto><type>void</type> <name>vkCmdSetBlendConstants</name></proto>
+                <param externsync="true"><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                <param>const <type>float</type> <name>blendConstants</name>[4]</param>
+            </command>
+            <command>
+                <proto><type>void</type> <name>vkGetBlendConstants</name></proto>
+                <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                <param><type>float</type> <name>blendConstants</name>[4]</param>
+            </command>
+        </commands>
+        "#,
+    );
+    let set = param(&registry, "vkCmdSetBlendConstants", "blendConstants");
+    assert!(set.is_const);
+    assert_eq!(set.array_extent(), Some(ArrayExtent::Literal(4)));
+    assert_eq!(set.type_tokens(true).to_string(), "& [ f32 ; 4 ]");
+    assert_eq!(set.type_tokens(false).to_string(), "[ f32 ; 4 ]");
+
+    let get = param(&registry, "vkGetBlendConstants", "blendConstants");
+    assert!(!get.is_const);
+    assert_eq!(get.type_tokens(true).to_string(), "& mut [ f32 ; 4 ]");
+}
+
+#[test]
+fn pointer_to_array_declarations() {
+    let named = |name: &str| Box::new(CType::Named(name.to_string()));
+    let extent = ArrayExtent::Literal(4);
+
+    let (name, array_of_pointers) = parse_declaration("VkBuffer* buffers[4]").unwrap();
+    assert_eq!(name, "buffers");
+    assert_eq!(
+        array_of_pointers,
+        CType::Array(
+            Box::new(CType::Pointer {
+                is_const: false,
+                pointee: named("VkBuffer"),
+            }),
+            extent.clone(),
+        )
+    );
+
+    let (name, pointer_to_array) = parse_declaration("VkBuffer (*buffers)[4]").unwrap();
+    assert_eq!(name, "buffers");
+    assert_eq!(
+        pointer_to_array,
+        CType::Pointer {
+            is_const: false,
+            pointee: Box::new(CType::Array(named("VkBuffer"), extent)),
+        }
+    );
+    assert_ne!(array_of_pointers, pointer_to_array);
+
+    let (_, ty) = parse_declaration("const float (*matrix)[3][4]").unwrap();
+    assert_eq!(ty.to_tokens().to_string(), "* const [ [ f32 ; 4 ] ; 3 ]");
+    let (name, ty) = parse_declaration("const char* const* ppEnabledLayerNames").unwrap();
+    assert_eq!(name, "ppEnabledLayerNames");
+    assert_eq!(ty.to_tokens().to_string(), "* const * const c_char");
+    assert_eq!(parse_declaration("VkBuffer (*buffers[4]"), None);
+}
+
+#[test]
+fn pointer_return_types() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="funcpointer">typedef void* (VKAPI_PTR *<name>PFN_vkReallocationFunction</name>)(
+    <type>void</type>*                                       pUserData,
+    <type>void</type>*                                       pOriginal,
+    <type>size_t</type>                                      size,
+    <type>size_t</type>                                      alignment,
+    <type>VkSystemAllocationScope</type>                     allocationScope);</type>
+        </types>
+        "#,
+    );
+    let fnptr = common::definitions(&registry)
+        .into_iter()
+        .find_map(|def| match def {
+            vkxml::DefinitionsElement::FuncPtr(ref fnptr) => Some(fnptr),
+            _ => None,
+        })
+        .expect("funcpointer");
+    let c_type = fnptr.return_type.c_type();
+    assert_eq!(
+        c_type,
+        CType::Pointer {
+            is_const: false,
+            pointee: Box::new(CType::Named("void".to_string())),
+        }
+    );
+    let unspaced = |tokens: String| tokens.replace(' ', "");
+    assert_eq!(
+        unspaced(c_type.to_tokens().to_string()),
+        unspaced(fnptr.return_type.type_tokens(true).to_string())
+    );
+
+    let original = &fnptr.param[1];
+    assert_eq!(original.c_type(), c_type);
+}
+
+#[test]
+fn unsized_array_declarations() {
+    assert_eq!(ArrayExtent::parse(""), ArrayExtent::Unsized);
+
+    let (name, ty) = parse_declaration("uint32_t code[]").unwrap();
+    assert_eq!(name, "code");
+    assert_eq!(
+        ty,
+        CType::Pointer {
+            is_const: false,
+            pointee: Box::new(CType::Primitive(CPrimitive::U32)),
+        }
+    );
+    assert_eq!(ty.to_tokens().to_string(), "* mut u32");
+
+    let (_, ty) = parse_declaration("const uint32_t code[]").unwrap();
+    assert_eq!(ty.to_tokens().to_string(), "* const u32");
+
+    let (_, ty) = parse_declaration("const char* names[]").unwrap();
+    assert_eq!(ty.to_tokens().to_string(), "* mut * const c_char");
+
+    let (_, ty) = parse_declaration("float matrix[][4]").unwrap();
+    assert_eq!(ty.to_tokens().to_string(), "* mut [ f32 ; 4 ]");
+
+    let (_, ty) = parse_declaration("const float matrix[][4]").unwrap();
+    assert_eq!(ty.to_tokens().to_string(), "* const [ f32 ; 4 ]");
+
+    // Nothing is known about writes through an unsized array that wasn't lowered
+    let ty = CType::Array(
+        Box::new(CType::Primitive(CPrimitive::U32)),
+        ArrayExtent::Unsized,
+    );
+    assert_eq!(ty.to_tokens().to_string(), "* const u32");
+}
+
+#[test]
+fn fixed_width_primitives() {
+    let (name, ty) = parse_declaration("uint64_t objectHandle").unwrap();
+    assert_eq!(name, "objectHandle");
+    assert_eq!(ty, CType::Primitive(CPrimitive::U64));
+    assert_eq!(ty.to_tokens().to_string(), "u64");
+
+    let (_, ty) = parse_declaration("int32_t offsets[2]").unwrap();
+    assert_eq!(
+        ty,
+        CType::Array(
+            Box::new(CType::Primitive(CPrimitive::I32)),
+            ArrayExtent::Literal(2)
+        )
+    );
+
+    let (_, ty) = parse_declaration("VkDeviceSize size").unwrap();
+    assert_eq!(ty, CType::Named("VkDeviceSize".to_string()));
+}
+
+#[test]
+fn const_pointer_array_declarations() {
+    let (name, ty) = parse_declaration("const uint32_t* const pCounts[4]").unwrap();
+    assert_eq!(name, "pCounts");
+    assert_eq!(
+        ty,
+        CType::Array(
+            Box::new(CType::Pointer {
+                is_const: true,
+                pointee: Box::new(CType::Primitive(CPrimitive::U32)),
+            }),
+            ArrayExtent::Literal(4)
+        )
+    );
+    assert_eq!(ty.to_tokens().to_string(), "[ * const u32 ; 4 ]");
+
+    // The inner pointer is const, the outer one points to it.
+    let (_, ty) = parse_declaration("const uint32_t* const* ppCounts[4]").unwrap();
+    assert_eq!(
+        ty,
+        CType::Array(
+            Box::new(CType::Pointer {
+                is_const: true,
+                pointee: Box::new(CType::Pointer {
+                    is_const: true,
+                    pointee: Box::new(CType::Primitive(CPrimitive::U32)),
+                }),
+            }),
+            ArrayExtent::Literal(4)
+        )
+    );
+    assert_eq!(ty.to_tokens().to_string(), "[ * const * const u32 ; 4 ]");
+}
+
+#[test]
+fn pointer_base_types() {
+    let registry = common::registry(
+        r#"
+        <types>
+            <type category="basetype">typedef <type>uint64_t</type> <name>VkDeviceSize</name>;</type>
+            <type category="basetype">typedef <type>void</type>* <name>VkRemoteAddressNV</name>;</type>
+            <type category="basetype">struct <name>ANativeWindow</name>;</type>
+        </types>
+        "#,
+    );
+    let base_types = base_types(&registry);
+    assert_eq!(base_types.len(), 2);
+    assert_eq!(
+        base_types["VkRemoteAddressNV"],
+        BaseType {
+            name: "VkRemoteAddressNV".to_string(),
+            ty: CType::Pointer {
+                is_const: false,
+                pointee: Box::new(CType::Named("void".to_string())),
+            },
+        }
+    );
+    assert_eq!(
+        base_types["VkDeviceSize"].ty,
+        CType::Primitive(CPrimitive::U64)
+    );
+}
+
+#[test]
+fn typedefs_with_several_declarators() {
+    let code = "typedef const uint32_t VkCount, *VkCountPointer;";
+    assert_eq!(
+        BaseType::parse_all(code),
+        Some(vec![
+            BaseType {
+                name: "VkCount".to_string(),
+                ty: CType::Primitive(CPrimitive::U32),
+            },
+            BaseType {
+                name: "VkCountPointer".to_string(),
+                ty: CType::Pointer {
+                    is_const: true,
+                    pointee: Box::new(CType::Primitive(CPrimitive::U32)),
+                },
+            },
+        ])
+    );
+    // A single base type can't stand for both names
+    assert_eq!(BaseType::parse(code), None);
+    // Neither declarator is dropped silently when one of them can't be parsed
+    assert_eq!(BaseType::parse_all("typedef uint32_t A, (B;"), None);
+
+    let registry = common::registry(
+        r#"
+        <types>
+            <type category="basetype">typedef <type>void</type>* <name>VkHandleA</name>, *<name>VkHandleB</name>;</type>
+        </types>
+        "#,
+    );
+    let base_types = base_types(&registry);
+    assert_eq!(base_types.len(), 2);
+    assert_eq!(base_types["VkHandleA"].ty, base_types["VkHandleB"].ty);
+}
+
+#[test]
+fn pointer_base_type_aliases() {
+    let xml = r#"
+        <types>
+            <type category="basetype">typedef <type>uint64_t</type> <name>VkDeviceSize</name>;</type>
+            <type category="basetype">typedef <type>void</type>* <name>VkRemoteAddressNV</name>;</type>
+        </types>
+    "#;
+    let base_types = base_types(&common::registry(xml));
+    let vkxml_registry = common::vkxml_registry(xml);
+    let typedefs: Vec<_> = common::definitions(&vkxml_registry)
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Typedef(ref typedef) => {
+                Some(generate_typedef(typedef, base_types.get(&typedef.name)).to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    assert!(typedefs[0].ends_with("pub type DeviceSize = u64 ;"));
+    assert!(typedefs[1].ends_with("pub type RemoteAddressNV = * mut c_void ;"));
+}
+
+#[test]
+fn char_arrays_are_string_buffers() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties" returnedonly="true">
+                <member><type>char</type> <name>deviceName</name>[<enum>VK_MAX_PHYSICAL_DEVICE_NAME_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>pipelineCacheUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+            </type>
+            <type category="struct" name="VkApplicationInfo">
+                <member>const <type>char</type>* <name>pApplicationName</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let member = |struct_name: &str, index: usize| match common::find_struct(&registry, struct_name)
+        .elements[index]
+    {
+        vkxml::StructElement::Member(ref field) => field,
+        _ => panic!("not a member"),
+    };
+    let device_name = member("VkPhysicalDeviceProperties", 0);
+    assert!(device_name.is_c_string_buffer());
+    assert!(!device_name.is_c_string());
+    assert!(!member("VkPhysicalDeviceProperties", 1).is_c_string_buffer());
+    let application_name = member("VkApplicationInfo", 0);
+    assert!(!application_name.is_c_string_buffer());
+    assert!(application_name.is_c_string());
+}
+
+#[test]
+fn ctype_tokens_resolve_named_types() {
+    let (_, ty) = parse_declaration("const VkExtent2D* pExtent").unwrap();
+    let resolve = |name: &str| {
+        let path: syn::Path = syn::parse_str(&format!("vk::{}", &name[2..])).unwrap();
+        path.into_tokens()
+    };
+    assert_eq!(
+        ty.to_rust_tokens(&resolve).to_string(),
+        "* const vk :: Extent2D"
+    );
+
+    let (_, ty) = parse_declaration("uint32_t* const pCounts[4]").unwrap();
+    assert_eq!(ty.to_rust_tokens(&resolve).to_string(), "[ * mut u32 ; 4 ]");
+    // The default resolution uses the names of the generated `vk` module
+    assert_eq!(
+        CType::Named("VkExtent2D".to_string())
+            .to_tokens()
+            .to_string(),
+        "Extent2D"
+    );
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use generator::{generate_object_type_table, handle_items, object_type_variant, vkxml};
+
+#[test]
+fn object_type_of_handle() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+            <type category="handle"><type>VK_DEFINE_HANDLE</type>(<name>VkCommandBuffer</name>)</type>
+            <type category="handle" parent="VkInstance"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkDebugUtilsMessengerEXT</name>)</type>
+        </types>
+        "#,
+    );
+    let handles: Vec<_> = common::definitions(&registry)
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Handle(ref handle) => Some(handle),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(handles.len(), 3);
+    assert_eq!(object_type_variant("VkBuffer").as_ref(), "BUFFER");
+    assert_eq!(
+        object_type_variant("VkDebugUtilsMessengerEXT").as_ref(),
+        "DEBUG_UTILS_MESSENGER_EXT"
+    );
+
+    let table = generate_object_type_table(&handles).to_string();
+    assert!(table.contains("\"VkBuffer\" => Some ( ObjectType :: BUFFER ) ,"));
+    assert!(table.contains("\"VkCommandBuffer\" => Some ( ObjectType :: COMMAND_BUFFER ) ,"));
+    assert!(table.contains("_ => None ,"));
+}
+
+#[test]
+fn handles_with_dispatch_kind() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="handle"><type>VK_DEFINE_HANDLE</type>(<name>VkDevice</name>)</type>
+            <type category="handle" parent="VkDevice"><type>VK_DEFINE_HANDLE</type>(<name>VkQueue</name>)</type>
+            <type category="handle" parent="VkDevice"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+            <type category="handle" parent="VkPhysicalDevice,VkDisplayKHR"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkDisplayModeKHR</name>)</type>
+        </types>
+        "#,
+    );
+    let definitions = common::definitions(&registry);
+    let handles: Vec<_> = handle_items(&definitions).collect();
+    let names: Vec<_> = handles.iter().map(|handle| handle.name).collect();
+    assert_eq!(
+        names,
+        ["VkDevice", "VkQueue", "VkBuffer", "VkDisplayModeKHR"]
+    );
+
+    let queue = &handles[1];
+    assert!(queue.is_dispatchable);
+    assert_eq!(queue.object_type.as_ref(), "QUEUE");
+    assert_eq!(queue.parents, ["VkDevice"]);
+
+    assert!(handles[0].parents.is_empty());
+    assert!(!handles[2].is_dispatchable);
+    assert_eq!(handles[3].parents, ["VkPhysicalDevice", "VkDisplayKHR"]);
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use generator::{
+    check_item_ids, collect_items, registry_summary, Item, ItemId, ItemKind, RegistrySummary,
+};
+use std::collections::HashSet;
+
+const REGISTRY: &str = r#"
+<types>
+    <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+    <type category="struct" name="VkExtent2D">
+        <member><type>uint32_t</type> <name>width</name></member>
+        <member><type>uint32_t</type> <name>height</name></member>
+    </type>
+    <type category="struct" name="VkExtent3D">
+        <member><type>uint32_t</type> <name>width</name></member>
+        <member><type>uint32_t</type> <name>height</name></member>
+        <member><type>uint32_t</type> <name>depth</name></member>
+    </type>
+</types>
+<enums name="API Constants" comment="Vulkan hardcoded constants - not an enumerated type, part of the header boilerplate">
+    <enum value="256" name="VK_MAX_PHYSICAL_DEVICE_NAME_SIZE"/>
+    <enum value="16" name="VK_UUID_SIZE"/>
+</enums>
+<enums name="VkFilter" type="enum">
+    <enum value="0" name="VK_FILTER_NEAREST"/>
+    <enum value="1" name="VK_FILTER_LINEAR"/>
+</enums>
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkDestroyBuffer</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param optional="true" externsync="true"><type>VkBuffer</type> <name>buffer</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+    </command>
+</commands>
+"#;
+
+#[test]
+fn item_ids_are_stable_and_unique() {
+    let first_registry = common::vkxml_registry(REGISTRY);
+    let second_registry = common::vkxml_registry(REGISTRY);
+    let first = collect_items(&first_registry);
+    let second = collect_items(&second_registry);
+    assert_eq!(first, second);
+
+    let names: Vec<_> = first.iter().map(|item| (item.kind, item.name)).collect();
+    assert_eq!(
+        names,
+        vec![
+            (ItemKind::Handle, "VkBuffer"),
+            (ItemKind::Struct, "VkExtent2D"),
+            (ItemKind::Struct, "VkExtent3D"),
+            (ItemKind::Constant, "VK_MAX_PHYSICAL_DEVICE_NAME_SIZE"),
+            (ItemKind::Constant, "VK_UUID_SIZE"),
+            (ItemKind::Enum, "VkFilter"),
+            (ItemKind::Command, "vkDestroyBuffer"),
+        ]
+    );
+    let ids: HashSet<_> = first.iter().map(|item| item.id).collect();
+    assert_eq!(ids.len(), first.len());
+
+    // Ids only depend on kind and name
+    assert_eq!(
+        ItemId::new(ItemKind::Struct, "VkExtent2D"),
+        ItemId::new(ItemKind::Struct, "VkExtent2D")
+    );
+    assert_ne!(
+        ItemId::new(ItemKind::Struct, "VkBuffer"),
+        ItemId::new(ItemKind::Handle, "VkBuffer")
+    );
+}
+
+#[test]
+fn colliding_item_ids_are_detected() {
+    let registry = common::vkxml_registry(REGISTRY);
+    let mut items = collect_items(&registry);
+    assert_eq!(check_item_ids(&items), Ok(()));
+
+    // The same item twice is not a collision
+    items.push(Item::new(ItemKind::Struct, "VkExtent2D"));
+    assert_eq!(check_item_ids(&items), Ok(()));
+
+    let id = ItemId::new(ItemKind::Struct, "VkExtent2D");
+    items.push(Item {
+        id,
+        kind: ItemKind::Struct,
+        name: "VkOffset2D",
+    });
+    assert_eq!(
+        check_item_ids(&items),
+        Err(format!(
+            "Struct VkExtent2D and Struct VkOffset2D share the id {}",
+            id.0
+        ))
+    );
+}
+
+#[test]
+fn registry_summary_counts_items() {
+    let registry = common::vkxml_registry(&format!(
+        r#"
+<types>
+    <type category="define">// Version of this file
+#define <name>VK_HEADER_VERSION</name> 290</type>
+</types>
+{}
+<extensions>
+    <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+        <require>
+            <enum value="70" name="VK_KHR_SWAPCHAIN_SPEC_VERSION"/>
+        </require>
+    </extension>
+</extensions>
+"#,
+        REGISTRY
+    ));
+    let summary = registry_summary(&registry);
+    assert_eq!(
+        summary,
+        RegistrySummary {
+            header_version: Some(290),
+            structs: 2,
+            enums: 1,
+            commands: 1,
+            extensions: 1,
+        }
+    );
+    assert_eq!(
+        summary.to_string(),
+        "Vulkan headers version 290: 2 structs, 1 enums, 1 commands, 1 extensions"
+    );
+
+    let unversioned = registry_summary(&common::vkxml_registry(REGISTRY));
+    assert_eq!(unversioned.header_version, None);
+    assert_eq!(unversioned.structs, 2);
+}
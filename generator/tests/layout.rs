@@ -0,0 +1,197 @@
+mod common;
+
+use std::collections::HashMap;
+
+use generator::{
+    array_sizes, generate_layout_tests, Layout, StructExt, Target, TypeLayouts, Version,
+};
+
+const TYPES: &str = r#"
+<types>
+    <type category="basetype">typedef <type>uint64_t</type> <name>VkDeviceSize</name>;</type>
+    <type name="VkStructureType" category="enum"/>
+    <type category="handle"><type>VK_DEFINE_HANDLE</type>(<name>VkDevice</name>)</type>
+    <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+    <type category="struct" name="VkExtent3D">
+        <member><type>uint32_t</type> <name>width</name></member>
+        <member><type>uint32_t</type> <name>height</name></member>
+        <member><type>uint32_t</type> <name>depth</name></member>
+    </type>
+    <type category="struct" name="VkMemoryRequirements" returnedonly="true">
+        <member><type>VkDeviceSize</type> <name>size</name></member>
+        <member><type>VkDeviceSize</type> <name>alignment</name></member>
+        <member><type>uint32_t</type> <name>memoryTypeBits</name></member>
+    </type>
+    <type category="struct" name="VkBufferMemoryRequirementsInfo2">
+        <member values="VK_STRUCTURE_TYPE_BUFFER_MEMORY_REQUIREMENTS_INFO_2"><type>VkStructureType</type> <name>sType</name></member>
+        <member>const <type>void</type>* <name>pNext</name></member>
+        <member><type>VkBuffer</type> <name>buffer</name></member>
+    </type>
+    <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true">
+        <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES"><type>VkStructureType</type> <name>sType</name></member>
+        <member><type>void</type>* <name>pNext</name></member>
+        <member><type>uint8_t</type> <name>deviceUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+        <member><type>uint8_t</type> <name>driverUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+        <member><type>uint8_t</type> <name>deviceLUID</name>[<enum>VK_LUID_SIZE</enum>]</member>
+        <member><type>uint32_t</type> <name>deviceNodeMask</name></member>
+        <member><type>VkBool32</type> <name>deviceLUIDValid</name></member>
+    </type>
+    <type category="union" name="VkClearColorValue">
+        <member><type>float</type> <name>float32</name>[4]</member>
+        <member><type>int32_t</type> <name>int32</name>[4]</member>
+        <member><type>uint32_t</type> <name>uint32</name>[4]</member>
+    </type>
+</types>
+<enums name="API Constants">
+    <enum value="16" name="VK_UUID_SIZE"/>
+    <enum value="8" name="VK_LUID_SIZE"/>
+</enums>
+<extensions></extensions>
+"#;
+
+#[test]
+fn member_offsets_on_64_bit() {
+    let registry = common::vkxml_registry(TYPES);
+    let definitions = common::definitions(&registry);
+    let constants = common::constants(&registry);
+    let layouts = TypeLayouts::new(&definitions, array_sizes(&constants), Target::Bits64);
+
+    assert_eq!(layouts.member_offset("VkExtent3D", "depth"), Some(8));
+    assert_eq!(layouts.layout("VkExtent3D"), Some(Layout::new(12, 4)));
+
+    assert_eq!(
+        layouts.member_offset("VkMemoryRequirements", "memoryTypeBits"),
+        Some(16)
+    );
+    assert_eq!(
+        layouts.layout("VkMemoryRequirements"),
+        Some(Layout::new(24, 8))
+    );
+
+    assert_eq!(
+        layouts.member_offset("VkBufferMemoryRequirementsInfo2", "pNext"),
+        Some(8)
+    );
+    assert_eq!(
+        layouts.member_offset("VkBufferMemoryRequirementsInfo2", "buffer"),
+        Some(16)
+    );
+    assert_eq!(
+        layouts.member_offset("VkPhysicalDeviceIDProperties", "deviceLUID"),
+        Some(48)
+    );
+    // `VkBool32` is not declared here, so the struct can't be laid out
+    assert_eq!(layouts.layout("VkPhysicalDeviceIDProperties"), None);
+    assert_eq!(
+        layouts.layout("VkClearColorValue"),
+        Some(Layout::new(16, 4))
+    );
+    assert_eq!(layouts.member_offset("VkExtent3D", "size"), None);
+}
+
+#[test]
+fn struct_member_offset_for_pointer_width() {
+    let registry = common::vkxml_registry(TYPES);
+    let definitions = common::definitions(&registry);
+    let extent = common::find_struct(&registry, "VkExtent3D");
+    let target = Target::from_pointer_width(64).unwrap();
+    let layouts = TypeLayouts::new(&definitions, Default::default(), target);
+    assert_eq!(extent.member_offset("depth", &layouts), Some(8));
+    assert_eq!(extent.member_offset("size", &layouts), None);
+
+    let info = common::find_struct(&registry, "VkBufferMemoryRequirementsInfo2");
+    let target = Target::from_pointer_width(32).unwrap();
+    let layouts = TypeLayouts::new(&definitions, Default::default(), target);
+    assert_eq!(info.member_offset("buffer", &layouts), Some(8));
+    assert_eq!(Target::from_pointer_width(16), None);
+}
+
+#[test]
+fn member_offsets_on_32_bit() {
+    let registry = common::vkxml_registry(TYPES);
+    let definitions = common::definitions(&registry);
+    let layouts = TypeLayouts::new(&definitions, Default::default(), Target::Bits32);
+
+    assert_eq!(layouts.member_offset("VkExtent3D", "depth"), Some(8));
+    assert_eq!(
+        layouts.member_offset("VkBufferMemoryRequirementsInfo2", "buffer"),
+        Some(8)
+    );
+    assert_eq!(
+        layouts.layout("VkBufferMemoryRequirementsInfo2"),
+        Some(Layout::new(16, 8))
+    );
+    assert_eq!(layouts.layout("VkDevice"), Some(Layout::new(4, 4)));
+    assert_eq!(layouts.layout("VkBuffer"), Some(Layout::new(8, 8)));
+}
+
+#[test]
+fn member_offsets_on_32_bit_x86() {
+    let registry = common::vkxml_registry(TYPES);
+    let definitions = common::definitions(&registry);
+    let layouts = TypeLayouts::new(&definitions, Default::default(), Target::X86SysV);
+
+    // 64 bit members are only aligned to 4 bytes
+    assert_eq!(
+        layouts.member_offset("VkBufferMemoryRequirementsInfo2", "buffer"),
+        Some(8)
+    );
+    assert_eq!(
+        layouts.layout("VkBufferMemoryRequirementsInfo2"),
+        Some(Layout::new(16, 4))
+    );
+    assert_eq!(
+        layouts.member_offset("VkMemoryRequirements", "memoryTypeBits"),
+        Some(16)
+    );
+    assert_eq!(
+        layouts.layout("VkMemoryRequirements"),
+        Some(Layout::new(20, 4))
+    );
+    assert_eq!(layouts.layout("VkBuffer"), Some(Layout::new(8, 4)));
+}
+
+#[test]
+fn generated_layout_tests() {
+    let registry = common::vkxml_registry(TYPES);
+    let constants = common::constants(&registry);
+    let definitions = common::definitions(&registry);
+    let versions = HashMap::<&str, Version>::new();
+
+    let layouts = TypeLayouts::new(&definitions, array_sizes(&constants), Target::Bits64);
+    let tests = generate_layout_tests(&definitions, &layouts, &versions).to_string();
+    assert!(tests.starts_with(
+        "# [ cfg ( all ( test , target_pointer_width = \"64\" ) ) ] mod layout_tests"
+    ));
+    assert!(tests.contains(
+        "fn layout_of_memory_requirements ( ) { \
+         assert_eq ! ( :: std :: mem :: size_of :: < MemoryRequirements > ( ) , 24usize ) ; \
+         assert_eq ! ( :: std :: mem :: align_of :: < MemoryRequirements > ( ) , 8usize ) ; \
+         let uninit = :: std :: mem :: MaybeUninit :: < MemoryRequirements > :: uninit ( ) ; \
+         let base = uninit . as_ptr ( ) ;"
+    ));
+    assert!(tests.contains(
+        "assert_eq ! ( unsafe { :: std :: ptr :: addr_of ! ( ( * base ) . memory_type_bits ) \
+         as usize - base as usize } , 16usize ) ;"
+    ));
+    assert!(!tests.contains("offset_of"));
+    // Unions have no member offsets to check
+    assert!(tests.contains(
+        "fn layout_of_clear_color_value ( ) { \
+         assert_eq ! ( :: std :: mem :: size_of :: < ClearColorValue > ( ) , 16usize ) ; \
+         assert_eq ! ( :: std :: mem :: align_of :: < ClearColorValue > ( ) , 4usize ) ; }"
+    ));
+
+    let layouts = TypeLayouts::new(&definitions, array_sizes(&constants), Target::X86SysV);
+    let tests = generate_layout_tests(&definitions, &layouts, &versions).to_string();
+    assert!(tests.starts_with(
+        "# [ cfg ( all ( test , all ( target_arch = \"x86\" , not ( windows ) ) ) ) ] \
+         mod layout_tests"
+    ));
+    assert!(tests.contains(
+        "assert_eq ! ( :: std :: mem :: size_of :: < MemoryRequirements > ( ) , 20usize ) ;"
+    ));
+    assert!(tests.contains(
+        "assert_eq ! ( :: std :: mem :: align_of :: < MemoryRequirements > ( ) , 4usize ) ;"
+    ));
+}
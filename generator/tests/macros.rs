@@ -0,0 +1,73 @@
+use generator::{define_handle_macro, handle_nondispatchable_macro, vk_version_macros};
+
+#[test]
+fn make_version_shim_delegates_to_make_api_version() {
+    let tokens = vk_version_macros().to_string();
+    assert!(tokens.contains(
+        "pub const fn make_api_version ( variant : u32 , major : u32 , minor : u32 , patch : u32 ) -> u32 { ( variant << 29 ) | ( major << 22 ) | ( minor << 12 ) | patch }"
+    ));
+    assert!(tokens.contains(
+        "# [ deprecated = \"This define is deprecated. make_api_version should be used instead.\" ]"
+    ));
+    assert!(tokens.contains(
+        "pub const fn make_version ( major : u32 , minor : u32 , patch : u32 ) -> u32 { make_api_version ( 0 , major , minor , patch ) }"
+    ));
+}
+
+#[test]
+fn deprecated_version_macros_name_an_existing_replacement() {
+    let file = syn::parse_file(&vk_version_macros().to_string()).unwrap();
+    let functions: Vec<_> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(function) => Some(function),
+            _ => None,
+        })
+        .collect();
+    let names: Vec<String> = functions.iter().map(|f| f.ident.to_string()).collect();
+    let replacements: Vec<String> = functions
+        .iter()
+        .flat_map(|function| function.attrs.iter())
+        .filter_map(|attr| match attr.interpret_meta()? {
+            syn::Meta::NameValue(ref meta) if meta.ident == "deprecated" => match meta.lit {
+                syn::Lit::Str(ref note) => Some(note.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|note| {
+            let replacement = note
+                .trim_start_matches("This define is deprecated. ")
+                .trim_end_matches(" should be used instead.");
+            replacement.to_string()
+        })
+        .collect();
+    assert_eq!(
+        replacements,
+        vec![
+            "make_api_version",
+            "api_version_major",
+            "api_version_minor",
+            "api_version_patch"
+        ]
+    );
+    for replacement in &replacements {
+        assert!(
+            names.contains(replacement),
+            "{} does not exist",
+            replacement
+        );
+    }
+}
+
+#[test]
+fn handles_convert_into_u64() {
+    let conversion = "impl From < $ name > for u64 { fn from ( handle : $ name ) -> u64 { handle . as_raw ( ) } }";
+    let from_raw = "unsafe fn from_raw ( x : u64 ) -> Self { $ name ( x as _ ) }";
+    for tokens in &[define_handle_macro(), handle_nondispatchable_macro()] {
+        let tokens = tokens.to_string();
+        assert!(tokens.contains(conversion));
+        assert!(tokens.contains(from_raw));
+    }
+}
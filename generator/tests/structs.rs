@@ -0,0 +1,938 @@
+mod common;
+
+use generator::{
+    array_sizes, chain_direction, chain_validity, derive_constructor, derive_default,
+    derive_setters, derive_tagged_structure, generate_definitions, generate_struct, non_copy_types,
+    root_structs, struct_usage, unknown_member_lens, vkxml, ChainDirection, ChainValidity,
+    Dependency, FieldExt, MemberLen, StructExt, TypeDependencyGraph,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[test]
+fn large_char_array_struct_is_copy() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkLayerProperties" returnedonly="true">
+                <member><type>char</type> <name>layerName</name>[<enum>VK_MAX_EXTENSION_NAME_SIZE</enum>]</member>
+                <member><type>uint32_t</type> <name>specVersion</name></member>
+            </type>
+        </types>
+        <enums name="API Constants">
+            <enum value="256" name="VK_MAX_EXTENSION_NAME_SIZE"/>
+        </enums>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkLayerProperties");
+    let tokens = common::generate_struct(_struct);
+    assert!(tokens.contains("pub layer_name : [ c_char ; MAX_EXTENSION_NAME_SIZE ]"));
+    assert!(tokens.contains("# [ derive ( Copy , Clone ,"));
+}
+
+#[test]
+fn structs_holding_unknown_types_are_not_copy() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkOpaqueHolder">
+                <member><type>OpaqueNative</type> <name>native</name></member>
+            </type>
+            <type category="struct" name="VkOpaqueArrayHolder">
+                <member><type>OpaqueNative</type> <name>natives</name>[4]</member>
+            </type>
+            <type category="struct" name="VkNestedHolder">
+                <member><type>VkOpaqueHolder</type> <name>holder</name></member>
+            </type>
+            <type category="struct" name="VkOpaquePointer">
+                <member><type>OpaqueNative</type>* <name>pNative</name></member>
+                <member><type>VkOpaqueHolder</type>* <name>pHolder</name></member>
+                <member><type>uint32_t</type> <name>values</name>[4]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let definitions = common::definitions(&registry);
+    let non_copy = non_copy_types(&definitions);
+    let mut expected: Vec<_> = non_copy.iter().cloned().collect();
+    expected.sort_unstable();
+    assert_eq!(
+        expected,
+        vec![
+            "OpaqueNative",
+            "VkNestedHolder",
+            "VkOpaqueArrayHolder",
+            "VkOpaqueHolder"
+        ]
+    );
+
+    let derives = |name: &str| {
+        let tokens = generate_struct(
+            common::find_struct(&registry, name),
+            &HashMap::new(),
+            &HashSet::new(),
+            &HashMap::new(),
+            &non_copy,
+            &[],
+        )
+        .to_string();
+        tokens.contains("# [ derive ( Copy , Clone ,")
+    };
+    assert!(!derives("VkOpaqueHolder"));
+    assert!(!derives("VkOpaqueArrayHolder"));
+    assert!(!derives("VkNestedHolder"));
+    // Pointers are `Copy` whatever they point to
+    assert!(derives("VkOpaquePointer"));
+}
+
+#[test]
+fn image_create_info_usage() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkExtent3D">
+                <member><type>uint32_t</type> <name>width</name></member>
+                <member><type>uint32_t</type> <name>height</name></member>
+                <member><type>uint32_t</type> <name>depth</name></member>
+            </type>
+            <type category="struct" name="VkImageCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkExtent3D</type> <name>extent</name></member>
+            </type>
+            <type category="struct" name="VkImageFormatProperties" returnedonly="true">
+                <member><type>VkExtent3D</type> <name>maxExtent</name></member>
+            </type>
+        </types>
+        <commands>
+            <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+                <proto><type>VkResult</type> <name>vkCreateImage</name></proto>
+                <param><type>VkDevice</type> <name>device</name></param>
+                <param>const <type>VkImageCreateInfo</type>* <name>pCreateInfo</name></param>
+                <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                <param><type>VkImage</type>* <name>pImage</name></param>
+            </command>
+            <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+                <proto><type>VkResult</type> <name>vkGetPhysicalDeviceImageFormatProperties</name></proto>
+                <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+                <param><type>VkImageFormatProperties</type>* <name>pImageFormatProperties</name></param>
+            </command>
+        </commands>
+        "#,
+    );
+    let commands = common::commands(&registry);
+    let definitions = common::definitions(&registry);
+
+    let usage = struct_usage("VkImageCreateInfo", &commands, &definitions);
+    assert_eq!(usage.consumed_by, vec!["vkCreateImage"]);
+    assert!(usage.produced_by.is_empty());
+    assert!(usage.embedded_in.is_empty());
+
+    let usage = struct_usage("VkImageFormatProperties", &commands, &definitions);
+    assert_eq!(
+        usage.produced_by,
+        vec!["vkGetPhysicalDeviceImageFormatProperties"]
+    );
+
+    let usage = struct_usage("VkExtent3D", &commands, &definitions);
+    assert_eq!(
+        usage.embedded_in,
+        vec!["VkImageCreateInfo", "VkImageFormatProperties"]
+    );
+}
+
+#[test]
+fn value_struct_const_constructor() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkExtent2D">
+                <member><type>uint32_t</type> <name>width</name></member>
+                <member><type>uint32_t</type> <name>height</name></member>
+            </type>
+            <type category="struct" name="VkImageCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkExtent3D</type> <name>extent</name></member>
+            </type>
+            <type category="struct" name="VkClearColorLimits">
+                <member><type>float</type> <name>red</name></member>
+                <member><type>float</type> <name>green</name></member>
+                <member><type>float</type> <name>blue</name></member>
+                <member><type>float</type> <name>alpha</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceLimits" returnedonly="true">
+                <member><type>uint32_t</type> <name>maxImageDimension1D</name></member>
+                <member><type>uint32_t</type> <name>maxImageDimension2D</name></member>
+                <member><type>uint32_t</type> <name>maxImageDimension3D</name></member>
+                <member><type>uint32_t</type> <name>maxImageDimensionCube</name></member>
+                <member><type>uint32_t</type> <name>maxImageArrayLayers</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let extent = common::find_struct(&registry, "VkExtent2D");
+    let code = format!(
+        "use std::os::raw::*;\n{}\n{}",
+        common::generate_struct(extent),
+        r#"
+        const EXTENT: Extent2D = Extent2D::new(1, 2);
+
+        #[test]
+        fn constructor() {
+            assert_eq!(EXTENT.width, 1);
+            assert_eq!(EXTENT.height, 2);
+        }
+        "#
+    );
+    assert!(common::run_tests("const_constructor", &code));
+
+    let create_info = common::find_struct(&registry, "VkImageCreateInfo");
+    assert!(derive_constructor(create_info).is_none());
+    // Only small structs get a constructor, larger ones would take too many arguments
+    assert!(derive_constructor(common::find_struct(&registry, "VkClearColorLimits")).is_some());
+    assert!(derive_constructor(common::find_struct(&registry, "VkPhysicalDeviceLimits")).is_none());
+}
+
+#[test]
+fn c_string_builder_setter() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkApplicationInfo">
+                <member values="VK_STRUCTURE_TYPE_APPLICATION_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member optional="true" len="null-terminated">const <type>char</type>* <name>pApplicationName</name></member>
+                <member><type>uint32_t</type> <name>applicationVersion</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let application_info = common::find_struct(&registry, "VkApplicationInfo");
+    let tokens = common::generate_struct(application_info);
+    assert!(tokens.contains(
+        "pub fn application_name ( mut self , application_name : & 'a :: std :: ffi :: CStr ) -> ApplicationInfoBuilder < 'a >"
+    ));
+}
+
+#[test]
+fn bool32_member_accessors() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceFeatures">
+                <member><type>VkBool32</type> <name>robustBufferAccess</name></member>
+                <member><type>VkBool32</type> <name>fullDrawIndexUint32</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let features = common::find_struct(&registry, "VkPhysicalDeviceFeatures");
+    let tokens = derive_setters(features, &HashMap::new(), &HashSet::new())
+        .expect("setters")
+        .to_string();
+    assert!(tokens.contains(
+        "pub fn robust_buffer_access ( mut self , robust_buffer_access : bool ) -> PhysicalDeviceFeaturesBuilder < 'a > { self . inner . robust_buffer_access = robust_buffer_access . into ( ) ;"
+    ));
+    assert!(tokens.contains(
+        "pub fn robust_buffer_access ( & self ) -> bool { self . robust_buffer_access != FALSE }"
+    ));
+    assert!(tokens.contains(
+        "pub fn set_full_draw_index_uint32 ( & mut self , full_draw_index_uint32 : bool ) { self . full_draw_index_uint32 = full_draw_index_uint32 . into ( ) ; }"
+    ));
+}
+
+#[test]
+fn keyword_member_is_renamed() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkIndirectCommandsLayoutTokenNV">
+                <member values="VK_STRUCTURE_TYPE_INDIRECT_COMMANDS_LAYOUT_TOKEN_NV"><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkIndirectCommandsTokenTypeNV</type> <name>type</name></member>
+                <member><type>uint32_t</type> <name>ref</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkIndirectCommandsLayoutTokenNV");
+    let tokens = common::generate_struct(_struct);
+    assert!(tokens.contains("pub ty : IndirectCommandsTokenTypeNV"));
+    assert!(tokens.contains("pub ref_ : u32"));
+    assert!(tokens.contains("pub fn ty ( mut self , ty : IndirectCommandsTokenTypeNV )"));
+    assert!(tokens.contains("self . inner . ref_ = ref_ ;"));
+    assert!(!tokens.contains(" type :"));
+}
+
+#[test]
+fn named_array_extent_is_resolved() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true">
+                <member><type>uint8_t</type> <name>deviceUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>deviceLUID</name>[<enum>VK_LUID_SIZE</enum>]</member>
+            </type>
+        </types>
+        <enums name="API Constants">
+            <enum value="16" name="VK_UUID_SIZE"/>
+        </enums>
+        "#,
+    );
+    let constants = common::constants(&registry);
+    let _struct = common::find_struct(&registry, "VkPhysicalDeviceIDProperties");
+    let tokens = generate_struct(
+        _struct,
+        &HashMap::new(),
+        &HashSet::new(),
+        &array_sizes(&constants),
+        &HashSet::new(),
+        &[],
+    )
+    .to_string();
+    assert!(tokens.contains("# [ doc = \"Length of `UUID_SIZE`\" ] pub device_uuid : [ u8 ; 16 ]"));
+    // Unknown constants are left as they are.
+    assert!(tokens.contains("pub device_luid : [ u8 ; LUID_SIZE ]"));
+}
+
+#[test]
+fn returned_only_extension_is_out_chainable() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties2" returnedonly="true">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true" structextends="VkPhysicalDeviceProperties2">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkDeviceGroupDeviceCreateInfo" structextends="VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let direction = |name| chain_direction(common::find_struct(&registry, name));
+    assert_eq!(
+        direction("VkPhysicalDeviceIDProperties"),
+        Some(ChainDirection::Out)
+    );
+    assert_eq!(
+        direction("VkDeviceGroupDeviceCreateInfo"),
+        Some(ChainDirection::In)
+    );
+    assert_eq!(direction("VkPhysicalDeviceProperties2"), None);
+}
+
+#[test]
+fn output_struct_is_not_pushed_into_input_chain() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties2" returnedonly="true">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true" structextends="VkPhysicalDeviceProperties2,VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let root_structs = root_structs(&common::definitions(&registry));
+    assert_eq!(
+        root_structs.get("VkDeviceCreateInfo"),
+        Some(&ChainDirection::In)
+    );
+    assert_eq!(
+        root_structs.get("VkPhysicalDeviceProperties2"),
+        Some(&ChainDirection::Out)
+    );
+    let setters = |name| {
+        derive_setters(
+            common::find_struct(&registry, name),
+            &root_structs,
+            &HashSet::new(),
+        )
+        .unwrap()
+        .to_string()
+    };
+
+    let id_properties = setters("VkPhysicalDeviceIDProperties");
+    assert!(id_properties
+        .contains("unsafe impl ExtendsPhysicalDeviceProperties2 for PhysicalDeviceIDProperties"));
+    assert!(!id_properties.contains("ExtendsDeviceCreateInfo"));
+
+    let create_info = setters("VkDeviceCreateInfo");
+    assert!(create_info.contains("pub fn push_next < T : ExtendsDeviceCreateInfo >"));
+    assert!(!create_info.contains("push_next_mut"));
+
+    let properties = setters("VkPhysicalDeviceProperties2");
+    assert!(properties.contains("pub fn push_next_mut < T : ExtendsPhysicalDeviceProperties2 >"));
+}
+
+#[test]
+fn features_and_create_info_chain_validity() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceFeatures2">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceProperties2" returnedonly="true">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceVulkan11Features" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkDeviceGroupDeviceCreateInfo" structextends="VkDeviceCreateInfo">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceIDProperties" returnedonly="true" structextends="VkPhysicalDeviceProperties2">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let validity = |name, root| {
+        chain_validity(
+            common::find_struct(&registry, name),
+            common::find_struct(&registry, root),
+        )
+    };
+    assert_eq!(
+        validity(
+            "VkPhysicalDeviceVulkan11Features",
+            "VkPhysicalDeviceFeatures2"
+        ),
+        Some(ChainValidity::Both)
+    );
+    assert_eq!(
+        validity("VkPhysicalDeviceVulkan11Features", "VkDeviceCreateInfo"),
+        Some(ChainValidity::Input)
+    );
+    assert_eq!(
+        validity("VkDeviceGroupDeviceCreateInfo", "VkDeviceCreateInfo"),
+        Some(ChainValidity::Input)
+    );
+    assert_eq!(
+        validity("VkDeviceGroupDeviceCreateInfo", "VkPhysicalDeviceFeatures2"),
+        None
+    );
+    assert_eq!(
+        validity(
+            "VkPhysicalDeviceIDProperties",
+            "VkPhysicalDeviceProperties2"
+        ),
+        Some(ChainValidity::Output)
+    );
+}
+
+#[test]
+fn embedded_structs_are_ordered_first() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkRect2D">
+                <member><type>VkOffset2D</type> <name>offset</name></member>
+                <member><type>VkExtent2D</type> <name>extent</name></member>
+            </type>
+            <type category="struct" name="VkBaseOutStructure">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member>struct <type>VkBaseOutStructure</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkRenderPassBeginInfo">
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkRect2D</type> <name>renderArea</name></member>
+                <member>const <type>VkClearValue</type>* <name>pClearValues</name></member>
+            </type>
+            <type category="union" name="VkClearValue">
+                <member><type>VkOffset2D</type> <name>offset</name></member>
+                <member>const <type>VkRenderPassBeginInfo</type>* <name>pBeginInfo</name></member>
+            </type>
+            <type category="struct" name="VkOffset2D">
+                <member><type>int32_t</type> <name>x</name></member>
+                <member><type>int32_t</type> <name>y</name></member>
+            </type>
+            <type category="struct" name="VkExtent2D">
+                <member><type>uint32_t</type> <name>width</name></member>
+                <member><type>uint32_t</type> <name>height</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let definitions = common::definitions(&registry);
+    let graph = TypeDependencyGraph::new(&definitions);
+    assert_eq!(
+        graph.dependencies("VkRect2D"),
+        &[
+            ("VkOffset2D", Dependency::Embeds),
+            ("VkExtent2D", Dependency::Embeds)
+        ]
+    );
+    // Self references and types outside of the registry are not part of the graph
+    assert!(graph.dependencies("VkBaseOutStructure").is_empty());
+    assert!(graph.dependencies("VkOffset2D").is_empty());
+
+    // `VkRenderPassBeginInfo` and `VkClearValue` point to each other, which doesn't affect the order
+    assert_eq!(
+        graph.topological_order(),
+        vec![
+            "VkOffset2D",
+            "VkExtent2D",
+            "VkRect2D",
+            "VkBaseOutStructure",
+            "VkRenderPassBeginInfo",
+            "VkClearValue",
+        ]
+    );
+}
+
+#[test]
+fn definitions_are_emitted_after_embedded_types() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkRect2D">
+                <member><type>VkOffset2D</type> <name>offset</name></member>
+                <member><type>VkExtent2D</type> <name>extent</name></member>
+            </type>
+            <type category="struct" name="VkOffset2D">
+                <member><type>int32_t</type> <name>x</name></member>
+                <member><type>int32_t</type> <name>y</name></member>
+            </type>
+            <type category="struct" name="VkExtent2D">
+                <member><type>uint32_t</type> <name>width</name></member>
+                <member><type>uint32_t</type> <name>height</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let definitions = common::definitions(&registry);
+    let code = generate_definitions(
+        &definitions,
+        &HashMap::new(),
+        &HashMap::new(),
+        &HashMap::new(),
+        &HashMap::new(),
+        &HashMap::new(),
+        &mut HashSet::new(),
+        &mut BTreeMap::new(),
+    );
+    let source: String = code.iter().map(|tokens| tokens.to_string()).collect();
+    syn::parse_file(&source).expect("definitions are valid Rust");
+
+    let position = |name: &str| {
+        source
+            .find(&format!("pub struct {} {{", name))
+            .unwrap_or_else(|| panic!("{} is not defined", name))
+    };
+    assert!(position("Offset2D") < position("Rect2D"));
+    assert!(position("Extent2D") < position("Rect2D"));
+}
+
+#[test]
+fn member_comments_are_docs() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPipelineColorBlendStateCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_STATE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member>const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkBool32</type> <name>logicOpEnable</name></member>
+                <member noautovalidity="true"><type>VkLogicOp</type> <name>logicOp</name></member>
+                <member><type>float</type> <name>blendConstants</name>[4]<comment>Constant color used for blending</comment></member>
+            </type>
+        </types>
+        "#,
+    );
+    let color_blend = common::find_struct(&registry, "VkPipelineColorBlendStateCreateInfo");
+    let tokens = common::generate_struct(color_blend);
+    assert!(tokens.contains(
+        "# [ doc = \"Constant color used for blending\" ] pub blend_constants : [ f32 ; 4 ]"
+    ));
+    assert!(tokens.contains(", pub logic_op : LogicOp ,"));
+}
+
+#[test]
+fn fixed_size_string_setter() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties">
+                <member><type>uint32_t</type> <name>apiVersion</name></member>
+                <member><type>char</type> <name>deviceName</name>[<enum>VK_MAX_PHYSICAL_DEVICE_NAME_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>pipelineCacheUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let properties = common::find_struct(&registry, "VkPhysicalDeviceProperties");
+    let setters = derive_setters(properties, &HashMap::new(), &HashSet::new())
+        .expect("setters")
+        .to_string();
+    // Oversized names are cut off one short of the array length, which leaves room for the
+    // terminating null, and the remainder of the array is cleared.
+    assert!(setters.contains(
+        "pub fn set_device_name ( & mut self , device_name : & :: std :: ffi :: CStr ) { \
+         let bytes = device_name . to_bytes ( ) ; \
+         let len = bytes . len ( ) . min ( self . device_name . len ( ) - 1 ) ; \
+         for ( i , c ) in self . device_name . iter_mut ( ) . enumerate ( ) { \
+         * c = if i < len { bytes [ i ] as c_char } else { 0 } ; } }"
+    ));
+    assert!(!setters.contains("set_pipeline_cache_uuid"));
+
+    let default = derive_default(properties).expect("default").to_string();
+    assert!(default.contains("device_name : unsafe { :: std :: mem :: zeroed ( ) }"));
+}
+
+#[test]
+fn uuid_byte_slice_accessors() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceIDProperties">
+                <member><type>uint8_t</type> <name>deviceUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>deviceLUID</name>[<enum>VK_LUID_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>extraData</name>[4]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let properties = common::find_struct(&registry, "VkPhysicalDeviceIDProperties");
+    let setters = derive_setters(properties, &HashMap::new(), &HashSet::new())
+        .expect("setters")
+        .to_string();
+    assert!(setters.contains("pub fn device_uuid ( & self ) -> & [ u8 ] { & self . device_uuid }"));
+    assert!(setters.contains("pub fn device_luid ( & self ) -> & [ u8 ] { & self . device_luid }"));
+    assert!(!setters.contains("pub fn extra_data ( & self )"));
+}
+
+#[test]
+fn structure_type_is_tagged() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkApplicationInfo">
+                <member values="VK_STRUCTURE_TYPE_APPLICATION_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+                <member><type>uint32_t</type> <name>apiVersion</name></member>
+            </type>
+            <type category="struct" name="VkBaseInStructure">
+                <member><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const struct <type>VkBaseInStructure</type>* <name>pNext</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let application_info = common::find_struct(&registry, "VkApplicationInfo");
+    let tokens = derive_tagged_structure(application_info)
+        .expect("tagged")
+        .to_string();
+    assert_eq!(
+        tokens,
+        "impl TaggedStructure for ApplicationInfo { \
+         const STRUCTURE_TYPE : StructureType = StructureType :: APPLICATION_INFO ; }"
+    );
+    let code = common::generate_struct(application_info);
+    assert!(code.contains(&tokens));
+
+    // Base structures can hold any structure type
+    let base = common::find_struct(&registry, "VkBaseInStructure");
+    assert!(derive_tagged_structure(base).is_none());
+}
+
+#[test]
+fn unknown_member_lens_are_reported() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkInstanceCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+                <member optional="true"><type>uint32_t</type> <name>enabledLayerCount</name></member>
+                <member len="enabledLayerCount,null-terminated">const <type>char</type>* const* <name>ppEnabledLayerNames</name></member>
+                <member len="null-terminated">const <type>char</type>* <name>pApplicationName</name></member>
+            </type>
+            <type category="struct" name="VkPipelineMultisampleStateCreateInfo">
+                <member><type>VkSampleCountFlagBits</type> <name>rasterizationSamples</name></member>
+                <member optional="true" len="latexmath:[\lceil{\mathit{rasterizationSamples} \over 32}\rceil]" altlen="(rasterizationSamples + 31) / 32">const <type>VkSampleMask</type>* <name>pSampleMask</name></member>
+            </type>
+            <type category="struct" name="VkSubmitInfo">
+                <member optional="true"><type>uint32_t</type> <name>waitSemaphoreCount</name></member>
+                <member len="waitSemaphoreCount">const <type>VkSemaphore</type>* <name>pWaitSemaphores</name></member>
+                <member len="waitSemaphorCount">const <type>VkPipelineStageFlags</type>* <name>pWaitDstStageMask</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    for name in &[
+        "VkInstanceCreateInfo",
+        "VkPipelineMultisampleStateCreateInfo",
+    ] {
+        let _struct = common::find_struct(&registry, name);
+        assert_eq!(unknown_member_lens(_struct), vec![]);
+    }
+    let submit_info = common::find_struct(&registry, "VkSubmitInfo");
+    assert_eq!(
+        unknown_member_lens(submit_info),
+        vec![("pWaitDstStageMask", "waitSemaphorCount")]
+    );
+}
+
+#[test]
+fn next_pointer_follows_chain_direction() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkBufferCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkMemoryRequirements2" returnedonly="true">
+                <member values="VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true"><type>void</type>* <name>pNext</name></member>
+            </type>
+            <type category="struct" name="VkSurfaceCapabilities2KHR" returnedonly="true">
+                <member values="VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_2_KHR"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let generate = |name: &str| {
+        let _struct = common::find_struct(&registry, name);
+        common::generate_struct(_struct)
+    };
+
+    let create_info = generate("VkBufferCreateInfo");
+    assert!(create_info.contains("pub p_next : *const c_void"));
+    assert!(create_info.contains("p_next : :: std :: ptr :: null ( )"));
+
+    let requirements = generate("VkMemoryRequirements2");
+    assert!(requirements.contains("pub p_next : *mut c_void"));
+    assert!(requirements.contains("p_next : :: std :: ptr :: null_mut ( )"));
+
+    // Returned-only structs are written by the implementation, whatever the registry declares
+    let capabilities = generate("VkSurfaceCapabilities2KHR");
+    assert!(capabilities.contains("pub p_next : *mut c_void"));
+    assert!(capabilities.contains("p_next : :: std :: ptr :: null_mut ( )"));
+}
+
+#[test]
+fn content_hash_tracks_members() {
+    let hash = |members: &str| {
+        let registry = common::vkxml_registry(&format!(
+            r#"<types><type category="struct" name="VkBufferCopy">{}</type></types>"#,
+            members
+        ));
+        common::find_struct(&registry, "VkBufferCopy").content_hash()
+    };
+    let original = hash(
+        r#"
+        <member><type>VkDeviceSize</type> <name>srcOffset</name><comment>Specified in bytes</comment></member>
+        <member optional="false"><type>VkDeviceSize</type> <name>dstOffset</name></member>
+        <member noautovalidity="true"><type>VkDeviceSize</type> <name>size</name></member>
+        "#,
+    );
+    let same_members = hash(
+        r#"
+        <comment>Regions to copy</comment>
+        <member><type>VkDeviceSize</type> <name>srcOffset</name></member>
+        <member><type>VkDeviceSize</type> <name>dstOffset</name></member>
+        <member><type>VkDeviceSize</type> <name>size</name></member>
+        "#,
+    );
+    let changed_type = hash(
+        r#"
+        <member><type>VkDeviceSize</type> <name>srcOffset</name></member>
+        <member><type>VkDeviceSize</type> <name>dstOffset</name></member>
+        <member><type>uint32_t</type> <name>size</name></member>
+        "#,
+    );
+    assert_eq!(original, same_members);
+    assert_ne!(original, changed_type);
+}
+
+#[test]
+fn returned_only_structs_have_no_builder() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties" returnedonly="true">
+                <member><type>uint32_t</type> <name>apiVersion</name></member>
+                <member><type>VkBool32</type> <name>residencyStandard2DBlockShape</name></member>
+            </type>
+            <type category="struct" name="VkBufferCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+                <member><type>VkDeviceSize</type> <name>size</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let properties = common::find_struct(&registry, "VkPhysicalDeviceProperties");
+    let create_info = common::find_struct(&registry, "VkBufferCreateInfo");
+    assert!(properties.is_returned_only());
+    assert!(!create_info.is_returned_only());
+
+    let properties = derive_setters(properties, &HashMap::new(), &HashSet::new())
+        .expect("accessors")
+        .to_string();
+    assert!(!properties.contains("Builder"));
+    assert!(properties.contains("pub fn residency_standard2_d_block_shape ( & self ) -> bool"));
+    let create_info = derive_setters(create_info, &HashMap::new(), &HashSet::new())
+        .expect("setters")
+        .to_string();
+    assert!(create_info.contains("pub struct BufferCreateInfoBuilder"));
+}
+
+#[test]
+fn returned_only_structs_have_getters() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPhysicalDeviceProperties" returnedonly="true">
+                <member><type>uint32_t</type> <name>apiVersion</name></member>
+                <member><type>char</type> <name>deviceName</name>[<enum>VK_MAX_PHYSICAL_DEVICE_NAME_SIZE</enum>]</member>
+                <member><type>uint8_t</type> <name>pipelineCacheUUID</name>[<enum>VK_UUID_SIZE</enum>]</member>
+                <member><type>VkPhysicalDeviceLimits</type> <name>limits</name></member>
+                <member><type>VkBool32</type> <name>residencyStandard2DBlockShape</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let properties = common::find_struct(&registry, "VkPhysicalDeviceProperties");
+    let getters = derive_setters(properties, &HashMap::new(), &HashSet::new())
+        .expect("getters")
+        .to_string();
+    assert!(getters.contains(
+        "# [ doc = \"# Safety\\n\\n`deviceName` has to be null-terminated, like it is after the \
+         implementation filled it in.\" ] \
+         pub unsafe fn device_name ( & self ) -> & :: std :: ffi :: CStr { \
+         :: std :: ffi :: CStr :: from_ptr ( self . device_name . as_ptr ( ) ) }"
+    ));
+    assert!(getters.contains("pub fn api_version ( & self ) -> u32 { self . api_version }"));
+    assert!(getters.contains("pub fn limits ( & self ) -> PhysicalDeviceLimits { self . limits }"));
+    assert!(getters.contains("pub fn residency_standard2_d_block_shape ( & self ) -> bool"));
+    assert!(getters.contains(
+        "pub fn pipeline_cache_uuid ( & self ) -> & [ u8 ] { & self . pipeline_cache_uuid }"
+    ));
+    assert!(!getters.contains("set_"));
+    assert!(!getters.contains("mut self"));
+}
+
+#[test]
+fn returned_only_getters_borrow_non_copy_members() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkNativeProperties" returnedonly="true">
+                <member><type>uint32_t</type> <name>count</name></member>
+                <member><type>OpaqueNative</type> <name>native</name></member>
+                <member><type>OpaqueNative</type> <name>natives</name>[4]</member>
+            </type>
+        </types>
+        "#,
+    );
+    let definitions = common::definitions(&registry);
+    let non_copy = non_copy_types(&definitions);
+    let properties = common::find_struct(&registry, "VkNativeProperties");
+    let getters = derive_setters(properties, &HashMap::new(), &non_copy)
+        .expect("getters")
+        .to_string();
+    assert!(getters.contains("pub fn count ( & self ) -> u32 { self . count }"));
+    assert!(getters.contains("pub fn native ( & self ) -> & OpaqueNative { & self . native }"));
+    assert!(getters
+        .contains("pub fn natives ( & self ) -> & [ OpaqueNative ; 4 ] { & self . natives }"));
+}
+
+#[test]
+fn latexmath_lengths_use_their_c_form() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkPipelineMultisampleStateCreateInfo">
+                <member><type>VkSampleCountFlagBits</type> <name>rasterizationSamples</name></member>
+                <member optional="true" len="latexmath:[\lceil{\mathit{rasterizationSamples} \over 32}\rceil]" altlen="(rasterizationSamples + 31) / 32">const <type>VkSampleMask</type>* <name>pSampleMask</name></member>
+                <member len="latexmath:[\textrm{codeSize} \over 4]">const <type>uint32_t</type>* <name>pCode</name></member>
+                <member><type>uint32_t</type> <name>layerCount</name></member>
+                <member len="layerCount,null-terminated">const <type>char</type>* const* <name>ppLayerNames</name></member>
+                <member len="null-terminated">const <type>char</type>* <name>pName</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let _struct = common::find_struct(&registry, "VkPipelineMultisampleStateCreateInfo");
+    let lens: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match elem {
+            vkxml::StructElement::Member(field) => Some(field.member_len()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        lens,
+        [
+            None,
+            Some(MemberLen::Expression("(rasterizationSamples + 31) / 32")),
+            Some(MemberLen::Manual),
+            None,
+            Some(MemberLen::Member("layerCount")),
+            Some(MemberLen::NullTerminated),
+        ]
+    );
+    assert_eq!(unknown_member_lens(_struct), vec![]);
+}
+
+#[test]
+fn formula_length_setters_are_unsafe() {
+    let registry = common::vkxml_registry(
+        r#"
+        <types>
+            <type category="struct" name="VkAccelerationStructureVersionInfoKHR">
+                <member values="VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_VERSION_INFO_KHR"><type>VkStructureType</type> <name>sType</name></member>
+                <member optional="true">const <type>void</type>* <name>pNext</name></member>
+                <member len="latexmath:[2 \times \mathtt{VK\_UUID\_SIZE}]" altlen="2*VK_UUID_SIZE">const <type>uint8_t</type>* <name>pVersionData</name></member>
+                <member len="latexmath:[\textrm{dataSize} \over 4]"><type>uint32_t</type>* <name>pWords</name></member>
+                <member><type>uint32_t</type> <name>layerCount</name></member>
+                <member len="layerCount">const <type>uint32_t</type>* <name>pLayers</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    let version_info = common::find_struct(&registry, "VkAccelerationStructureVersionInfoKHR");
+    let setters = derive_setters(version_info, &HashMap::new(), &HashSet::new())
+        .expect("setters")
+        .to_string();
+    let unspaced = setters.replace(' ', "");
+    assert!(unspaced.contains(
+        "pubunsafefnversion_data(mutself,version_data:&'a[u8])->AccelerationStructureVersionInfoKHRBuilder<'a>{self.inner.p_version_data=version_data.as_ptr();self}"
+    ));
+    assert!(setters.contains("The length of `pVersionData` is `2*VK_UUID_SIZE`"));
+    assert!(unspaced.contains("pubunsafefnwords(mutself,words:&'amut[u32])"));
+    assert!(unspaced.contains("self.inner.p_words=words.as_mut_ptr();"));
+    assert!(unspaced.contains("pubfnlayers(mutself,layers:&'a[u32])"));
+}
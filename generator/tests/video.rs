@@ -0,0 +1,137 @@
+mod common;
+
+use generator::{
+    bitfield_structs, generate_video, video_module, video_profile_enums, VideoProfileEnum,
+};
+
+#[test]
+fn video_std_types_are_generated_in_the_video_module() {
+    // Enums are only converted to vkxml once another element follows them
+    let xml = r#"
+        <enums name="StdVideoH264ProfileIdc" type="enum">
+            <enum name="STD_VIDEO_H264_PROFILE_IDC_BASELINE" value="66"/>
+            <enum name="STD_VIDEO_H264_PROFILE_IDC_MAIN" value="77"/>
+        </enums>
+        <types>
+            <type category="struct" name="StdVideoH264SpsVuiFlags">
+                <member><type>uint32_t</type> <name>aspect_ratio_info_present_flag</name></member>
+            </type>
+        </types>
+    "#;
+    let tokens = generate_video(&common::vkxml_registry(xml), &common::registry(xml))
+        .unwrap()
+        .to_string();
+    assert!(tokens.contains("pub struct StdVideoH264ProfileIdc ( pub ( crate ) i32 ) ;"));
+    assert!(tokens.contains(
+        "impl StdVideoH264ProfileIdc { pub const BASELINE : Self = StdVideoH264ProfileIdc ( 66 ) ;"
+    ));
+    assert!(tokens.contains(
+        "pub struct StdVideoH264SpsVuiFlags { pub aspect_ratio_info_present_flag : u32 }"
+    ));
+    assert_eq!(
+        video_module().to_string(),
+        "# [ cfg ( feature = \"video\" ) ] pub mod video ;"
+    );
+    assert!(common::ash_features().contains(&"video".to_string()));
+}
+
+#[test]
+fn video_profile_enums_are_collected() {
+    let registry = common::vkxml_registry(
+        r#"
+        <enums name="StdVideoH264ProfileIdc" type="enum">
+            <enum name="STD_VIDEO_H264_PROFILE_IDC_BASELINE" value="66"/>
+            <enum name="STD_VIDEO_H264_PROFILE_IDC_MAIN" value="77"/>
+            <enum name="STD_VIDEO_H264_PROFILE_IDC_INVALID" value="0x7FFFFFFF"/>
+        </enums>
+        <enums name="StdVideoH264LevelIdc" type="enum">
+            <enum name="STD_VIDEO_H264_LEVEL_IDC_1_0" value="0"/>
+        </enums>
+        <types>
+            <type category="struct" name="StdVideoH264SpsVuiFlags">
+                <member><type>uint32_t</type> <name>aspect_ratio_info_present_flag</name></member>
+            </type>
+        </types>
+        "#,
+    );
+    assert_eq!(
+        video_profile_enums(&registry),
+        [VideoProfileEnum {
+            name: "StdVideoH264ProfileIdc",
+            codec: "H264",
+            profiles: vec![
+                ("STD_VIDEO_H264_PROFILE_IDC_BASELINE", 66),
+                ("STD_VIDEO_H264_PROFILE_IDC_MAIN", 77),
+                ("STD_VIDEO_H264_PROFILE_IDC_INVALID", 0x7FFF_FFFF),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn bitfield_structs_have_accessors() {
+    let xml = r#"
+        <types>
+            <type category="struct" name="StdVideoH264SpsFlags">
+                <member><type>uint32_t</type> <name>constraint_set0_flag</name> : 1</member>
+                <member><type>uint32_t</type> <name>constraint_set1_flag</name> : 1</member>
+                <member><type>uint32_t</type> <name>reserved</name> : 30</member>
+                <member><type>uint32_t</type> <name>overflow_flag</name> : 1</member>
+                <member><type>uint8_t</type> <name>level</name></member>
+            </type>
+            <type category="struct" name="StdVideoH264SpsVuiFlags">
+                <member><type>uint32_t</type> <name>aspect_ratio_info_present_flag</name></member>
+            </type>
+        </types>
+    "#;
+    let registry = common::registry(xml);
+    let structs = bitfield_structs(&registry);
+    assert_eq!(structs.len(), 1);
+    assert_eq!(structs["StdVideoH264SpsFlags"].len(), 5);
+
+    let tokens = generate_video(&common::vkxml_registry(xml), &registry)
+        .unwrap()
+        .to_string();
+    assert!(tokens.contains(
+        "# [ repr ( C ) ] # [ derive ( Copy , Clone , Default ) ] pub struct StdVideoH264SpsFlags { \
+         _bitfield_0 : u32 , _bitfield_1 : u32 , pub level : u8 }"
+    ));
+    assert!(tokens.contains(
+        "pub fn constraint_set1_flag ( & self ) -> u32 { ( self . _bitfield_0 >> 1 ) & 0x1 }"
+    ));
+    assert!(tokens.contains("pub fn set_reserved ( & mut self , reserved : u32 )"));
+
+    let code = format!(
+        "{}\n#[test] fn layout() {{
+            assert_eq!(std::mem::size_of::<StdVideoH264SpsFlags>(), 12);
+            let mut flags = StdVideoH264SpsFlags::default();
+            flags.set_constraint_set1_flag(1);
+            flags.set_reserved(u32::MAX);
+            flags.set_overflow_flag(1);
+            assert_eq!(flags.constraint_set0_flag(), 0);
+            assert_eq!(flags.constraint_set1_flag(), 1);
+            assert_eq!(flags.reserved(), 0x3fff_ffff);
+            assert_eq!(flags._bitfield_0, 0xffff_fffe);
+            assert_eq!(flags._bitfield_1, 1);
+            flags.set_reserved(0);
+            assert_eq!(flags._bitfield_0, 2);
+        }}",
+        tokens.replace("use crate :: vk :: platform_types :: * ;", "")
+    );
+    assert!(common::run_tests("bitfields", &code));
+}
+
+#[test]
+fn bitfields_have_to_be_integers() {
+    let xml = r#"
+        <types>
+            <type category="struct" name="StdVideoFloatFlags">
+                <member><type>float</type> <name>scale</name> : 1</member>
+            </type>
+        </types>
+    "#;
+    assert_eq!(
+        generate_video(&common::vkxml_registry(xml), &common::registry(xml)),
+        Err("StdVideoFloatFlags.scale is a bitfield of float, which isn't an integer".to_string())
+    );
+}
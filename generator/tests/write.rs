@@ -0,0 +1,94 @@
+use generator::write_source_code;
+
+const VK_XML: &str = r#"<registry>
+<types>
+    <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+    <type category="struct" name="VkExtent2D">
+        <member><type>uint32_t</type> <name>width</name></member>
+        <member><type>uint32_t</type> <name>height</name></member>
+    </type>
+</types>
+<enums name="API Constants" comment="Vulkan hardcoded constants - not an enumerated type, part of the header boilerplate">
+    <enum value="16" name="VK_UUID_SIZE"/>
+    <enum value="(~0U)" name="VK_SHADER_UNUSED_KHR"/>
+</enums>
+<enums name="VkFilter" type="enum">
+    <enum value="0" name="VK_FILTER_NEAREST"/>
+    <enum value="1" name="VK_FILTER_LINEAR"/>
+</enums>
+<commands>
+    <command>
+        <proto><type>void</type> <name>vkDestroyBuffer</name></proto>
+        <param><type>VkDevice</type> <name>device</name></param>
+        <param optional="true" externsync="true"><type>VkBuffer</type> <name>buffer</name></param>
+        <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+    </command>
+</commands>
+<feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+    <require>
+        <command name="vkDestroyBuffer"/>
+    </require>
+</feature>
+<extensions>
+    <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+        <require>
+            <enum value="70" name="VK_KHR_SWAPCHAIN_SPEC_VERSION"/>
+            <enum value="&quot;VK_KHR_swapchain&quot;" name="VK_KHR_SWAPCHAIN_EXTENSION_NAME"/>
+        </require>
+    </extension>
+</extensions>
+</registry>
+"#;
+
+#[test]
+fn unchanged_files_are_not_rewritten() {
+    let dir = std::env::temp_dir().join(format!("ash-generator-write-{}", std::process::id()));
+    let vk_xml = dir.join("vk.xml");
+    let src_dir = dir.join("src");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&vk_xml, VK_XML).unwrap();
+
+    let generated = write_source_code(&vk_xml, &src_dir);
+    assert!(generated.written.contains(&src_dir.join("vk.rs")));
+    assert!(generated
+        .written
+        .contains(&src_dir.join("vk").join("definitions.rs")));
+    assert_eq!(generated.written.len(), generated.modules);
+
+    let rewritten = write_source_code(&vk_xml, &src_dir);
+    assert_eq!(rewritten.written, Vec::<std::path::PathBuf>::new());
+    assert_eq!(rewritten.modules, generated.modules);
+
+    std::fs::write(&vk_xml, VK_XML.replace("value=\"16\"", "value=\"32\"")).unwrap();
+    let changed = write_source_code(&vk_xml, &src_dir);
+    assert_eq!(
+        changed.written,
+        vec![src_dir.join("vk").join("constants.rs")]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn generated_source_is_summarized() {
+    let dir = std::env::temp_dir().join(format!("ash-generator-summary-{}", std::process::id()));
+    let vk_xml = dir.join("vk.xml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&vk_xml, VK_XML).unwrap();
+
+    let generated = write_source_code(&vk_xml, dir.join("src"));
+    assert_eq!(generated.summary.structs, 1);
+    assert_eq!(generated.summary.enums, 1);
+    assert_eq!(generated.summary.commands, 1);
+    assert_eq!(generated.summary.extensions, 1);
+    assert_eq!(
+        generated.to_string(),
+        format!(
+            "Vulkan headers of unknown version: 1 structs, 1 enums, 1 commands, 1 extensions; \
+             generated {0} modules, {0} of them changed",
+            generated.modules
+        )
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}